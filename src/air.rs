@@ -0,0 +1,104 @@
+use crate::AirQuality;
+
+/// Upper limits (μg/m³) of DEFRA bands 1 to 9 of each pollutant, band 10 above.
+///
+/// The bands are set for averages (8 hours for O₃, 1 hour for NO₂, 15 minutes
+/// for SO₂, 24 hours for particulates), so hourly values only approximate them.
+const DEFRA_BANDS: [(&str, [f64; 9]); 5] = [
+    (
+        "O3",
+        [33.0, 66.0, 100.0, 120.0, 140.0, 160.0, 187.0, 213.0, 240.0],
+    ),
+    (
+        "NO2",
+        [67.0, 134.0, 200.0, 267.0, 334.0, 400.0, 467.0, 534.0, 600.0],
+    ),
+    (
+        "SO2",
+        [
+            88.0, 177.0, 266.0, 354.0, 443.0, 532.0, 710.0, 887.0, 1064.0,
+        ],
+    ),
+    (
+        "PM2.5",
+        [11.0, 23.0, 35.0, 41.0, 47.0, 53.0, 58.0, 64.0, 70.0],
+    ),
+    (
+        "PM10",
+        [16.0, 33.0, 50.0, 58.0, 66.0, 75.0, 83.0, 91.0, 100.0],
+    ),
+];
+
+/// Returns the pollutants of a reading with their concentration (μg/m³), in the
+/// order of the WeatherAPI response.
+pub fn pollutants(air_quality: &AirQuality) -> [(&'static str, f64); 6] {
+    [
+        ("CO", air_quality.co),
+        ("NO2", air_quality.no2),
+        ("O3", air_quality.o3),
+        ("SO2", air_quality.so2),
+        ("PM2.5", air_quality.pm2_5),
+        ("PM10", air_quality.pm10),
+    ]
+}
+
+/// Returns the DEFRA Daily Air Quality Index band (1-10) of a concentration, or
+/// `None` for pollutants outside the index, such as CO.
+pub fn defra_band(pollutant: &str, value: f64) -> Option<i32> {
+    let (_, limits) = DEFRA_BANDS.iter().find(|(name, _)| *name == pollutant)?;
+    let below: usize = limits.iter().take_while(|limit| value > **limit).count();
+    Some(below as i32 + 1)
+}
+
+/// Returns the DEFRA band name of an index: Low (1-3), Moderate (4-6), High (7-9)
+/// or Very High (10).
+pub fn defra_text(index: i32) -> &'static str {
+    match index {
+        1..=3 => "Low",
+        4..=6 => "Moderate",
+        7..=9 => "High",
+        10 => "Very High",
+        _ => "Unknown",
+    }
+}
+
+/// Returns the DEFRA health advice of an index for the general population.
+pub fn defra_advice(index: i32) -> &'static str {
+    match index {
+        1..=3 => "Enjoy your usual outdoor activities.",
+        4..=6 => "At-risk people with symptoms should consider less strenuous activity outdoors.",
+        7..=9 => "With sore eyes, a cough or sore throat, consider less activity outdoors.",
+        10 => "Reduce physical exertion, particularly outdoors, especially with symptoms.",
+        _ => "No advice without an index.",
+    }
+}
+
+/// Returns the EPA health advice of a US EPA index (1-6).
+pub fn us_epa_advice(index: i32) -> &'static str {
+    match index {
+        1 => "Air quality is satisfactory, enjoy outdoor activities.",
+        2 => "Unusually sensitive people should limit prolonged or heavy exertion.",
+        3 => "Sensitive groups should reduce prolonged or heavy exertion outdoors.",
+        4 => "Everyone should reduce heavy exertion; sensitive groups should avoid it.",
+        5 => "Everyone should avoid heavy exertion; sensitive groups should stay indoors.",
+        6 => "Everyone should avoid all physical activity outdoors.",
+        _ => "No advice without an index.",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bands_pollutants() {
+        assert_eq!(defra_band("PM2.5", 10.5), Some(1));
+        assert_eq!(defra_band("PM2.5", 11.5), Some(2));
+        assert_eq!(defra_band("O3", 33.0), Some(1));
+        assert_eq!(defra_band("NO2", 700.0), Some(10));
+        assert_eq!(defra_band("CO", 290.0), None);
+        assert_eq!(defra_text(5), "Moderate");
+        assert_eq!(defra_text(10), "Very High");
+        assert!(us_epa_advice(3).starts_with("Sensitive groups"));
+    }
+}
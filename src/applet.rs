@@ -0,0 +1,110 @@
+use crate::{WeatherData, units};
+use std::io::Write;
+
+/// Output formats for desktop panel applets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AppletFormat {
+    /// Argos (GNOME) / BitBar / xbar: panel line, `---`, dropdown lines.
+    Argos,
+    /// XFCE Generic Monitor: `<txt>` panel text and `<tool>` tooltip.
+    Genmon,
+}
+
+impl AppletFormat {
+    /// Parses an applet format name as given to `--format`.
+    pub fn from_name(name: &str) -> Option<AppletFormat> {
+        match name {
+            "argos" | "bitbar" | "xbar" => Some(AppletFormat::Argos),
+            "genmon" => Some(AppletFormat::Genmon),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the short text shown in the panel, e.g. `21°C Sunny`.
+fn panel_text(weather: &WeatherData, units: units::Units) -> String {
+    format!(
+        "{} {}",
+        units.temp(
+            weather.current.temp_c.round(),
+            weather.current.temp_f.round()
+        ),
+        weather.current.condition.text
+    )
+}
+
+/// Returns the dropdown/tooltip lines: location and daily forecast.
+fn detail_lines(weather: &WeatherData, units: units::Units) -> Vec<String> {
+    let current = &weather.current;
+    let mut lines: Vec<String> = vec![
+        weather.location.to_string(),
+        format!(
+            "Feels like {}, Humidity {}%, Wind {} {}",
+            units.temp(current.feelslike_c, current.feelslike_f),
+            current.humidity,
+            units.speed(current.wind_kph, current.wind_mph),
+            current.wind_dir
+        ),
+    ];
+    for forecast_day in &weather.forecast.forecastday {
+        let day = &forecast_day.day;
+        lines.push(format!(
+            "{}: high {}, low {}, {}, rain {}",
+            forecast_day.date,
+            units.temp(day.maxtemp_c, day.maxtemp_f),
+            units.temp(day.mintemp_c, day.mintemp_f),
+            day.condition.text,
+            units.rain(
+                day.daily_chance_of_rain,
+                day.totalprecip_mm,
+                day.totalprecip_in
+            )
+        ));
+    }
+    lines
+}
+
+/// Escapes text for Pango markup used by genmon.
+fn escape_markup(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Writes the applet output in the given format.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data to render.
+/// * `format` - Applet syntax to produce.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the applet output.
+pub fn render<W: Write>(
+    weather: &WeatherData,
+    format: AppletFormat,
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    match format {
+        AppletFormat::Argos => {
+            // `|` starts the attribute list in Argos lines, so keep it out of the text.
+            writeln!(out, "{}", panel_text(weather, units).replace('|', "/"))?;
+            writeln!(out, "---")?;
+            for line in detail_lines(weather, units) {
+                writeln!(out, "{} | font=monospace", line.replace('|', "/"))?;
+            }
+        }
+        AppletFormat::Genmon => {
+            writeln!(
+                out,
+                "<txt>{}</txt>",
+                escape_markup(&panel_text(weather, units))
+            )?;
+            writeln!(
+                out,
+                "<tool>{}</tool>",
+                escape_markup(&detail_lines(weather, units).join("\n"))
+            )?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use crate::event::THUNDER_CODES;
+
+/// Width of the art, in columns.
+pub const ART_WIDTH: usize = 13;
+
+/// Sky drawn for a Weather API condition code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sky {
+    Sun,
+    Moon,
+    PartlyCloudy,
+    Cloudy,
+    Fog,
+    Rain,
+    Sleet,
+    Snow,
+    Thunder,
+}
+
+impl Sky {
+    /// Returns the sky of a condition code; unknown codes are drawn as clouds.
+    ///
+    /// # Arguments
+    /// * `code` - Weather API condition code (`Condition.code`).
+    /// * `is_day` - Whether the sun is up, to draw a clear night as the moon.
+    pub fn from_code(code: i32, is_day: bool) -> Sky {
+        match code {
+            1000 if is_day => Sky::Sun,
+            1000 => Sky::Moon,
+            1003 => Sky::PartlyCloudy,
+            1030 | 1135 | 1147 => Sky::Fog,
+            code if THUNDER_CODES.contains(&code) => Sky::Thunder,
+            1066 | 1114 | 1117 | 1210..=1225 | 1255 | 1258 => Sky::Snow,
+            1069 | 1072 | 1168 | 1171 | 1198..=1207 | 1237 | 1249 | 1252 | 1261 | 1264 => {
+                Sky::Sleet
+            }
+            1063 | 1150 | 1153 | 1180..=1195 | 1240..=1246 => Sky::Rain,
+            _ => Sky::Cloudy,
+        }
+    }
+
+    /// Single glyph for one-line forecasts.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            Sky::Sun => "☀",
+            Sky::Moon => "☾",
+            Sky::PartlyCloudy => "⛅",
+            Sky::Cloudy => "☁",
+            Sky::Fog => "🌫",
+            Sky::Rain => "🌧",
+            Sky::Sleet => "🌨",
+            Sky::Snow => "❄",
+            Sky::Thunder => "⛈",
+        }
+    }
+
+    /// Five lines of art, [`ART_WIDTH`] columns wide, after wttr.in.
+    pub fn art(self) -> [&'static str; 5] {
+        match self {
+            Sky::Sun => [
+                r"    \   /    ",
+                r"     .-.     ",
+                r"  ― (   ) ―  ",
+                r"     `-’     ",
+                r"    /   \    ",
+            ],
+            Sky::Moon => [
+                r"     _..     ",
+                r"   .' .-'    ",
+                r"  (  (       ",
+                r"   '._'-._   ",
+                r"             ",
+            ],
+            Sky::PartlyCloudy => [
+                r#"   \  /      "#,
+                r#" _ /"".-.    "#,
+                r#"   \_(   ).  "#,
+                r#"   /(___(__) "#,
+                r#"             "#,
+            ],
+            Sky::Cloudy => [
+                r"             ",
+                r"     .--.    ",
+                r"  .-(    ).  ",
+                r" (___.__)__) ",
+                r"             ",
+            ],
+            Sky::Fog => [
+                r"             ",
+                r" _ - _ - _ - ",
+                r"  _ - _ - _  ",
+                r" _ - _ - _ - ",
+                r"             ",
+            ],
+            Sky::Rain => [
+                r"     .-.     ",
+                r"    (   ).   ",
+                r"   (___(__)  ",
+                r"    ‘ ‘ ‘ ‘  ",
+                r"   ‘ ‘ ‘ ‘   ",
+            ],
+            Sky::Sleet => [
+                r"     .-.     ",
+                r"    (   ).   ",
+                r"   (___(__)  ",
+                r"    ‘ * ‘ *  ",
+                r"   * ‘ * ‘   ",
+            ],
+            Sky::Snow => [
+                r"     .-.     ",
+                r"    (   ).   ",
+                r"   (___(__)  ",
+                r"    *  *  *  ",
+                r"   *  *  *   ",
+            ],
+            Sky::Thunder => [
+                r"     .-.     ",
+                r"    (   ).   ",
+                r"   (___(__)  ",
+                r"    ‘‘/_‘‘   ",
+                r"    ‘ /‘ ‘   ",
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_condition_codes() {
+        assert_eq!(Sky::from_code(1000, true), Sky::Sun);
+        assert_eq!(Sky::from_code(1000, false), Sky::Moon);
+        assert_eq!(Sky::from_code(1063, true), Sky::Rain);
+        assert_eq!(Sky::from_code(1219, true), Sky::Snow);
+        assert_eq!(Sky::from_code(1201, true), Sky::Sleet);
+        assert_eq!(Sky::from_code(1276, true), Sky::Thunder);
+        assert_eq!(Sky::from_code(1009, true), Sky::Cloudy);
+        assert_eq!(Sky::from_code(9999, true), Sky::Cloudy);
+        for sky in [
+            Sky::Sun,
+            Sky::Moon,
+            Sky::PartlyCloudy,
+            Sky::Fog,
+            Sky::Thunder,
+        ] {
+            assert!(
+                sky.art()
+                    .iter()
+                    .all(|line| crate::layout::display_width(line) == ART_WIDTH)
+            );
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use serde::Serialize;
+
+use crate::{Hour, WeatherData, quakes};
+
+/// Latitude of the geomagnetic north pole (IGRF-13 dipole, 2025).
+const POLE_LAT: f64 = 80.8;
+/// Longitude of the geomagnetic north pole.
+const POLE_LON: f64 = -72.6;
+
+/// Three-hour period of the planetary Kp index forecast.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct KpPeriod {
+    /// Start of the period, UTC (`YYYY-MM-DD HH:MM:SS`).
+    pub time_tag: String,
+    /// Planetary K index, 0 to 9.
+    pub kp: f64,
+    /// `observed`, `estimated` or `predicted`.
+    pub observed: String,
+}
+
+/// Aurora visibility estimate for the coming night.
+#[derive(Serialize, Debug)]
+pub struct AuroraOutlook {
+    /// Highest Kp forecast during the dark hours.
+    pub kp: f64,
+    /// Geomagnetic latitude of the location, in degrees.
+    pub geomagnetic_lat: f64,
+    /// Kp at which the auroral oval reaches overhead of the location.
+    pub overhead_kp: f64,
+    /// Average cloud cover during the dark hours, in percent.
+    pub cloud: f64,
+    /// Moon illumination tonight, in percent.
+    pub moon_illumination: u32,
+    /// First dark hour (`YYYY-MM-DD HH:MM`), `None` if the sun does not set.
+    pub dark_from: Option<String>,
+    /// Last dark hour (`YYYY-MM-DD HH:MM`).
+    pub dark_until: Option<String>,
+    /// Estimated chance of seeing the aurora, in percent.
+    pub probability: u32,
+}
+
+/// Fetches the 3-day planetary Kp index forecast of the NOAA Space Weather Prediction Center.
+#[cfg(feature = "blocking")]
+pub fn fetch_kp_forecast() -> Result<Vec<KpPeriod>, crate::WeatherError> {
+    let response: reqwest::blocking::Response = crate::http::send(
+        crate::http::client()?
+            .get("https://services.swpc.noaa.gov/products/noaa-planetary-k-index-forecast.json"),
+    )?;
+    if response.status() != 200 {
+        return Err(crate::WeatherError::Status {
+            status: response.status().as_u16(),
+            message: None,
+        });
+    }
+    parse_kp_forecast(&response.text()?)
+}
+
+/// Parses the SWPC Kp forecast, a table whose first row holds the column names.
+///
+/// ```text
+/// [["time_tag","kp","observed","noaa_scale"],["2025-08-01 00:00:00","2.67","observed",null],...]
+/// ```
+pub fn parse_kp_forecast(body: &str) -> Result<Vec<KpPeriod>, crate::WeatherError> {
+    let rows: Vec<Vec<serde_json::Value>> = serde_json::from_str(body)?;
+    let text = |value: Option<&serde_json::Value>| -> String {
+        match value {
+            Some(serde_json::Value::String(text)) => text.clone(),
+            Some(serde_json::Value::Number(number)) => number.to_string(),
+            _ => String::new(),
+        }
+    };
+    Ok(rows
+        .iter()
+        .skip(1)
+        .filter_map(|row| {
+            Some(KpPeriod {
+                time_tag: text(row.first()),
+                kp: text(row.get(1)).parse().ok()?,
+                observed: text(row.get(2)),
+            })
+        })
+        .collect())
+}
+
+/// Returns the geomagnetic (dipole) latitude of a location, in degrees.
+pub fn geomagnetic_latitude(lat: f64, lon: f64) -> f64 {
+    let (lat, lon) = (lat.to_radians(), lon.to_radians());
+    let (pole_lat, pole_lon) = (POLE_LAT.to_radians(), POLE_LON.to_radians());
+    (lat.sin() * pole_lat.sin() + lat.cos() * pole_lat.cos() * (lon - pole_lon).cos())
+        .asin()
+        .to_degrees()
+}
+
+/// Returns the geomagnetic latitude of the equatorward edge of the auroral oval at a Kp.
+///
+/// Linear fit of the SWPC Kp map: 66.5° at Kp 0 down to 48.1° at Kp 9.
+pub fn oval_boundary(kp: f64) -> f64 {
+    66.5 - 2.045 * kp
+}
+
+/// Estimates tonight's aurora visibility at the forecast location.
+///
+/// Tonight is the first run of dark hours in the next 24 hours. The geomagnetic
+/// chance is full 2° inside the oval and fades to none 5° outside it, where the
+/// aurora sits below the horizon; it is then reduced by cloud cover and moonlight.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `kp_forecast` - Kp periods from [`fetch_kp_forecast`].
+pub fn outlook(weather: &WeatherData, kp_forecast: &[KpPeriod]) -> AuroraOutlook {
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    let dark: Vec<&Hour> = weather
+        .hours()
+        .filter(|hour| hour.time_epoch > from)
+        .take(24)
+        .skip_while(|hour| hour.is_day == 1)
+        .take_while(|hour| hour.is_day == 0)
+        .collect();
+
+    let kp: f64 = dark
+        .iter()
+        .filter_map(|hour| kp_at(kp_forecast, hour.time_epoch))
+        .fold(0.0, f64::max);
+    let cloud: f64 = if dark.is_empty() {
+        100.0
+    } else {
+        dark.iter().map(|hour| f64::from(hour.cloud)).sum::<f64>() / dark.len() as f64
+    };
+    let moon_illumination: u32 = dark
+        .first()
+        .and_then(|hour| {
+            weather
+                .forecast
+                .forecastday
+                .iter()
+                .find(|day| hour.time.starts_with(&day.date))
+        })
+        .map_or(0, |day| day.astro.moon_illumination);
+
+    let geomagnetic_lat: f64 =
+        geomagnetic_latitude(weather.location.lat, weather.location.lon).abs();
+    let margin: f64 = geomagnetic_lat - oval_boundary(kp);
+    let geomagnetic_chance: f64 = ((margin + 5.0) / 7.0).clamp(0.0, 1.0);
+    let clear_sky: f64 = 1.0 - cloud / 100.0;
+    let moonlight: f64 = 1.0 - 0.3 * f64::from(moon_illumination) / 100.0;
+    let probability: f64 = if dark.is_empty() {
+        0.0
+    } else {
+        100.0 * geomagnetic_chance * clear_sky * moonlight
+    };
+    AuroraOutlook {
+        kp,
+        geomagnetic_lat,
+        overhead_kp: ((66.5 - geomagnetic_lat) / 2.045).max(0.0),
+        cloud,
+        moon_illumination,
+        dark_from: dark.first().map(|hour| hour.time.clone()),
+        dark_until: dark.last().map(|hour| hour.time.clone()),
+        probability: probability.round() as u32,
+    }
+}
+
+/// Returns the Kp of the period containing a UNIX time.
+pub fn kp_at(kp_forecast: &[KpPeriod], epoch: i64) -> Option<f64> {
+    // `format_utc` gives `YYYY-MM-DD HH:MM UTC`, comparable with the time tags' prefix.
+    let time: String = quakes::format_utc(epoch * 1000);
+    kp_forecast
+        .iter()
+        .rev()
+        .find(|period| period.time_tag.get(..16) <= time.get(..16))
+        .map(|period| period.kp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn estimates_visibility() {
+        let kp_forecast: Vec<KpPeriod> = parse_kp_forecast(
+            r#"[["time_tag","kp","observed","noaa_scale"],
+                ["2025-08-01 12:00:00","2.00","observed",null],
+                ["2025-08-01 21:00:00","7.33","predicted","G3"],
+                ["2025-08-02 03:00:00","4.00","predicted",null]]"#,
+        )
+        .unwrap();
+        assert_eq!(kp_forecast.len(), 3);
+        assert_eq!(kp_forecast[1].observed, "predicted");
+
+        // London lies near 53° geomagnetic, inside the oval from about Kp 6.5.
+        let geomagnetic_lat: f64 = geomagnetic_latitude(51.5, -0.13);
+        assert!((53.0..55.0).contains(&geomagnetic_lat));
+        assert!((geomagnetic_latitude(-51.5, 179.0) + 54.0).abs() < 5.0);
+
+        let weather: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let outlook: AuroraOutlook = outlook(&weather, &kp_forecast);
+        assert_eq!(outlook.kp, 7.33);
+        assert_eq!(outlook.dark_from.as_deref(), Some("2025-08-01 21:00"));
+        assert_eq!(outlook.dark_until.as_deref(), Some("2025-08-02 05:00"));
+        // Clear skies, half moon.
+        assert_eq!(outlook.probability, 84);
+    }
+}
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{Hour, WeatherData, WeatherError};
+
+/// Names of the sources accepted by `--source`.
+pub const SOURCES: [&str; 2] = ["awattar-at", "awattar-de"];
+
+/// Value of an auxiliary series over a period.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct AuxValue {
+    /// Start of the period, UNIX time in seconds.
+    pub start_epoch: i64,
+    /// End of the period (exclusive).
+    pub end_epoch: i64,
+    pub value: f64,
+}
+
+/// Non-weather data shown alongside the hourly forecast, such as energy prices.
+///
+/// Sources are fetched on demand by the subcommands that show them, never for the
+/// main report.
+#[cfg(feature = "blocking")]
+pub trait AuxiliarySource {
+    /// Name of the source, one of [`SOURCES`].
+    fn name(&self) -> &'static str;
+
+    /// Column label and unit of the values, e.g. `("Price", "ct/kWh")`.
+    fn label(&self) -> (&'static str, &'static str);
+
+    /// Fetches the values of the periods overlapping a time range.
+    ///
+    /// # Arguments
+    /// * `from_epoch` - Start of the range, UNIX time in seconds.
+    /// * `until_epoch` - End of the range.
+    fn fetch(&self, from_epoch: i64, until_epoch: i64) -> Result<Vec<AuxValue>, WeatherError>;
+}
+
+/// Returns the source with a name from [`SOURCES`].
+#[cfg(feature = "blocking")]
+pub fn source(name: &str) -> Option<Box<dyn AuxiliarySource>> {
+    match name {
+        "awattar-at" => Some(Box::new(Awattar { country: "at" })),
+        "awattar-de" => Some(Box::new(Awattar { country: "de" })),
+        _ => None,
+    }
+}
+
+/// Hourly day-ahead electricity prices of aWATTar, for Austria or Germany.
+///
+/// Prices are the EPEX spot market's, without taxes or grid fees, and are
+/// published for the next day around 14:00 CET.
+#[derive(Debug)]
+pub struct Awattar {
+    /// `at` or `de`.
+    pub country: &'static str,
+}
+
+#[cfg(feature = "blocking")]
+impl AuxiliarySource for Awattar {
+    fn name(&self) -> &'static str {
+        match self.country {
+            "at" => "awattar-at",
+            _ => "awattar-de",
+        }
+    }
+
+    fn label(&self) -> (&'static str, &'static str) {
+        ("Price", "ct/kWh")
+    }
+
+    fn fetch(&self, from_epoch: i64, until_epoch: i64) -> Result<Vec<AuxValue>, WeatherError> {
+        let url: String = format!(
+            "https://api.awattar.{}/v1/marketdata?start={}&end={}",
+            self.country,
+            from_epoch * 1000,
+            until_epoch * 1000
+        );
+        let response: reqwest::blocking::Response =
+            crate::http::send(crate::http::client()?.get(url))?;
+        if response.status() != 200 {
+            return Err(WeatherError::Status {
+                status: response.status().as_u16(),
+                message: None,
+            });
+        }
+        parse_awattar(&response.text()?)
+    }
+}
+
+/// Market data of the aWATTar API.
+#[derive(Deserialize, Debug)]
+struct MarketData {
+    data: Vec<MarketPrice>,
+}
+
+/// Price of one period, in EUR/MWh.
+#[derive(Deserialize, Debug)]
+struct MarketPrice {
+    start_timestamp: i64,
+    end_timestamp: i64,
+    marketprice: f64,
+}
+
+/// Parses aWATTar market data into prices in ct/kWh.
+///
+/// ```text
+/// {"object":"list","data":[{"start_timestamp":1754002800000,"end_timestamp":1754006400000,
+///   "marketprice":92.5,"unit":"Eur/MWh"},...]}
+/// ```
+pub fn parse_awattar(body: &str) -> Result<Vec<AuxValue>, WeatherError> {
+    let market: MarketData = serde_json::from_str(body)?;
+    Ok(market
+        .data
+        .iter()
+        .map(|price| AuxValue {
+            start_epoch: price.start_timestamp / 1000,
+            end_epoch: price.end_timestamp / 1000,
+            // EUR/MWh to ct/kWh.
+            value: price.marketprice / 10.0,
+        })
+        .collect())
+}
+
+/// An hour of the forecast with the auxiliary value of its period.
+#[derive(Serialize, Debug)]
+pub struct AuxHour<'a> {
+    #[serde(skip)]
+    pub hour: &'a Hour,
+    /// Local time (`YYYY-MM-DD HH:MM`).
+    pub time: &'a str,
+    pub temp_c: f64,
+    /// Shortwave solar radiation (W/m²).
+    pub short_rad: f64,
+    /// `None` where the source has no value yet, e.g. prices after tomorrow.
+    pub value: Option<f64>,
+}
+
+/// Pairs the coming forecast hours with the auxiliary values.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `values` - Values fetched from a source.
+/// * `hours` - Number of hours, from the hour in progress.
+pub fn alongside<'a>(
+    weather: &'a WeatherData,
+    values: &[AuxValue],
+    hours: usize,
+) -> Vec<AuxHour<'a>> {
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    weather
+        .hours()
+        .filter(|hour| hour.time_epoch > from)
+        .take(hours)
+        .map(|hour| AuxHour {
+            hour,
+            time: &hour.time,
+            temp_c: hour.temp_c,
+            short_rad: hour.short_rad,
+            value: values
+                .iter()
+                .find(|value| (value.start_epoch..value.end_epoch).contains(&hour.time_epoch))
+                .map(|value| value.value),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_prices_with_hours() {
+        // 2025-08-01 14:00 and 15:00 BST.
+        let prices: Vec<AuxValue> = parse_awattar(
+            r#"{"object":"list","data":[
+                {"start_timestamp":1754053200000,"end_timestamp":1754056800000,"marketprice":92.5,"unit":"Eur/MWh"},
+                {"start_timestamp":1754056800000,"end_timestamp":1754060400000,"marketprice":-3.1,"unit":"Eur/MWh"}
+            ],"url":"/de/v1/marketdata"}"#,
+        )
+        .unwrap();
+        assert_eq!(prices[0].start_epoch, 1_754_053_200);
+        assert_eq!(prices[1].value, -0.31);
+
+        let weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        let hours: Vec<AuxHour> = alongside(&weather, &prices, 3);
+        assert_eq!(hours[0].time, "2025-08-01 14:00");
+        assert_eq!(
+            hours.iter().map(|hour| hour.value).collect::<Vec<_>>(),
+            [Some(9.25), Some(-0.31), None]
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::session::Session;
+
+/// Default time (minutes) a cached response is served without asking the API.
+pub const DEFAULT_TTL_MINUTES: u32 = 15;
+
+/// Forecast responses stored per query in `$XDG_CACHE_HOME/mosm/`.
+///
+/// Entries use the session file format, so a cached response can also be replayed.
+#[derive(Debug)]
+pub struct Cache {
+    dir: PathBuf,
+    ttl_secs: u64,
+}
+
+impl Cache {
+    /// Creates a cache in a directory, created on first store.
+    ///
+    /// # Arguments
+    /// * `dir` - Directory holding the entries.
+    /// * `ttl_minutes` - Age up to which entries are fresh.
+    pub fn new(dir: PathBuf, ttl_minutes: u32) -> Cache {
+        Cache {
+            dir,
+            ttl_secs: u64::from(ttl_minutes) * 60,
+        }
+    }
+
+    /// Returns `$XDG_CACHE_HOME/mosm`, else `~/.cache/mosm`.
+    pub fn default_dir() -> Option<PathBuf> {
+        let cache_home: PathBuf = match std::env::var_os("XDG_CACHE_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".cache"),
+        };
+        Some(cache_home.join("mosm"))
+    }
+
+    /// Path of the entry for a request.
+    ///
+    /// Queries differing only in case or surrounding whitespace share an entry.
+    fn path(&self, query: &str, days: u32, lang: Option<&str>) -> PathBuf {
+        let key: String = format!(
+            "{}|{days}|{}",
+            query.trim().to_lowercase(),
+            lang.unwrap_or("")
+        );
+        // FNV-1a, stable across builds unlike `DefaultHasher`.
+        let hash: u64 = key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        self.dir.join(format!("forecast-{hash:016x}.json"))
+    }
+
+    /// Reads the entry for a request, however old.
+    pub fn load(&self, query: &str, days: u32, lang: Option<&str>) -> Option<Session> {
+        let path: PathBuf = self.path(query, days, lang);
+        Session::load(path.to_str()?).ok()
+    }
+
+    /// Writes the entry for a request, replacing any previous one.
+    pub fn store(&self, session: &Session, lang: Option<&str>) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let path: PathBuf = self.path(&session.query, session.days, lang);
+        session.save(&path.to_string_lossy())
+    }
+
+    /// Returns the age of an entry in seconds.
+    pub fn age_secs(session: &Session) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|now| now.as_secs())
+            .unwrap_or(0)
+            .saturating_sub(session.recorded_at)
+    }
+
+    /// Returns `true` if the entry is younger than the TTL.
+    pub fn is_fresh(&self, session: &Session) -> bool {
+        Cache::age_secs(session) < self.ttl_secs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_and_expires_entries() {
+        let dir: PathBuf =
+            std::env::temp_dir().join(format!("mosm-cache-test-{}", std::process::id()));
+        let cache: Cache = Cache::new(dir.clone(), DEFAULT_TTL_MINUTES);
+        let mut session: Session = Session::new("London", 3, serde_json::json!({"ok": true}));
+        cache.store(&session, None).unwrap();
+
+        let cached: Session = cache.load(" london ", 3, None).unwrap();
+        assert_eq!(cached.response, serde_json::json!({"ok": true}));
+        assert!(cache.is_fresh(&cached));
+        assert!(cache.load("London", 2, None).is_none());
+        assert!(cache.load("London", 3, Some("fr")).is_none());
+
+        session.recorded_at -= 16 * 60;
+        assert!(!cache.is_fresh(&session));
+        assert!(!Cache::new(dir.clone(), 0).is_fresh(&cached));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
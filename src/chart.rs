@@ -0,0 +1,134 @@
+use crate::{Hour, WeatherData, hourly};
+use std::io::Write;
+
+/// Height of the plot, in lines of braille characters (4 dots each).
+pub const HEIGHT: usize = 8;
+
+/// Dot bits of a braille character, by column (left, right) and row from the top.
+const DOTS: [[u32; 4]; 2] = [[0x01, 0x02, 0x04, 0x40], [0x08, 0x10, 0x20, 0x80]];
+
+/// Plots values as a line of braille dots, two values per character.
+///
+/// Consecutive values are joined by filling the dots between them, so steep
+/// changes stay connected.
+///
+/// # Returns
+/// [`HEIGHT`] lines, top first, and the `(min, max)` of the values.
+pub fn plot(values: &[f64]) -> (Vec<String>, f64, f64) {
+    let min: f64 = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max: f64 = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let rows: usize = HEIGHT * 4;
+    let span: f64 = if max > min { max - min } else { 1.0 };
+    // Dot row of each value, 0 at the top.
+    let levels: Vec<usize> = values
+        .iter()
+        .map(|value| ((max - value) / span * (rows - 1) as f64).round() as usize)
+        .collect();
+    let mut cells: Vec<Vec<u32>> = vec![vec![0; values.len().div_ceil(2)]; HEIGHT];
+    for (x, level) in levels.iter().enumerate() {
+        let previous: usize = if x > 0 { levels[x - 1] } else { *level };
+        // Join to the previous value, without redrawing its dot.
+        let (top, bottom) = if previous < *level {
+            ((previous + 1).min(*level), *level)
+        } else {
+            (*level, previous.saturating_sub(1).max(*level))
+        };
+        for row in top..=bottom {
+            cells[row / 4][x / 2] |= DOTS[x % 2][row % 4];
+        }
+    }
+    let lines: Vec<String> = cells
+        .iter()
+        .map(|line| {
+            line.iter()
+                .map(|bits| char::from_u32(0x2800 + bits).unwrap_or(' '))
+                .collect()
+        })
+        .collect();
+    (lines, min, max)
+}
+
+/// Writes a line chart of the hourly temperature, with min/max labels on the
+/// axis and the days below.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `days` - Number of days to plot, from the hour in progress.
+/// * `out` - Destination of the rendered chart.
+pub fn render<W: Write>(weather: &WeatherData, days: usize, out: &mut W) -> std::io::Result<()> {
+    let hours: Vec<&Hour> = hourly::upcoming(weather, days * 24);
+    if hours.is_empty() {
+        return writeln!(out, "No hourly forecast to plot.");
+    }
+    let temps: Vec<f64> = hours.iter().map(|hour| hour.temp_c).collect();
+    let (lines, min, max) = plot(&temps);
+    let labels: [String; 2] = [format!("{max:.1}°C"), format!("{min:.1}°C")];
+    let label_width: usize = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    writeln!(out, "{}\nTemperature", weather.location)?;
+    for (i, line) in lines.iter().enumerate() {
+        let label: &str = match i {
+            0 => &labels[0],
+            _ if i == HEIGHT - 1 => &labels[1],
+            _ => "",
+        };
+        let tick: char = if label.is_empty() { '│' } else { '┤' };
+        writeln!(out, "{label:>label_width$} {tick}{line}")?;
+    }
+    let width: usize = lines[0].chars().count();
+    writeln!(out, "{:label_width$} └{}", "", "─".repeat(width))?;
+
+    // Each midnight under its column, after the first hour if there is room.
+    let midnights: Vec<(usize, &str)> = hours
+        .iter()
+        .enumerate()
+        .filter(|(_, hour)| hour.time.ends_with("00:00"))
+        .map(|(x, hour)| (x / 2, &hour.time[5..10]))
+        .collect();
+    let first: &str = &hours[0].time[11..];
+    let mut axis: String = String::new();
+    if midnights
+        .first()
+        .is_none_or(|(column, _)| *column > first.len())
+    {
+        axis.push_str(first);
+    }
+    for (column, date) in midnights {
+        if axis.is_empty() || column > axis.len() {
+            axis = format!("{axis:<column$}{date}");
+        }
+    }
+    writeln!(out, "{:label_width$}  {axis}", "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plots_braille_lines() {
+        let (lines, min, max) = plot(&[0.0, 10.0, 10.0, 0.0]);
+        assert_eq!((min, max), (0.0, 10.0));
+        assert_eq!(lines.len(), HEIGHT);
+        assert!(lines.iter().all(|line| line.chars().count() == 2));
+        // Rising and falling edges are filled in, the highs and lows dotted.
+        assert_eq!(lines[0], "⢸⢱");
+        assert_eq!(lines[1], "⢸⢸");
+        assert_eq!(lines[HEIGHT - 1], "⡸⢸");
+
+        let weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        let mut out: Vec<u8> = Vec::new();
+        render(&weather, 2, &mut out).unwrap();
+        let chart: String = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = chart.lines().collect();
+        assert_eq!(lines.len(), HEIGHT + 4);
+        assert!(lines[2].ends_with(|c: char| ('\u{2800}'..='\u{28FF}').contains(&c)));
+        // Midnight is too close to the first hour to label both.
+        assert_eq!(lines.last().unwrap().trim(), "08-02       08-03");
+    }
+}
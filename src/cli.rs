@@ -0,0 +1,352 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Error raised while parsing command-line arguments.
+#[derive(Debug, PartialEq)]
+pub enum CliError {
+    /// `--help` was requested; holds the help text to print.
+    Help(String),
+    /// The arguments are invalid; holds the reason.
+    Invalid(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Help(help) => write!(f, "{help}"),
+            CliError::Invalid(reason) => write!(f, "{reason}"),
+        }
+    }
+}
+
+/// A `--name` flag or `--name <VALUE>` option.
+#[derive(Debug, Clone)]
+pub struct Arg {
+    /// Long name, used as `--name`.
+    pub name: &'static str,
+    /// Placeholder shown in help; `None` for boolean flags.
+    pub value_name: Option<&'static str>,
+    /// One-line description shown in help.
+    pub help: &'static str,
+    /// Global options of the root command are also accepted by subcommands.
+    pub global: bool,
+}
+
+impl Arg {
+    /// Creates a boolean flag.
+    pub fn flag(name: &'static str, help: &'static str) -> Arg {
+        Arg {
+            name,
+            value_name: None,
+            help,
+            global: false,
+        }
+    }
+
+    /// Creates an option taking a value.
+    pub fn option(name: &'static str, value_name: &'static str, help: &'static str) -> Arg {
+        Arg {
+            name,
+            value_name: Some(value_name),
+            help,
+            global: false,
+        }
+    }
+
+    /// Makes a root option available to every subcommand.
+    pub fn global(mut self) -> Arg {
+        self.global = true;
+        self
+    }
+}
+
+/// A command with its options, positional argument and subcommands.
+#[derive(Debug, Clone)]
+pub struct Command {
+    name: &'static str,
+    about: &'static str,
+    positional: Option<&'static str>,
+    /// Whether the positional argument may be given several times.
+    repeated: bool,
+    args: Vec<Arg>,
+    subcommands: Vec<Command>,
+}
+
+/// Result of a successful parse.
+#[derive(Debug, Default, PartialEq)]
+pub struct Matches {
+    /// Name of the selected subcommand, if any.
+    pub subcommand: Option<&'static str>,
+    positionals: Vec<String>,
+    values: Vec<(&'static str, Option<String>)>,
+}
+
+impl Matches {
+    /// Returns `true` if the flag was given.
+    pub fn flag(&self, name: &str) -> bool {
+        self.values.iter().any(|(arg, _)| *arg == name)
+    }
+
+    /// Returns the value of an option (the last one if given several times).
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(arg, _)| *arg == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Parses the value of an option.
+    ///
+    /// # Returns
+    /// `Ok(None)` if the option was not given, or `Err` if the value does not parse.
+    pub fn parse_value<T: FromStr>(&self, name: &str) -> Result<Option<T>, CliError> {
+        match self.value(name) {
+            None => Ok(None),
+            Some(value) => value
+                .parse::<T>()
+                .map(Some)
+                .map_err(|_| CliError::Invalid(format!("Invalid value for --{name}: {value}"))),
+        }
+    }
+
+    /// Returns the positional argument (the location query), if given.
+    pub fn positional(&self) -> Option<&str> {
+        self.positionals.first().map(String::as_str)
+    }
+
+    /// Returns all positional arguments, for commands accepting several.
+    pub fn positionals(&self) -> &[String] {
+        &self.positionals
+    }
+}
+
+impl Command {
+    /// Creates a command without options.
+    pub fn new(name: &'static str, about: &'static str) -> Command {
+        Command {
+            name,
+            about,
+            positional: None,
+            repeated: false,
+            args: Vec::new(),
+            subcommands: Vec::new(),
+        }
+    }
+
+    /// Accepts one positional argument, shown as `name` in help.
+    pub fn positional(mut self, name: &'static str) -> Command {
+        self.positional = Some(name);
+        self
+    }
+
+    /// Accepts several positional arguments, shown as `name...` in help.
+    pub fn positionals(mut self, name: &'static str) -> Command {
+        self.positional = Some(name);
+        self.repeated = true;
+        self
+    }
+
+    /// Adds an option or flag.
+    pub fn arg(mut self, arg: Arg) -> Command {
+        self.args.push(arg);
+        self
+    }
+
+    /// Adds a subcommand.
+    pub fn subcommand(mut self, subcommand: Command) -> Command {
+        self.subcommands.push(subcommand);
+        self
+    }
+
+    /// Parses the arguments following the program name.
+    ///
+    /// The first positional argument selects a subcommand if it matches one by name.
+    ///
+    /// # Arguments
+    /// * `args` - Command-line arguments without the program name.
+    ///
+    /// # Returns
+    /// The parsed `Matches`, or a `CliError` for `--help` and invalid input.
+    pub fn parse(&self, args: &[String]) -> Result<Matches, CliError> {
+        let mut matches: Matches = Matches::default();
+        let mut command: &Command = self;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--help" || arg == "-h" {
+                return Err(CliError::Help(command.help(self)));
+            }
+            if let Some(option) = arg.strip_prefix("--") {
+                let (name, inline_value) = match option.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_owned())),
+                    None => (option, None),
+                };
+                let Some(spec) = command
+                    .args
+                    .iter()
+                    .chain(self.args.iter().filter(|spec| spec.global))
+                    .find(|spec| spec.name == name)
+                else {
+                    return Err(CliError::Invalid(format!("Unknown option --{name}")));
+                };
+                let value: Option<String> = match (spec.value_name, inline_value) {
+                    (None, None) => None,
+                    (None, Some(_)) => {
+                        return Err(CliError::Invalid(format!("--{name} does not take a value")));
+                    }
+                    (Some(_), Some(value)) => Some(value),
+                    (Some(value_name), None) => Some(args.next().cloned().ok_or_else(|| {
+                        CliError::Invalid(format!("Missing {value_name} for --{name}"))
+                    })?),
+                };
+                matches.values.push((spec.name, value));
+                continue;
+            }
+            if matches.subcommand.is_none()
+                && matches.positionals.is_empty()
+                && let Some(subcommand) = self.subcommands.iter().find(|sub| sub.name == arg)
+            {
+                matches.subcommand = Some(subcommand.name);
+                command = subcommand;
+                continue;
+            }
+            if command.positional.is_none() || !(command.repeated || matches.positionals.is_empty())
+            {
+                return Err(CliError::Invalid(format!(
+                    "Unexpected argument {arg:?}, use \"\" quotations if location have whitespace."
+                )));
+            }
+            if !arg.trim().is_empty() {
+                matches.positionals.push(arg.clone());
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Renders the help text of this command.
+    ///
+    /// # Arguments
+    /// * `root` - Root command, whose options are listed as global options.
+    pub fn help(&self, root: &Command) -> String {
+        let mut usage: String = if std::ptr::eq(self, root) {
+            root.name.to_owned()
+        } else {
+            format!("{} {}", root.name, self.name)
+        };
+        if !self.subcommands.is_empty() {
+            usage.push_str(" [COMMAND]");
+        }
+        if let Some(positional) = self.positional {
+            usage.push_str(&format!(" [{positional}]"));
+            if self.repeated {
+                usage.push_str("...");
+            }
+        }
+        usage.push_str(" [OPTIONS]");
+
+        let mut help: String = format!("{}\n\nUsage: {usage}\n", self.about);
+        if !self.subcommands.is_empty() {
+            help.push_str("\nCommands:\n");
+            for subcommand in &self.subcommands {
+                help.push_str(&format!("  {:<12}{}\n", subcommand.name, subcommand.about));
+            }
+        }
+        let mut args: Vec<&Arg> = self.args.iter().collect();
+        if !std::ptr::eq(self, root) {
+            args.extend(root.args.iter().filter(|arg| arg.global));
+        }
+        args.push(&HELP_ARG);
+        help.push_str("\nOptions:\n");
+        for arg in args {
+            let name: String = match arg.value_name {
+                Some(value_name) => format!("--{} <{value_name}>", arg.name),
+                None => format!("--{}", arg.name),
+            };
+            help.push_str(&format!("  {name:<28}{}\n", arg.help));
+        }
+        help
+    }
+}
+
+/// `--help`, handled by the parser itself.
+const HELP_ARG: Arg = Arg {
+    name: "help",
+    value_name: None,
+    help: "Print help",
+    global: true,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command() -> Command {
+        Command::new("mosm-rs", "Weather")
+            .positionals("LOCATION")
+            .arg(Arg::option("days", "N", "Forecast days").global())
+            .arg(Arg::flag("json", "Print JSON"))
+            .subcommand(
+                Command::new("quakes", "Earthquakes")
+                    .positional("LOCATION")
+                    .arg(Arg::option("radius", "KM", "Search radius")),
+            )
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_subcommand_options_and_globals() {
+        let matches = command()
+            .parse(&args(&["quakes", "Tokyo", "--radius=500", "--days", "2"]))
+            .unwrap();
+        assert_eq!(matches.subcommand, Some("quakes"));
+        assert_eq!(matches.positional(), Some("Tokyo"));
+        assert_eq!(matches.parse_value::<f64>("radius"), Ok(Some(500.0)));
+        assert_eq!(matches.parse_value::<u32>("days"), Ok(Some(2)));
+        assert!(!matches.flag("json"));
+    }
+
+    #[test]
+    fn location_disables_subcommands() {
+        let matches = command().parse(&args(&["London", "--json"])).unwrap();
+        assert_eq!(matches.subcommand, None);
+        assert_eq!(matches.positional(), Some("London"));
+        assert!(matches.flag("json"));
+        assert!(
+            command()
+                .parse(&args(&["quakes", "Tokyo", "Paris"]))
+                .is_err()
+        );
+
+        // The root command takes several locations, a subcommand name included.
+        let matches = command()
+            .parse(&args(&["London", "quakes", "New York"]))
+            .unwrap();
+        assert_eq!(matches.subcommand, None);
+        assert_eq!(matches.positionals(), ["London", "quakes", "New York"]);
+    }
+
+    #[test]
+    fn reports_invalid_input() {
+        assert!(matches!(
+            command().parse(&args(&["--radius", "5"])),
+            Err(CliError::Invalid(_))
+        ));
+        assert!(matches!(
+            command().parse(&args(&["--days"])),
+            Err(CliError::Invalid(_))
+        ));
+        assert!(matches!(
+            command().parse(&args(&["quakes", "--json"])),
+            Err(CliError::Invalid(_))
+        ));
+        assert!(matches!(
+            command().parse(&args(&["quakes", "--help"])),
+            Err(CliError::Help(_))
+        ));
+        let matches = command().parse(&args(&["--days", "x"])).unwrap();
+        assert!(matches.parse_value::<u32>("days").is_err());
+    }
+}
@@ -0,0 +1,459 @@
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::{FutureData, HistoryData, WeatherData, WeatherError};
+
+/// Query locating the caller by the public IP address of the request.
+pub const AUTO_IP_QUERY: &str = "auto:ip";
+
+/// Days ahead of today covered by the future endpoint.
+pub const FUTURE_DAYS_AHEAD: std::ops::RangeInclusive<i64> = 14..=300;
+
+/// Location match returned by the search endpoint.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchResult {
+    pub id: i64,
+    pub name: String,
+    pub region: String,
+    pub country: String,
+    pub lat: f64,
+    pub lon: f64,
+    /// URL slug of the location's page on weatherapi.com.
+    pub url: String,
+}
+
+impl SearchResult {
+    /// Query naming exactly this location, e.g. `id:2801268`.
+    pub fn id_query(&self) -> String {
+        format!("id:{}", self.id)
+    }
+}
+
+impl std::fmt::Display for SearchResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        for part in [&self.region, &self.country] {
+            if !part.is_empty() {
+                write!(f, ", {part}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the search results a place-name query may mean.
+///
+/// The search endpoint also returns prefix matches (`Londonderry` for `London`);
+/// only places with the queried name are kept, and any further parts of the query
+/// (`Springfield, Illinois`) must appear in their region or country.
+///
+/// # Returns
+/// The candidates, best match first. More than one means the query is ambiguous.
+pub fn candidates<'a>(query: &str, results: &'a [SearchResult]) -> Vec<&'a SearchResult> {
+    let mut parts = query.split(',').map(|part| part.trim().to_lowercase());
+    let name: String = parts.next().unwrap_or_default();
+    let qualifiers: Vec<String> = parts.filter(|part| !part.is_empty()).collect();
+    results
+        .iter()
+        .filter(|result| {
+            let area: String = format!("{} {}", result.region, result.country).to_lowercase();
+            result.name.to_lowercase() == name
+                && qualifiers.iter().all(|qualifier| area.contains(qualifier))
+        })
+        .collect()
+}
+
+/// Client for the Weather API (weatherapi.com).
+///
+/// The blocking methods need the `blocking` feature (enabled by default); the
+/// `_async` methods must be awaited from a tokio runtime. HTTP clients are built
+/// on first use, so a client used only from async code never starts the blocking one.
+#[derive(Debug)]
+pub struct WeatherClient {
+    api_key: String,
+    lang: Option<String>,
+    #[cfg(feature = "blocking")]
+    http: OnceLock<reqwest::blocking::Client>,
+    async_http: OnceLock<reqwest::Client>,
+}
+
+impl WeatherClient {
+    /// Creates a client authenticating with the given API key.
+    ///
+    /// # Arguments
+    /// * `api_key` - A weatherapi.com API key.
+    pub fn new(api_key: impl Into<String>) -> WeatherClient {
+        WeatherClient {
+            api_key: api_key.into(),
+            lang: None,
+            #[cfg(feature = "blocking")]
+            http: OnceLock::new(),
+            async_http: OnceLock::new(),
+        }
+    }
+
+    /// Requests condition texts in another language (e.g. `fr`, `de`, `zh`).
+    pub fn lang(mut self, lang: impl Into<String>) -> WeatherClient {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Returns the blocking HTTP client, building it on first use.
+    #[cfg(feature = "blocking")]
+    fn http(&self) -> Result<&reqwest::blocking::Client, WeatherError> {
+        if self.http.get().is_none() {
+            let _ = self.http.set(crate::http::client()?);
+        }
+        Ok(self.http.get().expect("HTTP client initialized above"))
+    }
+
+    /// Returns the async HTTP client, building it on first use.
+    fn async_http(&self) -> Result<&reqwest::Client, WeatherError> {
+        if self.async_http.get().is_none() {
+            let _ = self.async_http.set(crate::http::async_client()?);
+        }
+        Ok(self
+            .async_http
+            .get()
+            .expect("HTTP client initialized above"))
+    }
+
+    /// Builds the forecast request URL.
+    fn forecast_url(&self, query: &str, days: u32) -> Result<reqwest::Url, WeatherError> {
+        let query: &str = checked_query(query)?;
+        let mut url: reqwest::Url = reqwest::Url::parse_with_params(
+            "https://api.weatherapi.com/v1/forecast.json",
+            [
+                ("key", self.api_key.as_str()),
+                ("q", query),
+                ("days", &days.to_string()),
+                ("aqi", "yes"),
+                ("alerts", "yes"),
+            ],
+        )
+        .map_err(|e| WeatherError::InvalidQuery(e.to_string()))?;
+        self.append_lang(&mut url);
+        Ok(url)
+    }
+
+    /// Adds the condition text language, if any, to a request URL.
+    fn append_lang(&self, url: &mut reqwest::Url) {
+        if let Some(lang) = &self.lang {
+            url.query_pairs_mut().append_pair("lang", lang);
+        }
+    }
+
+    /// Builds the request URL of an endpoint for a single date (`YYYY-MM-DD`).
+    fn dated_url(
+        &self,
+        endpoint: &str,
+        query: &str,
+        date: &str,
+    ) -> Result<reqwest::Url, WeatherError> {
+        let query: &str = checked_query(query)?;
+        if !crate::time::is_valid_date(date) {
+            return Err(WeatherError::InvalidQuery(format!(
+                "invalid date {date}, expected YYYY-MM-DD"
+            )));
+        }
+        let mut url: reqwest::Url = reqwest::Url::parse_with_params(
+            &format!("https://api.weatherapi.com/v1/{endpoint}.json"),
+            [("key", self.api_key.as_str()), ("q", query), ("dt", date)],
+        )
+        .map_err(|e| WeatherError::InvalidQuery(e.to_string()))?;
+        self.append_lang(&mut url);
+        Ok(url)
+    }
+
+    /// Builds the search request URL.
+    fn search_url(&self, query: &str) -> Result<reqwest::Url, WeatherError> {
+        let query: &str = checked_query(query)?;
+        reqwest::Url::parse_with_params(
+            "https://api.weatherapi.com/v1/search.json",
+            [("key", self.api_key.as_str()), ("q", query)],
+        )
+        .map_err(|e| WeatherError::InvalidQuery(e.to_string()))
+    }
+
+    /// Sends a GET request, with retries, and returns the body of a successful response.
+    #[cfg(feature = "blocking")]
+    fn get(&self, url: reqwest::Url) -> Result<String, WeatherError> {
+        let response: reqwest::blocking::Response = crate::http::send(self.http()?.get(url))?;
+        let status: u16 = response.status().as_u16();
+        checked_body(status, response.text()?)
+    }
+
+    /// Async variant of [`WeatherClient::get`].
+    async fn get_async(&self, url: reqwest::Url) -> Result<String, WeatherError> {
+        let response: reqwest::Response =
+            crate::http::send_async(self.async_http()?.get(url)).await?;
+        let status: u16 = response.status().as_u16();
+        checked_body(status, response.text().await?)
+    }
+
+    /// Fetches the raw forecast response from the Weather API.
+    ///
+    /// # Arguments
+    /// * `query` - Location query (e.g., city name, coordinates).
+    /// * `days` - Number of forecast days to request.
+    ///
+    /// # Returns
+    /// A `String` containing the JSON response body.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_forecast_json(&self, query: &str, days: u32) -> Result<String, WeatherError> {
+        self.get(self.forecast_url(query, days)?)
+    }
+
+    /// Fetches and parses weather data from the Weather API.
+    ///
+    /// # Arguments
+    /// * `query` - Location query (e.g., city name, coordinates).
+    /// * `days` - Number of forecast days to request.
+    ///
+    /// # Returns
+    /// A `WeatherData` struct containing the parsed weather information.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_forecast(&self, query: &str, days: u32) -> Result<WeatherData, WeatherError> {
+        let json_body: String = self.fetch_forecast_json(query, days)?;
+        Ok(serde_json::from_str(&json_body)?)
+    }
+
+    /// Searches locations matching a query with the Weather API.
+    ///
+    /// # Arguments
+    /// * `query` - Location query (name, coordinates, postcode...).
+    ///
+    /// # Returns
+    /// A `Vec<SearchResult>` with the matching locations, best match first.
+    #[cfg(feature = "blocking")]
+    pub fn search(&self, query: &str) -> Result<Vec<SearchResult>, WeatherError> {
+        Ok(serde_json::from_str(&self.get(self.search_url(query)?)?)?)
+    }
+
+    /// Fetches the weather observed on a past date from the Weather API.
+    ///
+    /// The free plan covers the last 7 days; paid plans go back to 2010-01-01.
+    ///
+    /// # Arguments
+    /// * `query` - Location query (e.g., city name, coordinates).
+    /// * `date` - Local date (`YYYY-MM-DD`).
+    ///
+    /// # Returns
+    /// A `HistoryData` struct with the observed day.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_history(&self, query: &str, date: &str) -> Result<HistoryData, WeatherError> {
+        Ok(serde_json::from_str(
+            &self.get(self.dated_url("history", query, date)?)?,
+        )?)
+    }
+
+    /// Fetches the long-range forecast of a date [`FUTURE_DAYS_AHEAD`] from the
+    /// Weather API (paid plans only).
+    ///
+    /// # Arguments
+    /// * `query` - Location query (e.g., city name, coordinates).
+    /// * `date` - Local date (`YYYY-MM-DD`).
+    ///
+    /// # Returns
+    /// A `FutureData` struct with the forecast day.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_future(&self, query: &str, date: &str) -> Result<FutureData, WeatherError> {
+        Ok(serde_json::from_str(
+            &self.get(self.dated_url("future", query, date)?)?,
+        )?)
+    }
+
+    /// Async variant of [`WeatherClient::fetch_forecast_json`].
+    pub async fn fetch_forecast_json_async(
+        &self,
+        query: &str,
+        days: u32,
+    ) -> Result<String, WeatherError> {
+        self.get_async(self.forecast_url(query, days)?).await
+    }
+
+    /// Async variant of [`WeatherClient::fetch_forecast`].
+    ///
+    /// Several locations can be fetched concurrently by joining the futures,
+    /// e.g. with `tokio::join!` or `futures::future::join_all`.
+    pub async fn fetch_forecast_async(
+        &self,
+        query: &str,
+        days: u32,
+    ) -> Result<WeatherData, WeatherError> {
+        let json_body: String = self.fetch_forecast_json_async(query, days).await?;
+        Ok(serde_json::from_str(&json_body)?)
+    }
+
+    /// Async variant of [`WeatherClient::search`].
+    pub async fn search_async(&self, query: &str) -> Result<Vec<SearchResult>, WeatherError> {
+        Ok(serde_json::from_str(
+            &self.get_async(self.search_url(query)?).await?,
+        )?)
+    }
+
+    /// Async variant of [`WeatherClient::fetch_history`].
+    pub async fn fetch_history_async(
+        &self,
+        query: &str,
+        date: &str,
+    ) -> Result<HistoryData, WeatherError> {
+        Ok(serde_json::from_str(
+            &self
+                .get_async(self.dated_url("history", query, date)?)
+                .await?,
+        )?)
+    }
+
+    /// Async variant of [`WeatherClient::fetch_future`].
+    pub async fn fetch_future_async(
+        &self,
+        query: &str,
+        date: &str,
+    ) -> Result<FutureData, WeatherError> {
+        Ok(serde_json::from_str(
+            &self
+                .get_async(self.dated_url("future", query, date)?)
+                .await?,
+        )?)
+    }
+}
+
+/// Trims a location query, rejecting empty ones.
+fn checked_query(query: &str) -> Result<&str, WeatherError> {
+    let query: &str = query.trim();
+    if query.is_empty() {
+        return Err(WeatherError::InvalidQuery(
+            "no location provided".to_owned(),
+        ));
+    }
+    Ok(query)
+}
+
+/// Returns the body of a successful Weather API response, see [`check_status`].
+fn checked_body(status: u16, body: String) -> Result<String, WeatherError> {
+    check_status(status, &body)?;
+    Ok(body)
+}
+
+/// Maps an unsuccessful Weather API response to a `WeatherError`.
+///
+/// The API reports errors as `{"error": {"code": 1006, "message": "..."}}`; codes
+/// 1002 and 2006-2009 concern the API key, 1003 and 1006 the location query.
+fn check_status(status: u16, body: &str) -> Result<(), WeatherError> {
+    if status == 200 {
+        return Ok(());
+    }
+    let error: serde_json::Value = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| value.get("error").cloned())
+        .unwrap_or_default();
+    let message: Option<String> = error
+        .get("message")
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_owned);
+    Err(
+        match (
+            error.get("code").and_then(serde_json::Value::as_i64),
+            message,
+        ) {
+            (Some(1002 | 2006..=2009), Some(message)) => WeatherError::InvalidApiKey(message),
+            (Some(1003 | 1006), Some(message)) => WeatherError::InvalidQuery(message),
+            (_, message) => WeatherError::Status { status, message },
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn narrows_search_results() {
+        let results: Vec<SearchResult> = serde_json::from_str(
+            r#"[
+                {"id": 2618724, "name": "Springfield", "region": "Illinois", "country": "United States of America", "lat": 39.8, "lon": -89.64, "url": "springfield-illinois-united-states-of-america"},
+                {"id": 2635423, "name": "Springfield", "region": "Missouri", "country": "United States of America", "lat": 37.22, "lon": -93.3, "url": "springfield-missouri-united-states-of-america"},
+                {"id": 2618725, "name": "Springfield Gardens", "region": "New York", "country": "United States of America", "lat": 40.66, "lon": -73.76, "url": "springfield-gardens-new-york-united-states-of-america"}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(candidates("springfield", &results).len(), 2);
+        let missouri: Vec<&SearchResult> = candidates("Springfield, MISSOURI", &results);
+        assert_eq!(missouri.len(), 1);
+        assert_eq!(missouri[0].id_query(), "id:2635423");
+        assert_eq!(
+            missouri[0].to_string(),
+            "Springfield, Missouri, United States of America"
+        );
+        assert!(candidates("Springfield, Ohio", &results).is_empty());
+    }
+
+    #[test]
+    fn maps_api_errors() {
+        let body = r#"{"error":{"code":1006,"message":"No matching location found."}}"#;
+        assert!(matches!(
+            check_status(400, body),
+            Err(WeatherError::InvalidQuery(message)) if message == "No matching location found."
+        ));
+        let body = r#"{"error":{"code":2006,"message":"API key is invalid."}}"#;
+        assert!(matches!(
+            check_status(401, body),
+            Err(WeatherError::InvalidApiKey(_))
+        ));
+        assert!(matches!(
+            check_status(502, "Bad Gateway"),
+            Err(WeatherError::Status {
+                status: 502,
+                message: None
+            })
+        ));
+        assert!(check_status(200, "{}").is_ok());
+    }
+
+    #[test]
+    fn builds_request_urls() {
+        let client: WeatherClient = WeatherClient::new("key");
+        assert!(matches!(
+            client.dated_url("history", "London", "2024-1-15"),
+            Err(WeatherError::InvalidQuery(_))
+        ));
+        assert!(
+            client
+                .dated_url("history", "London", "2024-01-15")
+                .unwrap()
+                .as_str()
+                .ends_with("history.json?key=key&q=London&dt=2024-01-15")
+        );
+        assert!(
+            client
+                .dated_url("future", "Rock & Roll Hall of Fame", "2024-01-15")
+                .unwrap()
+                .as_str()
+                .ends_with("future.json?key=key&q=Rock+%26+Roll+Hall+of+Fame&dt=2024-01-15")
+        );
+        let client: WeatherClient = WeatherClient::new("key").lang("de&days=1");
+        let url: reqwest::Url = client.forecast_url("Rock & Roll Hall of Fame", 3).unwrap();
+        assert!(url.as_str().ends_with(
+            "forecast.json?key=key&q=Rock+%26+Roll+Hall+of+Fame&days=3&aqi=yes&alerts=yes&lang=de%26days%3D1"
+        ));
+        assert!(
+            client
+                .dated_url("history", "London", "2024-01-15")
+                .unwrap()
+                .as_str()
+                .ends_with("&lang=de%26days%3D1")
+        );
+        assert!(
+            client
+                .search_url("St. John's, Newfoundland #1")
+                .unwrap()
+                .as_str()
+                .ends_with("search.json?key=key&q=St.+John%27s%2C+Newfoundland+%231")
+        );
+        assert!(matches!(
+            checked_query("  "),
+            Err(WeatherError::InvalidQuery(_))
+        ));
+    }
+}
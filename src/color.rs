@@ -0,0 +1,99 @@
+/// 256-color palette from cold/low (blue) to hot/high (red).
+pub const PALETTE: [u8; 16] = [
+    21, 27, 33, 39, 45, 51, 87, 123, 229, 228, 227, 226, 220, 214, 208, 196,
+];
+
+/// Coldest and hottest temperatures (°C) of the temperature scale.
+const TEMP_RANGE_C: (f64, f64) = (-10.0, 40.0);
+
+/// Returns the palette color for a value within `min..=max`.
+pub fn scale(value: f64, min: f64, max: f64) -> u8 {
+    let ratio: f64 = if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let index: usize = (ratio * (PALETTE.len() - 1) as f64).round() as usize;
+    PALETTE[index.min(PALETTE.len() - 1)]
+}
+
+/// Returns the color of a temperature, from blue at -10 °C to red at 40 °C.
+pub fn temp(celsius: f64) -> u8 {
+    scale(celsius, TEMP_RANGE_C.0, TEMP_RANGE_C.1)
+}
+
+/// Returns the color of a US EPA index, after the EPA's own: green, yellow,
+/// orange, red, purple and maroon.
+pub fn us_epa_index(index: i32) -> u8 {
+    match index {
+        ..=1 => 46,
+        2 => 226,
+        3 => 208,
+        4 => 196,
+        5 => 129,
+        _ => 88,
+    }
+}
+
+/// Returns the color of a DEFRA index (1-10), after DEFRA's own: greens (low),
+/// yellow to orange (moderate), reds (high) and purple (very high).
+pub fn defra_index(index: i32) -> u8 {
+    match index {
+        ..=1 => 120,
+        2 => 46,
+        3 => 40,
+        4 => 226,
+        5 => 220,
+        6 => 208,
+        7 => 203,
+        8 => 196,
+        9 => 88,
+        _ => 135,
+    }
+}
+
+/// Returns the color of a UV index, after the WHO scale: green (low), yellow,
+/// orange, red and violet (extreme).
+pub fn uv(index: f64) -> u8 {
+    match index {
+        ..3.0 => 46,
+        ..6.0 => 226,
+        ..8.0 => 208,
+        ..11.0 => 196,
+        _ => 129,
+    }
+}
+
+/// Wraps text in a 256-color foreground code, or returns it as is.
+///
+/// # Arguments
+/// * `text` - Text to color.
+/// * `code` - Color of the 256-color palette.
+/// * `enabled` - Whether to color, false for `--no-color`, `NO_COLOR` or pipes.
+pub fn paint(text: &str, code: u8, enabled: bool) -> String {
+    if enabled {
+        format!("\x1b[38;5;{code}m{text}\x1b[0m")
+    } else {
+        text.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_by_severity() {
+        assert_eq!(temp(-30.0), PALETTE[0]);
+        assert_eq!(temp(45.0), PALETTE[15]);
+        assert!(PALETTE[..8].contains(&temp(10.0)));
+        assert_eq!(us_epa_index(1), 46);
+        assert_eq!(us_epa_index(6), 88);
+        assert_eq!(defra_index(4), 226);
+        assert_eq!(defra_index(10), 135);
+        assert_eq!(uv(2.9), 46);
+        assert_eq!(uv(11.0), 129);
+        assert_eq!(paint("20°C", 196, true), "\x1b[38;5;196m20°C\x1b[0m");
+        assert_eq!(paint("20°C", 196, false), "20°C");
+    }
+}
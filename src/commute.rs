@@ -0,0 +1,70 @@
+use crate::{Hour, WeatherData};
+
+/// Chance of rain (%) from which an umbrella is recommended.
+pub const UMBRELLA_CHANCE_OF_RAIN: i32 = 40;
+/// Temperature (°C) at or below which icy surfaces are possible.
+pub const ICE_TEMP_C: f64 = 1.0;
+
+/// Validates a local time in the `HH:MM` format.
+pub fn is_valid_time(input: &str) -> bool {
+    match input.split_once(':') {
+        Some((hours, minutes)) if hours.len() == 2 && minutes.len() == 2 => {
+            matches!(hours.parse::<u8>(), Ok(0..=23)) && matches!(minutes.parse::<u8>(), Ok(0..=59))
+        }
+        _ => false,
+    }
+}
+
+/// Returns the forecast hour for the next occurrence of a local time of day.
+///
+/// Today's hour is used if the time has not passed yet at the location,
+/// otherwise tomorrow's.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `time` - Local time of day (`HH:MM`).
+pub fn next_hour_at<'a>(weather: &'a WeatherData, time: &str) -> Option<&'a Hour> {
+    let localtime: &str = &weather.location.localtime;
+    let today: &str = localtime.get(..10)?;
+    let now: &str = localtime.get(11..)?;
+    // `localtime` drops the leading zero of the hour (`2025-08-01 8:05`).
+    let now: String = format!("{now:0>5}");
+    if time >= now.as_str() {
+        return weather.hour_at(&format!("{today} {time}"));
+    }
+    weather
+        .forecast
+        .forecastday
+        .iter()
+        .find(|forecast_day| forecast_day.date.as_str() > today)
+        .and_then(|forecast_day| weather.hour_at(&format!("{} {time}", forecast_day.date)))
+}
+
+/// Returns the warnings worth mentioning for a trip at the given hour.
+pub fn warnings(hour: &Hour) -> Vec<&'static str> {
+    let mut warnings: Vec<&'static str> = Vec::new();
+    if hour.chance_of_rain >= UMBRELLA_CHANCE_OF_RAIN || hour.precip_mm > 0.2 {
+        warnings.push("☂ Take an umbrella");
+    }
+    if hour.chance_of_snow >= UMBRELLA_CHANCE_OF_RAIN || hour.snow_cm > 0.0 {
+        warnings.push("❄ Snow expected");
+    }
+    if hour.temp_c <= ICE_TEMP_C || hour.feelslike_c <= ICE_TEMP_C - 2.0 {
+        warnings.push("⚠ Icy surfaces possible");
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_times() {
+        assert!(is_valid_time("08:15"));
+        assert!(is_valid_time("23:59"));
+        assert!(!is_valid_time("8:15"));
+        assert!(!is_valid_time("24:00"));
+        assert!(!is_valid_time("08-15"));
+    }
+}
@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::WeatherError;
+use crate::jobsite::Thresholds;
+use crate::provider::PROVIDERS;
+use crate::units::Units;
+
+/// Environment variable overriding the path of the config file.
+pub const CONFIG_ENV: &str = "MOSM_CONFIG";
+
+/// Output formats accepted by the `format` setting.
+pub const FORMATS: [&str; 5] = ["full", "summary", "json", "argos", "genmon"];
+
+/// User defaults read from `config.toml`, overridable by environment variables.
+///
+/// ```toml
+/// # ~/.config/mosm/config.toml
+/// api_key = "your-weatherapi-key"
+/// location = "London"
+/// units = "metric"
+/// days = 3
+/// lang = "en"
+/// format = "full"
+/// cache_ttl = 15
+/// provider = "weatherapi"
+/// fallback_provider = "open-meteo"
+/// retries = 2
+/// retry_delay = 500
+/// timeout = 15
+/// indoor_temp = 22
+/// indoor_humidity = 50
+///
+/// [trade.crane]
+/// max_wind = 30
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Config {
+    /// Weather API key (`WEATHER_API_KEY`).
+    pub api_key: Option<String>,
+    /// Location used when none is given (`MOSM_LOCATION`).
+    pub location: Option<String>,
+    /// Unit system (`MOSM_UNITS`).
+    pub units: Option<Units>,
+    /// Number of forecast days, 1 to 14 (`MOSM_DAYS`).
+    pub days: Option<u32>,
+    /// Language code for condition texts (`MOSM_LANG`).
+    pub lang: Option<String>,
+    /// Output format of the report, one of [`FORMATS`] (`MOSM_FORMAT`).
+    pub format: Option<String>,
+    /// Minutes a cached response is reused, 0 to disable the cache (`MOSM_CACHE_TTL`).
+    pub cache_ttl: Option<u32>,
+    /// Forecast provider, one of [`PROVIDERS`] (`MOSM_PROVIDER`).
+    pub provider: Option<String>,
+    /// Provider asked when `provider` fails (`MOSM_FALLBACK_PROVIDER`).
+    pub fallback_provider: Option<String>,
+    /// Retries of a request failing with a timeout or server error, 0 to 10 (`MOSM_RETRIES`).
+    pub retries: Option<u32>,
+    /// Milliseconds before the first retry, doubled for each further one (`MOSM_RETRY_DELAY`).
+    pub retry_delay: Option<u32>,
+    /// Seconds a request may take, 1 to 300 (`MOSM_TIMEOUT`).
+    pub timeout: Option<u32>,
+    /// Indoor temperature (°C) to keep when airing.
+    pub indoor_temp: Option<i32>,
+    /// Indoor relative humidity (%) to keep when airing.
+    pub indoor_humidity: Option<u32>,
+    /// Jobsite limits of each `[trade.NAME]` table, over the built-in ones of that trade.
+    pub trades: BTreeMap<String, Thresholds>,
+}
+
+/// A value on the right-hand side of `key = value`.
+#[derive(Debug, PartialEq)]
+enum Value {
+    String(String),
+    Integer(i64),
+}
+
+impl Config {
+    /// Returns the config file path: `$MOSM_CONFIG`, else `$XDG_CONFIG_HOME/mosm/config.toml`,
+    /// else `~/.config/mosm/config.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        if let Some(path) = std::env::var_os(CONFIG_ENV) {
+            return Some(PathBuf::from(path));
+        }
+        Some(Config::dir()?.join("config.toml"))
+    }
+
+    /// Returns the directory of the user's mosm files: `$XDG_CONFIG_HOME/mosm`, else
+    /// `~/.config/mosm`.
+    pub fn dir() -> Option<PathBuf> {
+        let config_home: PathBuf = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+        };
+        Some(config_home.join("mosm"))
+    }
+
+    /// Reads a config file; a missing file yields the empty config.
+    ///
+    /// # Arguments
+    /// * `path` - Path of the TOML file.
+    ///
+    /// # Returns
+    /// The parsed `Config`, or `WeatherError::Config` if the file is unreadable or invalid.
+    pub fn load(path: &Path) -> Result<Config, WeatherError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Config::parse(&text)
+                .map_err(|reason| WeatherError::Config(format!("{}: {reason}", path.display()))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(WeatherError::Config(format!("{}: {e}", path.display()))),
+        }
+    }
+
+    /// Parses the `key = value` subset of TOML used by the config file.
+    ///
+    /// Strings are double- or single-quoted, numbers are integers and `#` starts a comment.
+    /// `[trade.NAME]` tables hold jobsite limits.
+    ///
+    /// # Returns
+    /// The parsed `Config`, or the reason with its line number.
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let mut config: Config = Config::default();
+        let mut trade: Option<String> = None;
+        for (number, line) in text.lines().enumerate() {
+            let line: &str = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fail = |reason: String| format!("line {}: {reason}", number + 1);
+            if let Some(table) = line
+                .strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                match table.trim().strip_prefix("trade.") {
+                    Some(name) if !name.is_empty() => trade = Some(name.to_owned()),
+                    _ => return Err(fail(format!("unknown table [{table}]"))),
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(fail(format!("expected key = value, found {line:?}")));
+            };
+            let key: &str = key.trim();
+            let value: Value = parse_value(value.trim()).map_err(fail)?;
+            match &trade {
+                Some(name) => config.set_trade(name, key, value).map_err(fail)?,
+                None => config.set(key, value).map_err(fail)?,
+            }
+        }
+        Ok(config)
+    }
+
+    /// Returns the jobsite limits of a trade: its config table, else the built-in ones.
+    pub fn thresholds(&self, trade: &str) -> Option<Thresholds> {
+        self.trades
+            .get(trade)
+            .cloned()
+            .or_else(|| Thresholds::preset(trade))
+    }
+
+    /// Applies the `WEATHER_API_KEY` and `MOSM_*` environment variables over the file values.
+    pub fn apply_env(&mut self) -> Result<(), WeatherError> {
+        for (key, name) in [
+            ("api_key", "WEATHER_API_KEY"),
+            ("location", "MOSM_LOCATION"),
+            ("units", "MOSM_UNITS"),
+            ("days", "MOSM_DAYS"),
+            ("lang", "MOSM_LANG"),
+            ("format", "MOSM_FORMAT"),
+            ("cache_ttl", "MOSM_CACHE_TTL"),
+            ("provider", "MOSM_PROVIDER"),
+            ("fallback_provider", "MOSM_FALLBACK_PROVIDER"),
+            ("retries", "MOSM_RETRIES"),
+            ("retry_delay", "MOSM_RETRY_DELAY"),
+            ("timeout", "MOSM_TIMEOUT"),
+        ] {
+            let Ok(text) = std::env::var(name) else {
+                continue;
+            };
+            let value: Value = match text.parse::<i64>() {
+                Ok(number)
+                    if matches!(
+                        key,
+                        "days" | "cache_ttl" | "retries" | "retry_delay" | "timeout"
+                    ) =>
+                {
+                    Value::Integer(number)
+                }
+                _ => Value::String(text),
+            };
+            self.set(key, value)
+                .map_err(|reason| WeatherError::Config(format!("{name}: {reason}")))?;
+        }
+        Ok(())
+    }
+
+    /// Validates and stores a setting.
+    fn set(&mut self, key: &str, value: Value) -> Result<(), String> {
+        match (key, value) {
+            ("api_key", Value::String(text)) => self.api_key = Some(text),
+            ("location", Value::String(text)) => self.location = Some(text),
+            ("lang", Value::String(text)) => self.lang = Some(text),
+            ("units", Value::String(text)) => {
+                self.units = Some(text.parse::<Units>().map_err(|_| {
+                    format!("invalid units {text:?}, expected metric, imperial or both")
+                })?);
+            }
+            ("days", Value::Integer(days @ 1..=14)) => self.days = Some(days as u32),
+            ("days", _) => return Err("days must be a number from 1 to 14".to_owned()),
+            ("cache_ttl", Value::Integer(minutes @ 0..=10_080)) => {
+                self.cache_ttl = Some(minutes as u32);
+            }
+            ("cache_ttl", _) => {
+                return Err("cache_ttl must be a number of minutes from 0 to 10080".to_owned());
+            }
+            ("format", Value::String(text)) if FORMATS.contains(&text.as_str()) => {
+                self.format = Some(text);
+            }
+            ("format", _) => {
+                return Err(format!("format must be one of {}", FORMATS.join(", ")));
+            }
+            ("provider", Value::String(text)) if PROVIDERS.contains(&text.as_str()) => {
+                self.provider = Some(text);
+            }
+            ("provider", _) => {
+                return Err(format!("provider must be one of {}", PROVIDERS.join(", ")));
+            }
+            ("fallback_provider", Value::String(text)) if PROVIDERS.contains(&text.as_str()) => {
+                self.fallback_provider = Some(text);
+            }
+            ("fallback_provider", _) => {
+                return Err(format!(
+                    "fallback_provider must be one of {}",
+                    PROVIDERS.join(", ")
+                ));
+            }
+            ("retries", Value::Integer(retries @ 0..=10)) => self.retries = Some(retries as u32),
+            ("retries", _) => return Err("retries must be a number from 0 to 10".to_owned()),
+            ("retry_delay", Value::Integer(millis @ 0..=60_000)) => {
+                self.retry_delay = Some(millis as u32);
+            }
+            ("retry_delay", _) => {
+                return Err(
+                    "retry_delay must be a number of milliseconds from 0 to 60000".to_owned(),
+                );
+            }
+            ("timeout", Value::Integer(secs @ 1..=300)) => self.timeout = Some(secs as u32),
+            ("timeout", _) => {
+                return Err("timeout must be a number of seconds from 1 to 300".to_owned());
+            }
+            ("indoor_temp", Value::Integer(temp @ -10..=40)) => {
+                self.indoor_temp = Some(temp as i32);
+            }
+            ("indoor_temp", _) => {
+                return Err("indoor_temp must be a number of °C from -10 to 40".to_owned());
+            }
+            ("indoor_humidity", Value::Integer(humidity @ 1..=100)) => {
+                self.indoor_humidity = Some(humidity as u32);
+            }
+            ("indoor_humidity", _) => {
+                return Err("indoor_humidity must be a percentage from 1 to 100".to_owned());
+            }
+            ("api_key" | "location" | "lang" | "units", _) => {
+                return Err(format!("{key} must be a string"));
+            }
+            _ => return Err(format!("unknown setting {key:?}")),
+        }
+        Ok(())
+    }
+
+    /// Stores a setting of a `[trade.NAME]` table, starting from the built-in limits.
+    fn set_trade(&mut self, trade: &str, key: &str, value: Value) -> Result<(), String> {
+        let Value::Integer(number) = value else {
+            return Err(format!("{key} must be a number"));
+        };
+        self.trades
+            .entry(trade.to_owned())
+            .or_insert_with(|| Thresholds::preset(trade).unwrap_or_default())
+            .set(key, number as f64)
+    }
+}
+
+/// Removes a `#` comment that is not inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quote: Option<char> = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '#') => return &line[..i],
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses a quoted string or an integer.
+fn parse_value(text: &str) -> Result<Value, String> {
+    if let Some(inner) = text.strip_prefix('\'') {
+        return match inner.strip_suffix('\'') {
+            Some(literal) => Ok(Value::String(literal.to_owned())),
+            None => Err(format!("unterminated string {text}")),
+        };
+    }
+    if let Some(inner) = text.strip_prefix('"') {
+        let Some(inner) = inner.strip_suffix('"') else {
+            return Err(format!("unterminated string {text}"));
+        };
+        let mut value: String = String::new();
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                value.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('"') => value.push('"'),
+                Some('\\') => value.push('\\'),
+                Some('n') => value.push('\n'),
+                Some('t') => value.push('\t'),
+                other => return Err(format!("unsupported escape \\{}", other.unwrap_or(' '))),
+            }
+        }
+        return Ok(Value::String(value));
+    }
+    text.replace('_', "")
+        .parse::<i64>()
+        .map(Value::Integer)
+        .map_err(|_| format!("expected a quoted string or a number, found {text}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_settings() {
+        let config: Config = Config::parse(
+            "# defaults\n\
+             location = \"São Paulo, Brazil\"  # home\n\
+             units = 'imperial'\n\
+             days = 5\n\
+             \n\
+             lang = \"pt\"\n\
+             format = \"summary\"\n\
+             cache_ttl = 0\n\
+             indoor_temp = 21\n\
+             fallback_provider = \"open-meteo\"\n\
+             timeout = 5\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                api_key: None,
+                location: Some("São Paulo, Brazil".to_owned()),
+                units: Some(Units::Imperial),
+                days: Some(5),
+                lang: Some("pt".to_owned()),
+                format: Some("summary".to_owned()),
+                cache_ttl: Some(0),
+                provider: None,
+                fallback_provider: Some("open-meteo".to_owned()),
+                retries: None,
+                retry_delay: None,
+                timeout: Some(5),
+                indoor_temp: Some(21),
+                indoor_humidity: None,
+                trades: BTreeMap::new(),
+            }
+        );
+        assert_eq!(
+            Config::parse("location = \"a # b\"")
+                .unwrap()
+                .location
+                .as_deref(),
+            Some("a # b")
+        );
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        assert_eq!(
+            Config::parse("days = 3\ndays = 30"),
+            Err("line 2: days must be a number from 1 to 14".to_owned())
+        );
+        assert!(Config::parse("units = \"kelvin\"").is_err());
+        assert!(Config::parse("location = London").is_err());
+        assert!(Config::parse("colour = \"red\"").is_err());
+        assert!(Config::parse("provider = \"metoffice\"").is_err());
+        assert!(Config::parse("fallback_provider = \"metoffice\"").is_err());
+        assert!(Config::parse("indoor_humidity = 0").is_err());
+        assert!(Config::parse("retries = 11").is_err());
+        assert!(Config::parse("timeout = 0").is_err());
+        assert!(Config::parse("[section]").is_err());
+        assert!(Config::parse("[trade.crane]\nmax_wind = \"low\"").is_err());
+        assert!(Config::parse("[trade.crane]\nmax_snow = 3").is_err());
+    }
+
+    #[test]
+    fn parses_trade_tables() {
+        let config: Config = Config::parse(
+            "days = 3\n\
+             [trade.crane]\n\
+             max_wind = 28\n\
+             [trade.painting]\n\
+             max_precip = 0\n",
+        )
+        .unwrap();
+        assert_eq!(config.days, Some(3));
+        let crane: Thresholds = config.thresholds("crane").unwrap();
+        assert_eq!(crane.max_wind_kph, 28.0);
+        assert_eq!(
+            crane.max_gust_kph,
+            Thresholds::preset("crane").unwrap().max_gust_kph
+        );
+        assert_eq!(config.thresholds("painting").unwrap().max_precip_mm, 0.0);
+        assert_eq!(config.thresholds("roofing"), Thresholds::preset("roofing"));
+        assert_eq!(config.thresholds("diving"), None);
+    }
+}
@@ -0,0 +1,97 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::WeatherError;
+use crate::config::Config;
+
+/// Returns `api_key` in the directory of the config file, see [`Config::dir`].
+///
+/// The key is kept out of `config.toml` so the config can be shared or versioned.
+pub fn default_path() -> Option<PathBuf> {
+    Some(Config::dir()?.join("api_key"))
+}
+
+/// Reads the saved API key; a missing or empty file yields none.
+///
+/// # Returns
+/// The trimmed key, or `WeatherError::Config` if the file is unreadable.
+pub fn load(path: &Path) -> Result<Option<String>, WeatherError> {
+    match std::fs::read_to_string(path) {
+        Ok(text) if text.trim().is_empty() => Ok(None),
+        Ok(text) => Ok(Some(text.trim().to_owned())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(WeatherError::Config(format!("{}: {e}", path.display()))),
+    }
+}
+
+/// Writes the API key, readable by the owner only, creating the directory if needed.
+pub fn save(path: &Path, key: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut options: std::fs::OpenOptions = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        // The mode only applies to new files.
+        if path.exists() {
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        }
+    }
+    writeln!(options.open(path)?, "{}", key.trim())
+}
+
+/// Deletes the saved API key.
+///
+/// # Returns
+/// `false` if no key was saved.
+pub fn remove(path: &Path) -> std::io::Result<bool> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Hides all but the first and last 4 characters of a key, e.g. `2aed…0108`.
+pub fn mask(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 8 {
+        return "…".repeat(chars.len().min(1));
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{head}…{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_key_for_owner_only() {
+        let path: PathBuf = std::env::temp_dir()
+            .join(format!("mosm-credentials-test-{}", std::process::id()))
+            .join("api_key");
+        assert_eq!(load(&path).unwrap(), None);
+        save(&path, " 0123456789abcdef0123456789\n").unwrap();
+        assert_eq!(
+            load(&path).unwrap().as_deref(),
+            Some("0123456789abcdef0123456789")
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode: u32 = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert!(remove(&path).unwrap());
+        assert!(!remove(&path).unwrap());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(mask("0123456789abcdef0123456789"), "0123…6789");
+        assert_eq!(mask("short"), "…");
+    }
+}
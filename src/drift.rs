@@ -0,0 +1,135 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{WeatherData, WeatherError};
+
+/// Differences between a raw response and the fields of the typed model.
+///
+/// Paths join object keys with `.` and mark array elements with `[]`, e.g.
+/// `forecast.forecastday[].hour[].short_rad`.
+#[derive(Serialize, Debug, Default, PartialEq)]
+pub struct Drift {
+    /// Fields in the response that the model ignores, such as new API fields.
+    pub unknown: Vec<String>,
+    /// Fields of the model absent from the response, read as their default.
+    pub missing: Vec<String>,
+}
+
+impl Drift {
+    /// Returns `true` if the response and the model have the same fields.
+    pub fn is_empty(&self) -> bool {
+        self.unknown.is_empty() && self.missing.is_empty()
+    }
+}
+
+impl std::fmt::Display for Drift {
+    /// Lists unknown fields with `+` and missing ones with `-`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let paths: Vec<String> = self
+            .unknown
+            .iter()
+            .map(|path| format!("+{path}"))
+            .chain(self.missing.iter().map(|path| format!("-{path}")))
+            .collect();
+        write!(f, "{}", paths.join(", "))
+    }
+}
+
+/// Compares a raw forecast response with the `WeatherData` model.
+///
+/// The response is parsed, serialized back and the field paths of both compared:
+/// fields dropped on the way are unknown to the model, fields added are missing
+/// from the response. Optional fields the model leaves out when absent, such as
+/// `air_quality`, are never reported missing.
+///
+/// # Arguments
+/// * `body` - Raw JSON body of a forecast response.
+///
+/// # Returns
+/// The `Drift`, or the parse error if the response does not fit the model at all.
+pub fn forecast_drift(body: &str) -> Result<Drift, WeatherError> {
+    response_drift(&serde_json::from_str(body)?)
+}
+
+/// Compares an already parsed forecast response with the `WeatherData` model, see
+/// [`forecast_drift`].
+pub fn response_drift(raw: &Value) -> Result<Drift, WeatherError> {
+    let weather: WeatherData = serde_json::from_value(raw.clone())?;
+    Ok(compare(raw, &serde_json::to_value(&weather)?))
+}
+
+/// Compares the field paths of a raw and a typed JSON value.
+pub fn compare(raw: &Value, typed: &Value) -> Drift {
+    let raw_paths: BTreeSet<String> = field_paths(raw);
+    let typed_paths: BTreeSet<String> = field_paths(typed);
+    Drift {
+        unknown: raw_paths.difference(&typed_paths).cloned().collect(),
+        missing: typed_paths.difference(&raw_paths).cloned().collect(),
+    }
+}
+
+/// Returns the path of every object field under a value, merging array elements.
+pub fn field_paths(value: &Value) -> BTreeSet<String> {
+    let mut paths: BTreeSet<String> = BTreeSet::new();
+    collect_paths(value, "", &mut paths);
+    paths
+}
+
+/// Adds the path of every object field under a value, merging array elements.
+fn collect_paths(value: &Value, prefix: &str, paths: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                let path: String = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                collect_paths(field, &path, paths);
+                paths.insert(path);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_paths(item, &format!("{prefix}[]"), paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_unknown_and_missing_fields() {
+        let fixture: &str = include_str!("../tests/fixtures/forecast.json");
+        let mut raw: Value = serde_json::from_str(fixture).unwrap();
+        let drift: Drift = forecast_drift(&raw.to_string()).unwrap();
+        assert!(drift.unknown.is_empty(), "{drift:?}");
+
+        raw["current"]["pollen"] = serde_json::json!({"grass": 3});
+        for hour in raw["forecast"]["forecastday"][0]["hour"]
+            .as_array_mut()
+            .unwrap()
+        {
+            hour.as_object_mut().unwrap().remove("gti");
+        }
+        let drift: Drift = forecast_drift(&raw.to_string()).unwrap();
+        assert_eq!(drift.unknown, ["current.pollen", "current.pollen.grass"]);
+        assert!(
+            drift
+                .to_string()
+                .starts_with("+current.pollen, +current.pollen.grass")
+        );
+        // Other days still have `gti`, so only whole omissions count.
+        assert!(
+            !drift
+                .missing
+                .contains(&"forecast.forecastday[].hour[].gti".to_owned())
+        );
+    }
+}
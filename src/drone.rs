@@ -0,0 +1,280 @@
+use serde::Serialize;
+
+use crate::event::Verdict;
+use crate::{Hour, WeatherData, aurora};
+
+/// Share of a limit from which conditions call for caution.
+const CAUTION_RATIO: f64 = 0.8;
+
+/// Flight limits of the aircraft.
+///
+/// The defaults suit a small consumer quadcopter without water resistance.
+#[derive(Serialize, Debug)]
+pub struct Limits {
+    /// Highest sustained wind (kph).
+    pub max_wind_kph: f64,
+    /// Highest gust (kph).
+    pub max_gust_kph: f64,
+    /// Highest precipitation (mm per hour).
+    pub max_precip_mm: f64,
+    /// Lowest visibility (km), for keeping the aircraft in sight.
+    pub min_vis_km: f64,
+    /// Lowest air temperature (°C), below which batteries lose capacity fast.
+    pub min_temp_c: f64,
+    /// Highest air temperature (°C).
+    pub max_temp_c: f64,
+    /// Highest planetary K index, above which GNSS and compass errors grow.
+    pub max_kp: f64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_wind_kph: 30.0,
+            max_gust_kph: 38.0,
+            max_precip_mm: 0.0,
+            min_vis_km: 3.0,
+            min_temp_c: 0.0,
+            max_temp_c: 40.0,
+            max_kp: 5.0,
+        }
+    }
+}
+
+/// Measurement checked against a limit.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Factor {
+    Wind,
+    Gust,
+    Precip,
+    Visibility,
+    Cold,
+    Heat,
+    Kp,
+}
+
+/// A measurement at or beyond the caution threshold of its limit.
+#[derive(Serialize, Debug)]
+pub struct Concern {
+    pub factor: Factor,
+    /// Forecast value, in the unit of the limit.
+    pub value: f64,
+    pub limit: f64,
+    /// `NoGo` beyond the limit, `Caution` close to it.
+    pub verdict: Verdict,
+}
+
+/// Verdict of one forecast hour.
+#[derive(Serialize, Debug)]
+pub struct HourCheck<'a> {
+    #[serde(skip)]
+    pub hour: &'a Hour,
+    /// Local time (`YYYY-MM-DD HH:MM`).
+    pub time: &'a str,
+    /// Forecast Kp of the hour, if known.
+    pub kp: Option<f64>,
+    /// Worst verdict of the concerns, `Go` without any.
+    pub verdict: Verdict,
+    pub concerns: Vec<Concern>,
+    /// Factors the forecast has no value for, which call for caution.
+    pub unknown: Vec<Factor>,
+}
+
+/// Checks an hour's conditions against the aircraft limits.
+///
+/// A value beyond a limit grounds the flight; one within 20% of it (5 °C for
+/// temperatures, 1 for Kp) calls for caution, as does a value missing from the
+/// forecast. Thunderstorms always ground it.
+///
+/// # Arguments
+/// * `hour` - Forecast hour.
+/// * `kp` - Planetary K index during the hour, if known.
+/// * `limits` - Aircraft limits.
+pub fn check_hour<'a>(hour: &'a Hour, kp: Option<f64>, limits: &Limits) -> HourCheck<'a> {
+    let mut concerns: Vec<Concern> = Vec::new();
+    let mut check = |factor: Factor, value: f64, limit: f64, beyond: bool, close: bool| {
+        let verdict: Verdict = match (beyond, close) {
+            (true, _) => Verdict::NoGo,
+            (false, true) => Verdict::Caution,
+            (false, false) => return,
+        };
+        concerns.push(Concern {
+            factor,
+            value,
+            limit,
+            verdict,
+        });
+    };
+    let mut unknown: Vec<Factor> = Vec::new();
+    let (wind, precip) = (hour.wind_kph, hour.precip_mm);
+    let (max_wind, max_gust, max_precip) = (
+        limits.max_wind_kph,
+        limits.max_gust_kph,
+        limits.max_precip_mm,
+    );
+    check(
+        Factor::Wind,
+        wind,
+        max_wind,
+        wind > max_wind,
+        wind >= max_wind * CAUTION_RATIO,
+    );
+    match hour.gust_kph {
+        Some(gust) => check(
+            Factor::Gust,
+            gust,
+            max_gust,
+            gust > max_gust,
+            gust >= max_gust * CAUTION_RATIO,
+        ),
+        None => unknown.push(Factor::Gust),
+    }
+    check(
+        Factor::Precip,
+        precip,
+        max_precip,
+        precip > max_precip,
+        precip > 0.0 && precip >= max_precip * CAUTION_RATIO,
+    );
+    let min_vis: f64 = limits.min_vis_km;
+    match hour.vis_km {
+        Some(vis) => check(
+            Factor::Visibility,
+            vis,
+            min_vis,
+            vis < min_vis,
+            vis <= min_vis / CAUTION_RATIO,
+        ),
+        None => unknown.push(Factor::Visibility),
+    }
+    let (temp, min_temp, max_temp) = (hour.temp_c, limits.min_temp_c, limits.max_temp_c);
+    check(
+        Factor::Cold,
+        temp,
+        min_temp,
+        temp < min_temp,
+        temp <= min_temp + 5.0,
+    );
+    check(
+        Factor::Heat,
+        temp,
+        max_temp,
+        temp > max_temp,
+        temp >= max_temp - 5.0,
+    );
+    if let Some(kp) = kp {
+        check(
+            Factor::Kp,
+            kp,
+            limits.max_kp,
+            kp > limits.max_kp,
+            kp >= limits.max_kp - 1.0,
+        );
+    }
+
+    let thunder: bool = crate::event::THUNDER_CODES.contains(&hour.condition.code);
+    let verdict: Verdict = concerns
+        .iter()
+        .map(|concern| concern.verdict)
+        .chain((!unknown.is_empty()).then_some(Verdict::Caution))
+        .chain(thunder.then_some(Verdict::NoGo))
+        .max()
+        .unwrap_or(Verdict::Go);
+    HourCheck {
+        hour,
+        time: &hour.time,
+        kp,
+        verdict,
+        concerns,
+        unknown,
+    }
+}
+
+/// Checks the coming hours against the aircraft limits.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `kp_forecast` - Kp periods from [`aurora::fetch_kp_forecast`], empty if unavailable.
+/// * `limits` - Aircraft limits.
+/// * `hours` - Number of hours, from the hour in progress.
+pub fn check_hours<'a>(
+    weather: &'a WeatherData,
+    kp_forecast: &[aurora::KpPeriod],
+    limits: &Limits,
+    hours: usize,
+) -> Vec<HourCheck<'a>> {
+    crate::hourly::upcoming(weather, hours)
+        .into_iter()
+        .map(|hour| check_hour(hour, aurora::kp_at(kp_forecast, hour.time_epoch), limits))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn checks_limits() {
+        let weather: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let hour: &Hour = weather.hours().next().unwrap();
+        let limits: Limits = Limits {
+            max_wind_kph: hour.wind_kph + 1.0,
+            max_gust_kph: hour.gust_kph.unwrap() - 1.0,
+            min_temp_c: hour.temp_c - 20.0,
+            ..Limits::default()
+        };
+
+        let check: HourCheck = check_hour(hour, Some(2.0), &limits);
+        assert_eq!(check.verdict, Verdict::NoGo);
+        let factors: Vec<(Factor, Verdict)> = check
+            .concerns
+            .iter()
+            .map(|concern| (concern.factor, concern.verdict))
+            .collect();
+        assert!(factors.contains(&(Factor::Wind, Verdict::Caution)));
+        assert!(factors.contains(&(Factor::Gust, Verdict::NoGo)));
+        assert!(!factors.iter().any(|(factor, _)| *factor == Factor::Kp));
+
+        let calm: Limits = Limits {
+            max_wind_kph: 100.0,
+            max_gust_kph: 100.0,
+            min_temp_c: -20.0,
+            ..Limits::default()
+        };
+        let check: HourCheck = check_hour(hour, Some(4.3), &calm);
+        assert_eq!(check.verdict, Verdict::Caution);
+        assert_eq!(check.concerns[0].factor, Factor::Kp);
+        let hazy: Limits = Limits {
+            min_vis_km: hour.vis_km.unwrap() * 0.9,
+            ..Limits::default()
+        };
+        let check: HourCheck = check_hour(hour, None, &hazy);
+        assert!(
+            check
+                .concerns
+                .iter()
+                .any(|concern| concern.factor == Factor::Visibility
+                    && concern.verdict == Verdict::Caution)
+        );
+        let cold: Limits = Limits {
+            min_temp_c: hour.temp_c + 1.0,
+            ..calm
+        };
+        let check: HourCheck = check_hour(hour, None, &cold);
+        assert_eq!(check.verdict, Verdict::NoGo);
+        assert_eq!(check.concerns[0].limit, hour.temp_c + 1.0);
+
+        assert_eq!(check_hours(&weather, &[], &calm, 6).len(), 6);
+
+        // Without a gust forecast, a calm hour is no longer a go.
+        let mut gustless: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        gustless.forecast.forecastday[0].hour[0].gust_kph = None;
+        let hour: &Hour = gustless.hours().next().unwrap();
+        let check: HourCheck = check_hour(hour, None, &calm);
+        assert_eq!(check.verdict, Verdict::Caution);
+        assert_eq!(check.unknown, [Factor::Gust]);
+    }
+}
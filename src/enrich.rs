@@ -0,0 +1,182 @@
+#[cfg(feature = "blocking")]
+use crate::OpenMeteoClient;
+use crate::open_meteo::Place;
+use crate::{WeatherData, WeatherError, geo, i18n};
+
+/// Lookups that add to a forecast what its provider leaves out.
+pub trait PlaceLookup {
+    /// Finds the place nearest to coordinates.
+    fn reverse_geocode(&self, lat: f64, lon: f64) -> Result<Place, WeatherError>;
+
+    /// Returns the ground elevation of coordinates, in metres above sea level.
+    fn elevation(&self, lat: f64, lon: f64) -> Result<f64, WeatherError>;
+}
+
+#[cfg(feature = "blocking")]
+impl PlaceLookup for OpenMeteoClient {
+    fn reverse_geocode(&self, lat: f64, lon: f64) -> Result<Place, WeatherError> {
+        OpenMeteoClient::reverse_geocode(self, lat, lon)
+    }
+
+    fn elevation(&self, lat: f64, lon: f64) -> Result<f64, WeatherError> {
+        OpenMeteoClient::elevation(self, lat, lon)
+    }
+}
+
+/// Additions to a forecast asked for on the command line.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Enrichment<'a> {
+    /// Names forecasts requested by coordinates after the nearest place (`--place`).
+    pub place: bool,
+    /// Adds the elevation of the location (`--context`).
+    pub context: bool,
+    /// Translates the condition texts left in English (`--lang`).
+    pub lang: Option<&'a str>,
+}
+
+/// Adds the requested place name, elevation and translations to a forecast, whichever
+/// provider or session it comes from.
+///
+/// # Arguments
+/// * `weather` - Forecast to complete.
+/// * `query` - Location query the forecast was requested with.
+/// * `enrichment` - Additions to make.
+/// * `lookup` - Source of the place names and elevations.
+///
+/// # Returns
+/// Notes on the lookups that failed, which leave the forecast as it was.
+pub fn enrich(
+    weather: &mut WeatherData,
+    query: &str,
+    enrichment: &Enrichment,
+    lookup: &dyn PlaceLookup,
+) -> Vec<String> {
+    let mut notes: Vec<String> = Vec::new();
+    if enrichment.place
+        && let Some((lat, lon)) = geo::parse_coordinates(query)
+        && let Err(e) = name_coordinates(weather, lat, lon, lookup)
+    {
+        notes.push(format!("no place name for {lat}, {lon}: {e}"));
+    }
+    if enrichment.context {
+        let (lat, lon) = (weather.location.lat, weather.location.lon);
+        match lookup.elevation(lat, lon) {
+            Ok(elevation_m) => weather.location.elevation_m = Some(elevation_m),
+            Err(e) => notes.push(format!("no elevation for {lat}, {lon}: {e}")),
+        }
+    }
+    if let Some(lang) = enrichment.lang {
+        i18n::localize_conditions(weather, lang);
+    }
+    notes
+}
+
+/// Names a forecast requested by coordinates after the nearest place, keeping the
+/// coordinates for the header.
+///
+/// The Weather API names such forecasts itself; Open-Meteo's are named with a
+/// reverse lookup, left as coordinates if it fails.
+fn name_coordinates(
+    weather: &mut WeatherData,
+    lat: f64,
+    lon: f64,
+    lookup: &dyn PlaceLookup,
+) -> Result<(), WeatherError> {
+    weather.location.show_coordinates = true;
+    if geo::parse_coordinates(&weather.location.name).is_none() {
+        return Ok(());
+    }
+    let place: Place = lookup.reverse_geocode(lat, lon)?;
+    weather.location.name = place.name;
+    weather.location.region = place.admin1;
+    weather.location.country = place.country;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Knows a single place, or fails every lookup.
+    struct Stub(bool);
+
+    impl PlaceLookup for Stub {
+        fn reverse_geocode(&self, lat: f64, lon: f64) -> Result<Place, WeatherError> {
+            match self.0 {
+                true => Ok(Place {
+                    name: "Paris".to_owned(),
+                    latitude: lat,
+                    longitude: lon,
+                    country: "France".to_owned(),
+                    admin1: "Île-de-France".to_owned(),
+                }),
+                false => Err(WeatherError::InvalidQuery("at sea".to_owned())),
+            }
+        }
+
+        fn elevation(&self, _lat: f64, _lon: f64) -> Result<f64, WeatherError> {
+            match self.0 {
+                true => Ok(35.0),
+                false => Err(WeatherError::InvalidQuery("no data".to_owned())),
+            }
+        }
+    }
+
+    fn forecast(name: &str) -> WeatherData {
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        weather.location.name = name.to_owned();
+        weather
+    }
+
+    #[test]
+    fn names_coordinate_queries() {
+        let query: &str = "48.8584, 2.2945";
+        let place: Enrichment = Enrichment {
+            place: true,
+            ..Enrichment::default()
+        };
+        let mut weather: WeatherData = forecast(query);
+        assert!(enrich(&mut weather, query, &place, &Stub(true)).is_empty());
+        assert_eq!(weather.location.name, "Paris");
+        assert_eq!(weather.location.region, "Île-de-France");
+        assert!(weather.location.show_coordinates);
+
+        // Weather API forecasts are already named.
+        let mut weather: WeatherData = forecast("Paris 07");
+        assert!(enrich(&mut weather, query, &place, &Stub(false)).is_empty());
+        assert_eq!(weather.location.name, "Paris 07");
+        assert!(weather.location.show_coordinates);
+
+        let mut weather: WeatherData = forecast(query);
+        let notes: Vec<String> = enrich(&mut weather, query, &place, &Stub(false));
+        assert_eq!(
+            notes,
+            ["no place name for 48.8584, 2.2945: Invalid location: at sea"]
+        );
+        assert_eq!(weather.location.name, query);
+
+        let mut weather: WeatherData = forecast("London");
+        enrich(&mut weather, "London", &place, &Stub(true));
+        assert!(!weather.location.show_coordinates);
+        assert!(enrich(&mut weather, query, &Enrichment::default(), &Stub(true)).is_empty());
+        assert_eq!(weather.location.name, "London");
+    }
+
+    #[test]
+    fn adds_elevation() {
+        let context: Enrichment = Enrichment {
+            context: true,
+            ..Enrichment::default()
+        };
+        let mut weather: WeatherData = forecast("London");
+        assert!(enrich(&mut weather, "London", &context, &Stub(true)).is_empty());
+        assert_eq!(weather.location.elevation_m, Some(35.0));
+
+        let mut weather: WeatherData = forecast("London");
+        let notes: Vec<String> = enrich(&mut weather, "London", &context, &Stub(false));
+        assert_eq!(notes.len(), 1);
+        assert!(notes[0].starts_with("no elevation for "));
+        assert_eq!(weather.location.elevation_m, None);
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+/// Exit code of invalid arguments, options or settings.
+pub const EXIT_USAGE: i32 = 2;
+/// Exit code of unreachable, failing or rate-limiting servers.
+pub const EXIT_NETWORK: i32 = 3;
+/// Exit code of a missing or rejected API key.
+pub const EXIT_AUTH: i32 = 4;
+/// Exit code of an empty or unknown location.
+pub const EXIT_LOCATION: i32 = 5;
+/// Exit code of responses or files that cannot be parsed.
+pub const EXIT_PARSE: i32 = 6;
+
+/// Error raised while fetching or parsing weather data.
+#[derive(Debug)]
+pub enum WeatherError {
+    /// The request could not be sent or the response could not be read.
+    Network(reqwest::Error),
+    /// The server answered with an unexpected status.
+    Status {
+        /// HTTP status code.
+        status: u16,
+        /// Error message from the response body, if any.
+        message: Option<String>,
+    },
+    /// The response does not match the expected structure.
+    Parse(serde_json::Error),
+    /// No API key was provided or found in the environment.
+    MissingApiKey,
+    /// The API key is malformed or was rejected by the Weather API.
+    InvalidApiKey(String),
+    /// The location query is empty or does not match any location.
+    InvalidQuery(String),
+    /// The CA bundle named by `MOSM_CA_BUNDLE` cannot be used.
+    CaBundle(String),
+    /// The config file or a `MOSM_*` variable is invalid.
+    Config(String),
+    /// `--strict` found a data problem: a response differing from the model or
+    /// cached data used because the API could not be reached.
+    Strict(String),
+}
+
+impl WeatherError {
+    /// Returns the process exit code of the CLI for this error, one of the `EXIT_*` codes.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            WeatherError::Network(_) | WeatherError::CaBundle(_) => EXIT_NETWORK,
+            WeatherError::Status {
+                status: 401 | 403, ..
+            } => EXIT_AUTH,
+            WeatherError::Status { .. } => EXIT_NETWORK,
+            WeatherError::MissingApiKey | WeatherError::InvalidApiKey(_) => EXIT_AUTH,
+            WeatherError::InvalidQuery(_) => EXIT_LOCATION,
+            WeatherError::Parse(_) | WeatherError::Strict(_) => EXIT_PARSE,
+            WeatherError::Config(_) => EXIT_USAGE,
+        }
+    }
+}
+
+impl fmt::Display for WeatherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WeatherError::Network(e) => write!(f, "Failed to reach the server: {e}"),
+            WeatherError::Status {
+                status,
+                message: Some(message),
+            } => write!(f, "Request failed with status code {status}: {message}"),
+            WeatherError::Status {
+                status,
+                message: None,
+            } => write!(f, "Request failed with status code {status}"),
+            WeatherError::Parse(e) => write!(f, "Failed to parse the response: {e}"),
+            WeatherError::MissingApiKey => write!(
+                f,
+                "No API key found, run `mosm-rs auth set` or set WEATHER_API_KEY"
+            ),
+            WeatherError::InvalidApiKey(reason) => write!(f, "Invalid API key: {reason}"),
+            WeatherError::InvalidQuery(reason) => write!(f, "Invalid location: {reason}"),
+            WeatherError::CaBundle(reason) => write!(f, "{reason}"),
+            WeatherError::Config(reason) => write!(f, "Invalid config: {reason}"),
+            WeatherError::Strict(reason) => write!(f, "Strict mode: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for WeatherError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WeatherError::Network(e) => Some(e),
+            WeatherError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for WeatherError {
+    fn from(e: reqwest::Error) -> WeatherError {
+        WeatherError::Network(e)
+    }
+}
+
+impl From<serde_json::Error> for WeatherError {
+    fn from(e: serde_json::Error) -> WeatherError {
+        WeatherError::Parse(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_errors_to_exit_codes() {
+        let status = |status: u16| WeatherError::Status {
+            status,
+            message: None,
+        };
+        assert_eq!(status(503).exit_code(), EXIT_NETWORK);
+        assert_eq!(status(403).exit_code(), EXIT_AUTH);
+        assert_eq!(WeatherError::MissingApiKey.exit_code(), EXIT_AUTH);
+        assert_eq!(
+            WeatherError::InvalidQuery("No matching location found.".to_owned()).exit_code(),
+            EXIT_LOCATION
+        );
+        let parse: serde_json::Error = serde_json::from_str::<u8>("{").unwrap_err();
+        assert_eq!(WeatherError::from(parse).exit_code(), EXIT_PARSE);
+        assert_eq!(
+            WeatherError::Config("days: out of range".to_owned()).exit_code(),
+            EXIT_USAGE
+        );
+    }
+}
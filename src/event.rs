@@ -0,0 +1,179 @@
+use crate::{Hour, WeatherData};
+use serde::Serialize;
+use std::fmt;
+
+/// Condition codes reported for thunderstorms.
+pub const THUNDER_CODES: [i32; 5] = [1087, 1273, 1276, 1279, 1282];
+
+/// Go/no-go verdict for an outdoor event.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+pub enum Verdict {
+    Go,
+    Caution,
+    NoGo,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Go => write!(f, "✅ GO"),
+            Verdict::Caution => write!(f, "⚠ CAUTION"),
+            Verdict::NoGo => write!(f, "⛔ NO-GO"),
+        }
+    }
+}
+
+/// Weather summary across the hours of an event.
+#[derive(Debug)]
+pub struct EventSummary {
+    pub min_temp_c: f64,
+    pub max_temp_c: f64,
+    /// Highest hourly chance of rain (%).
+    pub max_chance_of_rain: i32,
+    /// Total expected precipitation (mm).
+    pub total_precip_mm: f64,
+    /// Highest gust (kph), `None` if the forecast has no gusts.
+    pub max_gust_kph: Option<f64>,
+    pub thunder: bool,
+    pub verdict: Verdict,
+}
+
+/// Parses a duration such as `3h`, `90m` or `1h30m` into minutes.
+pub fn parse_duration(input: &str) -> Option<u32> {
+    let mut minutes: u32 = 0;
+    let mut number = String::new();
+    for c in input.trim().chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'h' | 'm' => {
+                let value: u32 = number.parse().ok()?;
+                minutes += if c == 'h' { value * 60 } else { value };
+                number.clear();
+            }
+            _ => return None,
+        }
+    }
+    if !number.is_empty() || minutes == 0 {
+        return None;
+    }
+    Some(minutes)
+}
+
+/// Validates a local date-time in the `YYYY-MM-DD HH:MM` format.
+pub fn is_valid_datetime(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    bytes.len() == 16
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            10 => *b == b' ',
+            13 => *b == b':',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+/// Selects the forecast hours overlapping an event window.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `at` - Local start time of the event (`YYYY-MM-DD HH:MM`).
+/// * `duration_minutes` - Length of the event.
+///
+/// # Returns
+/// The overlapping hours, or an empty `Vec` if the start is outside the forecast.
+pub fn select_hours<'a>(
+    weather: &'a WeatherData,
+    at: &str,
+    duration_minutes: u32,
+) -> Vec<&'a Hour> {
+    let Some(start) = weather.hour_at(at) else {
+        return Vec::new();
+    };
+    let start_minute: u32 = at[14..].parse().unwrap_or(0);
+    let count: usize = (start_minute + duration_minutes).div_ceil(60) as usize;
+    weather
+        .hours()
+        .skip_while(|hour| hour.time_epoch < start.time_epoch)
+        .take(count)
+        .collect()
+}
+
+/// Summarizes the weather across the event hours and derives a verdict.
+pub fn summarize(hours: &[&Hour]) -> EventSummary {
+    let min_temp_c = hours.iter().map(|h| h.temp_c).fold(f64::INFINITY, f64::min);
+    let max_temp_c = hours
+        .iter()
+        .map(|h| h.temp_c)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let max_chance_of_rain = hours.iter().map(|h| h.chance_of_rain).max().unwrap_or(0);
+    let total_precip_mm: f64 = hours.iter().map(|h| h.precip_mm).sum();
+    let max_gust_kph: Option<f64> = hours.iter().filter_map(|h| h.gust_kph).reduce(f64::max);
+    let thunder = hours
+        .iter()
+        .any(|h| THUNDER_CODES.contains(&h.condition.code));
+
+    let verdict = if thunder
+        || max_chance_of_rain >= 60
+        || total_precip_mm >= 2.0
+        || max_gust_kph.is_some_and(|gust| gust >= 60.0)
+    {
+        Verdict::NoGo
+    } else if max_chance_of_rain >= 30
+            || total_precip_mm >= 0.5
+            // Unknown gusts call for caution rather than a go.
+            || max_gust_kph.is_none_or(|gust| gust >= 40.0)
+    {
+        Verdict::Caution
+    } else {
+        Verdict::Go
+    };
+
+    EventSummary {
+        min_temp_c,
+        max_temp_c,
+        max_chance_of_rain,
+        total_precip_mm,
+        max_gust_kph,
+        thunder,
+        verdict,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("3h"), Some(180));
+        assert_eq!(parse_duration("90m"), Some(90));
+        assert_eq!(parse_duration("1h30m"), Some(90));
+        assert_eq!(parse_duration("3"), None);
+        assert_eq!(parse_duration("0h"), None);
+        assert_eq!(parse_duration("2d"), None);
+    }
+
+    #[test]
+    fn summarizes_hours() {
+        let mut weather: crate::WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        let hours: Vec<&Hour> = weather.hours().take(3).collect();
+        let summary: EventSummary = summarize(&hours);
+        assert!(summary.max_gust_kph.is_some());
+
+        for hour in &mut weather.forecast.forecastday[0].hour {
+            hour.gust_kph = None;
+        }
+        let hours: Vec<&Hour> = weather.hours().take(3).collect();
+        let gustless: EventSummary = summarize(&hours);
+        assert_eq!(gustless.max_gust_kph, None);
+        assert!(gustless.verdict >= Verdict::Caution);
+    }
+
+    #[test]
+    fn validates_datetimes() {
+        assert!(is_valid_datetime("2025-09-20 16:00"));
+        assert!(!is_valid_datetime("2025-09-20 16"));
+        assert!(!is_valid_datetime("2025/09/20 16:00"));
+    }
+}
@@ -0,0 +1,70 @@
+use serde::Serialize;
+
+use crate::{Location, schedule};
+
+/// Depression of the sun below the horizon at dawn (Fajr), in degrees.
+///
+/// 18° is the Muslim World League convention; other authorities use 15° to 19.5°.
+pub const DEFAULT_DAWN_ANGLE: f64 = 18.0;
+
+/// Fasting window of one day, in local time.
+#[derive(Serialize, Debug)]
+pub struct FastingDay {
+    /// Local date (`YYYY-MM-DD`).
+    pub date: String,
+    /// Dawn (`HH:MM`), when the fast begins: the end of suhoor.
+    pub dawn: Option<String>,
+    /// Sunset (`HH:MM`), when the fast ends: iftar.
+    pub sunset: Option<String>,
+    /// Length of the fast, in seconds.
+    pub fast_secs: Option<i64>,
+}
+
+/// Computes the dawn and sunset times for consecutive days from today.
+///
+/// Dawn is `None` where the sun does not sink `dawn_angle` below the horizon, as
+/// at high latitudes around the summer solstice.
+///
+/// # Arguments
+/// * `location` - Location with its local time, for today's date and UTC offset.
+/// * `days` - Number of days.
+/// * `dawn_angle` - Depression of the sun at dawn, in degrees.
+pub fn fasting_days(location: &Location, days: u32, dawn_angle: f64) -> Vec<FastingDay> {
+    schedule::day_schedules(location, days, dawn_angle)
+        .into_iter()
+        .map(|day| FastingDay {
+            fast_secs: day
+                .dawn
+                .as_ref()
+                .zip(day.sunset.as_ref())
+                .map(|(dawn, sunset)| sunset.epoch - dawn.epoch),
+            dawn: day.dawn.map(|event| event.time),
+            sunset: day.sunset.map(|event| event.time),
+            date: day.date,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn computes_fasting_times() {
+        let weather: crate::WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let days: Vec<FastingDay> = fasting_days(&weather.location, 3, DEFAULT_DAWN_ANGLE);
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0].date, "2025-08-01");
+        assert_eq!(days[2].date, "2025-08-03");
+        let dawn: &str = days[0].dawn.as_deref().unwrap();
+        assert!(("02:20".."02:50").contains(&dawn));
+        assert_eq!(days[0].sunset.as_deref(), Some("20:48"));
+        assert!((18 * 3600..18 * 3600 + 15 * 60).contains(&days[0].fast_secs.unwrap()));
+
+        // A shallower dawn angle starts the fast later.
+        let days: Vec<FastingDay> = fasting_days(&weather.location, 1, 15.0);
+        assert!(days[0].dawn.as_deref().unwrap() > dawn);
+    }
+}
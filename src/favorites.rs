@@ -0,0 +1,354 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::WeatherError;
+use crate::config::Config;
+
+/// Formats accepted by `locations import --from`.
+pub const IMPORT_FORMATS: [&str; 3] = ["wttr", "owm-cli", "csv"];
+
+/// Named locations saved by the user, stored in `locations.json` next to the config.
+///
+/// A saved location is used as `@name`, or by its bare name when no place is
+/// meant, e.g. `mosm-rs home`.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Favorites {
+    /// Name of the location used when none is given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    /// Location query of each name.
+    #[serde(default)]
+    pub locations: BTreeMap<String, String>,
+}
+
+impl Favorites {
+    /// Returns `locations.json` in the directory of the config file, see [`Config::dir`].
+    pub fn default_path() -> Option<PathBuf> {
+        Some(Config::dir()?.join("locations.json"))
+    }
+
+    /// Reads the saved locations; a missing file yields none.
+    ///
+    /// # Returns
+    /// The parsed `Favorites`, or `WeatherError::Config` if the file is unreadable or invalid.
+    pub fn load(path: &Path) -> Result<Favorites, WeatherError> {
+        let invalid =
+            |reason: String| WeatherError::Config(format!("{}: {reason}", path.display()));
+        match std::fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json).map_err(|e| invalid(e.to_string())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Favorites::default()),
+            Err(e) => Err(invalid(e.to_string())),
+        }
+    }
+
+    /// Writes the saved locations as pretty-printed JSON, creating the directory if needed.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    /// Saves a location under a name, replacing any previous one.
+    ///
+    /// # Arguments
+    /// * `name` - Name of the location, with or without the leading `@`.
+    /// * `query` - Location query, as given on the command line.
+    ///
+    /// # Returns
+    /// The reason if the name or query is invalid.
+    pub fn add(&mut self, name: &str, query: &str) -> Result<(), String> {
+        let name: String = normalize(name);
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err("names are single words, such as home or work".to_owned());
+        }
+        if query.trim().is_empty() {
+            return Err(format!("missing location for @{name}"));
+        }
+        self.locations.insert(name, query.trim().to_owned());
+        Ok(())
+    }
+
+    /// Saves imported locations, keeping the saved ones of the same names.
+    ///
+    /// # Arguments
+    /// * `entries` - `(name, query)` pairs, see [`parse_import`].
+    ///
+    /// # Returns
+    /// The names saved, and the names skipped with the reason.
+    pub fn import(&mut self, entries: &[(String, String)]) -> (Vec<String>, Vec<(String, String)>) {
+        let mut saved: Vec<String> = Vec::new();
+        let mut skipped: Vec<(String, String)> = Vec::new();
+        for (name, query) in entries {
+            let reason: Result<(), String> = if self.locations.contains_key(&normalize(name)) {
+                Err("already saved".to_owned())
+            } else {
+                self.add(name, query)
+            };
+            match reason {
+                Ok(()) => saved.push(normalize(name)),
+                Err(reason) => skipped.push((name.clone(), reason)),
+            }
+        }
+        (saved, skipped)
+    }
+
+    /// Removes a saved location, and the default if it pointed there.
+    ///
+    /// # Returns
+    /// `false` if no location had that name.
+    pub fn remove(&mut self, name: &str) -> bool {
+        let name: String = normalize(name);
+        if self.default.as_ref() == Some(&name) {
+            self.default = None;
+        }
+        self.locations.remove(&name).is_some()
+    }
+
+    /// Makes a saved location the default.
+    ///
+    /// # Returns
+    /// `false` if no location had that name.
+    pub fn set_default(&mut self, name: &str) -> bool {
+        let name: String = normalize(name);
+        let found: bool = self.locations.contains_key(&name);
+        if found {
+            self.default = Some(name);
+        }
+        found
+    }
+
+    /// Returns the query of the default location, if one is set.
+    pub fn default_query(&self) -> Option<&str> {
+        self.locations
+            .get(self.default.as_ref()?)
+            .map(String::as_str)
+    }
+
+    /// Resolves a location given by the user.
+    ///
+    /// `@name` must be a saved location; a bare name is replaced when saved and
+    /// passed through as a place otherwise.
+    ///
+    /// # Returns
+    /// The location query, or `WeatherError::InvalidQuery` for an unknown `@name`.
+    pub fn resolve<'a>(&'a self, query: &'a str) -> Result<&'a str, WeatherError> {
+        match self.locations.get(&normalize(query)) {
+            Some(saved) => Ok(saved),
+            None if query.trim_start().starts_with('@') => Err(WeatherError::InvalidQuery(
+                format!("no saved location {}", query.trim()),
+            )),
+            None => Ok(query),
+        }
+    }
+}
+
+/// Reads the locations saved by another tool, as `(name, query)` pairs.
+///
+/// * `wttr` - One place or `wttr.in` URL per line, e.g. `https://wttr.in/Paris?m`,
+///   with `#` comments. Names are made from the places.
+/// * `owm-cli` - JSON list of locations, alone or under `locations`, each a city
+///   name or an object with `name`, optional `country` and optional `lat`/`lon`.
+/// * `csv` - `name,location` or `name,lat,lon` rows, with an optional header row
+///   starting with `name`. Fields may be double-quoted.
+///
+/// # Arguments
+/// * `format` - One of [`IMPORT_FORMATS`].
+/// * `text` - Content of the exported file.
+///
+/// # Returns
+/// The locations in file order, or the reason the file cannot be read.
+pub fn parse_import(format: &str, text: &str) -> Result<Vec<(String, String)>, String> {
+    match format {
+        "wttr" => Ok(text
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or("").trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let path: &str = line.rsplit('/').next().unwrap_or(line);
+                let place: String = path
+                    .split('?')
+                    .next()
+                    .unwrap_or(path)
+                    .trim_start_matches('~')
+                    .replace(['+', '_'], " ");
+                (name_for(&place), place)
+            })
+            .collect()),
+        "owm-cli" => {
+            let json: serde_json::Value = serde_json::from_str(text).map_err(|e| e.to_string())?;
+            let entries: &[serde_json::Value] = json
+                .as_array()
+                .or_else(|| json["locations"].as_array())
+                .ok_or("expected a list of locations")?;
+            entries
+                .iter()
+                .map(|entry| {
+                    let name: &str = entry
+                        .as_str()
+                        .or_else(|| entry["name"].as_str())
+                        .ok_or_else(|| format!("location without a name: {entry}"))?;
+                    let query: String = match (entry["lat"].as_f64(), entry["lon"].as_f64()) {
+                        (Some(lat), Some(lon)) => format!("{lat},{lon}"),
+                        _ => match entry["country"].as_str() {
+                            Some(country) => format!("{name}, {country}"),
+                            None => name.to_owned(),
+                        },
+                    };
+                    Ok((name_for(name), query))
+                })
+                .collect()
+        }
+        "csv" => text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, csv_fields(line)))
+            .filter(|(i, fields)| {
+                !(fields.iter().all(String::is_empty)
+                    || *i == 1 && fields[0].eq_ignore_ascii_case("name"))
+            })
+            .map(|(i, fields)| match fields.as_slice() {
+                [name, query] => Ok((normalize(name), query.clone())),
+                [name, lat, lon] if lat.parse::<f64>().is_ok() && lon.parse::<f64>().is_ok() => {
+                    Ok((normalize(name), format!("{lat},{lon}")))
+                }
+                _ => Err(format!("line {i}: expected name,location or name,lat,lon")),
+            })
+            .collect(),
+        _ => Err(format!(
+            "unknown format, expected {}",
+            IMPORT_FORMATS.join(", ")
+        )),
+    }
+}
+
+/// Splits a CSV line into trimmed fields, honouring double quotes.
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields: Vec<String> = vec![String::new()];
+    let mut quoted: bool = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields.iter().map(|field| field.trim().to_owned()).collect()
+}
+
+/// Makes a location name from a place, e.g. `new-york` for `New York, NY`.
+fn name_for(place: &str) -> String {
+    place
+        .split(',')
+        .next()
+        .unwrap_or(place)
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join("-")
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '.')
+        .collect()
+}
+
+/// Returns the lowercase name without the leading `@`.
+fn normalize(name: &str) -> String {
+    name.trim().trim_start_matches('@').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_and_resolves_locations() {
+        let mut favorites: Favorites = Favorites::default();
+        favorites.add("@Home", " London, UK ").unwrap();
+        favorites.add("work", "Canary Wharf").unwrap();
+        assert!(favorites.add("my office", "Leeds").is_err());
+        assert!(favorites.add("gym", " ").is_err());
+
+        assert_eq!(favorites.resolve("@home").unwrap(), "London, UK");
+        assert_eq!(favorites.resolve("Work").unwrap(), "Canary Wharf");
+        assert_eq!(favorites.resolve("Paris").unwrap(), "Paris");
+        assert!(favorites.resolve("@gym").is_err());
+
+        assert_eq!(favorites.default_query(), None);
+        assert!(favorites.set_default("home"));
+        assert!(!favorites.set_default("gym"));
+        assert_eq!(favorites.default_query(), Some("London, UK"));
+
+        let path: PathBuf = std::env::temp_dir()
+            .join(format!("mosm-favorites-test-{}", std::process::id()))
+            .join("locations.json");
+        assert_eq!(Favorites::load(&path).unwrap(), Favorites::default());
+        favorites.save(&path).unwrap();
+        assert_eq!(Favorites::load(&path).unwrap(), favorites);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert!(favorites.remove("@home"));
+        assert!(!favorites.remove("home"));
+        assert_eq!(favorites.default, None);
+    }
+
+    #[test]
+    fn imports_other_tools_locations() {
+        let pair = |name: &str, query: &str| (name.to_owned(), query.to_owned());
+        assert_eq!(
+            parse_import(
+                "wttr",
+                "# cities\nhttps://wttr.in/New+York?m\n~Eiffel+Tower\nSão Paulo, Brazil\n"
+            )
+            .unwrap(),
+            [
+                pair("new-york", "New York"),
+                pair("eiffel-tower", "Eiffel Tower"),
+                pair("são-paulo", "São Paulo, Brazil")
+            ]
+        );
+        assert_eq!(
+            parse_import(
+                "owm-cli",
+                r#"{"locations":["Leeds",{"name":"Paris","country":"FR"},
+                    {"name":"Home","lat":51.5,"lon":-0.12}]}"#
+            )
+            .unwrap(),
+            [
+                pair("leeds", "Leeds"),
+                pair("paris", "Paris, FR"),
+                pair("home", "51.5,-0.12")
+            ]
+        );
+        assert_eq!(
+            parse_import(
+                "csv",
+                "name,location\nwork,\"Canary Wharf, London\"\n\ncabin,61.1,10.4\n"
+            )
+            .unwrap(),
+            [
+                pair("work", "Canary Wharf, London"),
+                pair("cabin", "61.1,10.4")
+            ]
+        );
+        assert!(parse_import("csv", "gym").is_err());
+        assert!(parse_import("owm-cli", "{}").is_err());
+
+        let mut favorites: Favorites = Favorites::default();
+        favorites.add("work", "Leeds").unwrap();
+        let (saved, skipped) = favorites.import(&[
+            pair("work", "Canary Wharf"),
+            pair("cabin", "61.1,10.4"),
+            pair("", "Nowhere"),
+        ]);
+        assert_eq!(saved, ["cabin"]);
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(favorites.resolve("work").unwrap(), "Leeds");
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{Hour, WeatherData, time};
+
+/// First and last hour of a night: from the evening of its date to the next morning.
+pub const NIGHT_HOURS: (u32, u32) = (18, 9);
+
+/// Cloud cover (%) under which a night counts as clear.
+const CLEAR_SKY_CLOUD: i32 = 30;
+
+/// Escalating frost stage of a night.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Advisory,
+    Warning,
+    Critical,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Advisory => write!(f, "ℹ ADVISORY"),
+            Stage::Warning => write!(f, "⚠ WARNING"),
+            Stage::Critical => write!(f, "⛔ CRITICAL"),
+        }
+    }
+}
+
+/// Minimum temperatures (°C) at which each stage starts.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Stages {
+    /// Frost is possible on exposed crops.
+    pub advisory_c: f64,
+    /// Air frost: protect sensitive crops.
+    pub warning_c: f64,
+    /// Hard frost: blossom and young fruit are damaged.
+    pub critical_c: f64,
+}
+
+impl Default for Stages {
+    fn default() -> Self {
+        Stages {
+            advisory_c: 3.0,
+            warning_c: 0.0,
+            critical_c: -2.0,
+        }
+    }
+}
+
+impl Stages {
+    /// Returns the stage reached by a minimum temperature, if any.
+    pub fn stage(&self, min_temp_c: f64) -> Option<Stage> {
+        if min_temp_c <= self.critical_c {
+            Some(Stage::Critical)
+        } else if min_temp_c <= self.warning_c {
+            Some(Stage::Warning)
+        } else if min_temp_c <= self.advisory_c {
+            Some(Stage::Advisory)
+        } else {
+            None
+        }
+    }
+}
+
+/// Frost outlook of one night.
+#[derive(Serialize, Debug)]
+pub struct Night {
+    /// Local date of the evening the night starts (`YYYY-MM-DD`).
+    pub date: String,
+    /// Lowest forecast temperature of the night.
+    pub min_temp_c: f64,
+    /// Local time of the lowest temperature (`YYYY-MM-DD HH:MM`).
+    pub min_at: String,
+    /// Dew point at the coldest hour, if known.
+    pub dewpoint_c: Option<f64>,
+    /// Whether the sky is clear at the coldest hour.
+    pub clear: bool,
+    pub stage: Option<Stage>,
+}
+
+/// Summarizes the frost risk of each remaining night of the forecast.
+///
+/// A night's stage comes from its minimum temperature. Under a clear sky with a
+/// dew point at or below freezing, plants radiate heat and cool below the air
+/// temperature, so the stage is raised by one (or set to advisory). Without a
+/// dew point, the stage is left as the temperature gives it.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `stages` - Temperatures at which the stages start.
+pub fn nights(weather: &WeatherData, stages: &Stages) -> Vec<Night> {
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    let hours: Vec<&Hour> = weather
+        .hours()
+        .filter(|hour| hour.time_epoch > from)
+        .collect();
+    let (evening, morning) = NIGHT_HOURS;
+    let mut nights: Vec<Night> = Vec::new();
+    for day in &weather.forecast.forecastday {
+        let night: Vec<&&Hour> = hours
+            .iter()
+            .filter(|hour| {
+                let clock: u32 = hour.time[11..13].parse().unwrap_or(0);
+                (hour.time.starts_with(&day.date) && clock >= evening)
+                    || (clock <= morning && night_date(&hour.time) == day.date)
+            })
+            .collect();
+        let Some(coldest) = night.iter().min_by(|a, b| a.temp_c.total_cmp(&b.temp_c)) else {
+            continue;
+        };
+        let clear: bool = coldest.cloud < CLEAR_SKY_CLOUD;
+        let stage: Option<Stage> = match stages.stage(coldest.temp_c) {
+            stage if !clear || coldest.dewpoint_c.is_none_or(|dewpoint| dewpoint > 0.0) => stage,
+            None => Some(Stage::Advisory),
+            Some(Stage::Advisory) => Some(Stage::Warning),
+            Some(_) => Some(Stage::Critical),
+        };
+        nights.push(Night {
+            date: day.date.clone(),
+            min_temp_c: coldest.temp_c,
+            min_at: coldest.time.clone(),
+            dewpoint_c: coldest.dewpoint_c,
+            clear,
+            stage,
+        });
+    }
+    nights
+}
+
+/// Returns the date of the evening before a local time (`YYYY-MM-DD HH:MM`).
+fn night_date(time: &str) -> String {
+    let midnight: i64 = time::local_epoch(&time[..10], 0);
+    time::format_local(midnight - 43_200, 0)[..10].to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn stages_nights() {
+        let weather: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let nights: Vec<Night> = nights(&weather, &Stages::default());
+        assert_eq!(nights.len(), 3);
+        assert_eq!(nights[0].date, "2025-08-01");
+        // Tonight runs into tomorrow morning; the last night ends with the forecast.
+        assert!(nights[0].min_at.starts_with("2025-08-02 0"));
+        assert!(nights.iter().all(|night| night.stage.is_none()));
+
+        // An August night in London is an advisory only with a very high threshold.
+        let stages: Stages = Stages {
+            advisory_c: nights[0].min_temp_c,
+            ..Stages::default()
+        };
+        let tonight: &Night = &super::nights(&weather, &stages)[0];
+        assert_eq!(tonight.stage, Some(Stage::Advisory));
+
+        // Under a clear sky, only a known dew point at or below freezing raises it.
+        let mut clear: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        for forecast_day in &mut clear.forecast.forecastday {
+            for hour in &mut forecast_day.hour {
+                hour.cloud = 0;
+                hour.dewpoint_c = None;
+            }
+        }
+        assert_eq!(
+            super::nights(&clear, &stages)[0].stage,
+            Some(Stage::Advisory)
+        );
+        for forecast_day in &mut clear.forecast.forecastday {
+            for hour in &mut forecast_day.hour {
+                hour.dewpoint_c = Some(-1.0);
+            }
+        }
+        assert_eq!(
+            super::nights(&clear, &stages)[0].stage,
+            Some(Stage::Warning)
+        );
+
+        assert_eq!(Stages::default().stage(-2.0), Some(Stage::Critical));
+        assert_eq!(Stages::default().stage(-0.5), Some(Stage::Warning));
+        assert_eq!(Stages::default().stage(3.5), None);
+    }
+}
@@ -0,0 +1,73 @@
+/// Mean Earth radius, in kilometres.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Great-circle distance between two points, in kilometres.
+pub fn distance_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians();
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * a.sqrt().asin()
+}
+
+/// Initial bearing from the first point to the second, in degrees clockwise from north.
+pub fn bearing_deg(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lon = (lon2 - lon1).to_radians();
+    let y = d_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+    y.atan2(x).to_degrees().rem_euclid(360.0)
+}
+
+/// The 16 compass points, clockwise from north.
+const POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW",
+    "NNW",
+];
+
+/// Returns the 16-point compass direction (`N`, `NNE`, ... `NNW`) of a bearing.
+pub fn compass_point(bearing_deg: f64) -> &'static str {
+    POINTS[((bearing_deg.rem_euclid(360.0) / 22.5).round() as usize) % 16]
+}
+
+/// Parses a bearing given in degrees (`270`) or as a compass point (`W`, `wsw`).
+pub fn parse_bearing(text: &str) -> Option<f64> {
+    match text.parse::<f64>() {
+        Ok(degrees) => (0.0..=360.0).contains(&degrees).then_some(degrees % 360.0),
+        Err(_) => POINTS
+            .iter()
+            .position(|point| point.eq_ignore_ascii_case(text))
+            .map(|index| index as f64 * 22.5),
+    }
+}
+
+/// Parses a `lat,lon` location query, e.g. `51.5, -0.12`, within the valid ranges.
+pub fn parse_coordinates(query: &str) -> Option<(f64, f64)> {
+    let (lat, lon) = query.split_once(',')?;
+    let lat: f64 = lat.trim().parse().ok()?;
+    let lon: f64 = lon.trim().parse().ok()?;
+    ((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)).then_some((lat, lon))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_distance_and_bearing() {
+        // London to Paris: ~344 km, heading south-east.
+        let distance: f64 = distance_km(51.5072, -0.1276, 48.8566, 2.3522);
+        assert!((distance - 344.0).abs() < 2.0, "{distance}");
+        let bearing: f64 = bearing_deg(51.5072, -0.1276, 48.8566, 2.3522);
+        assert!((bearing - 148.0).abs() < 1.0, "{bearing}");
+        assert_eq!(compass_point(bearing), "SSE");
+        assert_eq!(compass_point(359.0), "N");
+        assert_eq!(compass_point(270.0), "W");
+        assert_eq!(parse_bearing("wsw"), Some(247.5));
+        assert_eq!(parse_bearing("360"), Some(0.0));
+        assert_eq!(parse_bearing("400"), None);
+        assert_eq!(parse_coordinates("51.5, -0.12"), Some((51.5, -0.12)));
+        assert_eq!(parse_coordinates("London, UK"), None);
+        assert_eq!(parse_coordinates("95,0"), None);
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::WeatherError;
+
+/// Consecutive failures after which a provider is considered down.
+pub const DOWN_AFTER_FAILURES: u32 = 3;
+/// Seconds a down provider is skipped for, before it is tried first again.
+pub const DOWN_FOR_SECS: u64 = 10 * 60;
+
+/// Outcome of the recent requests to a provider.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ProviderHealth {
+    /// UNIX time of the last successful request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_success: Option<u64>,
+    /// UNIX time of the last failed request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_failure: Option<u64>,
+    /// Message of the last failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// Failures since the last success.
+    #[serde(default)]
+    pub consecutive_failures: u32,
+}
+
+/// Health of each forecast provider, stored in `state.json` of the state directory.
+///
+/// A provider failing [`DOWN_AFTER_FAILURES`] times in a row is tried after the
+/// fallback provider for [`DOWN_FOR_SECS`], so a dead API does not slow down every run.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Health {
+    #[serde(default)]
+    pub providers: BTreeMap<String, ProviderHealth>,
+}
+
+impl Health {
+    /// Returns `$XDG_STATE_HOME/mosm/state.json`, else `~/.local/state/mosm/state.json`.
+    pub fn default_path() -> Option<PathBuf> {
+        let state_home: PathBuf = match std::env::var_os("XDG_STATE_HOME") {
+            Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+            _ => PathBuf::from(std::env::var_os("HOME")?).join(".local/state"),
+        };
+        Some(state_home.join("mosm").join("state.json"))
+    }
+
+    /// Reads the provider health; a missing or unreadable file yields none, as the
+    /// state is only a hint.
+    pub fn load(path: &Path) -> Health {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Writes the provider health as pretty-printed JSON, creating the directory if needed.
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    /// Records a successful request to a provider.
+    pub fn record_success(&mut self, provider: &str, now: u64) {
+        let health: &mut ProviderHealth = self.providers.entry(provider.to_owned()).or_default();
+        health.last_success = Some(now);
+        health.consecutive_failures = 0;
+    }
+
+    /// Records a failed request to a provider.
+    pub fn record_failure(&mut self, provider: &str, now: u64, error: &WeatherError) {
+        let health: &mut ProviderHealth = self.providers.entry(provider.to_owned()).or_default();
+        health.last_failure = Some(now);
+        health.last_error = Some(error.to_string());
+        health.consecutive_failures += 1;
+    }
+
+    /// Returns `true` if a provider failed repeatedly within the last [`DOWN_FOR_SECS`].
+    pub fn is_down(&self, provider: &str, now: u64) -> bool {
+        self.providers.get(provider).is_some_and(|health| {
+            health.consecutive_failures >= DOWN_AFTER_FAILURES
+                && health
+                    .last_failure
+                    .is_some_and(|at| now.saturating_sub(at) < DOWN_FOR_SECS)
+        })
+    }
+}
+
+/// Returns `true` if an error comes from the provider rather than the request, so
+/// another provider may succeed.
+///
+/// Unknown locations and invalid settings fail the same way everywhere.
+pub fn warrants_failover(error: &WeatherError) -> bool {
+    !matches!(
+        error,
+        WeatherError::InvalidQuery(_) | WeatherError::Config(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_failing_providers() {
+        let mut health: Health = Health::default();
+        let error: WeatherError = WeatherError::Status {
+            status: 503,
+            message: None,
+        };
+        for now in [100, 200] {
+            health.record_failure("weatherapi", now, &error);
+        }
+        assert!(!health.is_down("weatherapi", 300));
+        health.record_failure("weatherapi", 300, &error);
+        assert!(health.is_down("weatherapi", 300));
+        assert!(!health.is_down("weatherapi", 300 + DOWN_FOR_SECS));
+        assert!(!health.is_down("open-meteo", 300));
+
+        health.record_success("weatherapi", 400);
+        assert!(!health.is_down("weatherapi", 400));
+        assert_eq!(
+            health.providers["weatherapi"].last_error.as_deref(),
+            Some(error.to_string().as_str())
+        );
+
+        assert!(warrants_failover(&error));
+        assert!(!warrants_failover(&WeatherError::InvalidQuery(
+            "No matching location found.".to_owned()
+        )));
+    }
+}
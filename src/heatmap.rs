@@ -0,0 +1,92 @@
+use crate::color::{self, PALETTE};
+use crate::{Hour, WeatherData};
+use std::io::Write;
+
+/// Hourly metric shown in the heatmap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+    Temp,
+    Rain,
+    Wind,
+    Humidity,
+    Uv,
+}
+
+impl Metric {
+    /// Parses a metric name as given to `--metric`.
+    pub fn from_name(name: &str) -> Option<Metric> {
+        match name {
+            "temp" => Some(Metric::Temp),
+            "rain" => Some(Metric::Rain),
+            "wind" => Some(Metric::Wind),
+            "humidity" => Some(Metric::Humidity),
+            "uv" => Some(Metric::Uv),
+            _ => None,
+        }
+    }
+
+    /// Human-readable name with unit.
+    pub fn label(self) -> &'static str {
+        match self {
+            Metric::Temp => "temperature (°C)",
+            Metric::Rain => "chance of rain (%)",
+            Metric::Wind => "wind (kph)",
+            Metric::Humidity => "humidity (%)",
+            Metric::Uv => "UV index",
+        }
+    }
+
+    /// Value of the metric for an hour, `None` if missing from the forecast.
+    pub fn value(self, hour: &Hour) -> Option<f64> {
+        match self {
+            Metric::Temp => Some(hour.temp_c),
+            Metric::Rain => Some(f64::from(hour.chance_of_rain)),
+            Metric::Wind => Some(hour.wind_kph),
+            Metric::Humidity => Some(f64::from(hour.humidity)),
+            Metric::Uv => hour.uv,
+        }
+    }
+}
+
+/// Writes a heatmap with one row per forecast day and one cell per hour.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `metric` - Hourly metric to plot.
+/// * `out` - Destination of the rendered heatmap.
+pub fn render<W: Write>(weather: &WeatherData, metric: Metric, out: &mut W) -> std::io::Result<()> {
+    let values: Vec<f64> = weather
+        .hours()
+        .filter_map(|hour| metric.value(hour))
+        .collect();
+    let min: f64 = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max: f64 = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    writeln!(out, "{}\nHeatmap: {}\n", weather.location, metric.label())?;
+    write!(out, "       ")?;
+    for hour in (0..24).step_by(3) {
+        write!(out, "{hour:02}    ")?;
+    }
+    writeln!(out)?;
+    for forecast_day in &weather.forecast.forecastday {
+        write!(
+            out,
+            "{}  ",
+            forecast_day.date.get(5..).unwrap_or(&forecast_day.date)
+        )?;
+        for hour in &forecast_day.hour {
+            match metric.value(hour) {
+                Some(value) => write!(out, "\x1b[48;5;{}m  ", color::scale(value, min, max))?,
+                // Missing hours are left blank.
+                None => write!(out, "\x1b[0m  ")?,
+            }
+        }
+        writeln!(out, "\x1b[0m")?;
+    }
+    write!(out, "\n{min} ")?;
+    for code in PALETTE {
+        write!(out, "\x1b[48;5;{code}m ")?;
+    }
+    writeln!(out, "\x1b[0m {max}")?;
+    Ok(())
+}
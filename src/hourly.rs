@@ -0,0 +1,67 @@
+use crate::{Hour, WeatherData};
+
+/// Returns the forecast hours from the hour in progress onwards.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `count` - Maximum number of hours.
+pub fn upcoming(weather: &WeatherData, count: usize) -> Vec<&Hour> {
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    weather
+        .hours()
+        .filter(|hour| hour.time_epoch > from)
+        .take(count)
+        .collect()
+}
+
+/// Resolves a day given as an offset from today (`0`, `1`...) or a `YYYY-MM-DD` date.
+///
+/// # Returns
+/// The date of the matching forecast day, or `None` if it is outside the forecast.
+pub fn resolve_day<'a>(weather: &'a WeatherData, day: &str) -> Option<&'a str> {
+    let days = &weather.forecast.forecastday;
+    let forecast_day = match day.parse::<usize>() {
+        Ok(offset) => days.get(offset),
+        Err(_) => days.iter().find(|forecast_day| forecast_day.date == day),
+    };
+    forecast_day.map(|forecast_day| forecast_day.date.as_str())
+}
+
+/// Returns the forecast hours of a day, from midnight.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `date` - Date of the day (`YYYY-MM-DD`).
+/// * `count` - Maximum number of hours.
+pub fn day_hours<'a>(weather: &'a WeatherData, date: &str, count: usize) -> Vec<&'a Hour> {
+    weather
+        .hours()
+        .filter(|hour| hour.time.starts_with(date))
+        .take(count)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn selects_hours() {
+        let weather: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let hours: Vec<&Hour> = upcoming(&weather, 12);
+        assert_eq!(hours.len(), 12);
+        assert_eq!(hours[0].time, "2025-08-01 14:00");
+        assert_eq!(hours[11].time, "2025-08-02 01:00");
+
+        assert_eq!(resolve_day(&weather, "1"), Some("2025-08-02"));
+        assert_eq!(resolve_day(&weather, "2025-08-03"), Some("2025-08-03"));
+        assert_eq!(resolve_day(&weather, "3"), None);
+        assert_eq!(resolve_day(&weather, "tomorrow"), None);
+
+        let hours: Vec<&Hour> = day_hours(&weather, "2025-08-02", 24);
+        assert_eq!(hours.len(), 24);
+        assert_eq!(hours[0].time, "2025-08-02 00:00");
+    }
+}
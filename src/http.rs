@@ -0,0 +1,244 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::WeatherError;
+
+/// Environment variable pointing to a PEM bundle of extra trusted CA certificates,
+/// e.g. for corporate TLS-intercepting proxies.
+pub const CA_BUNDLE_ENV: &str = "MOSM_CA_BUNDLE";
+
+/// Retries and timeout of the HTTP requests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Retry {
+    /// Retries after a failed attempt, 0 for a single attempt.
+    pub retries: u32,
+    /// Wait before the first retry, doubled before each further one.
+    pub delay: Duration,
+    /// Limit for a whole attempt: connecting, sending and reading the response.
+    pub timeout: Duration,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Retry {
+            retries: 2,
+            delay: Duration::from_millis(500),
+            timeout: Duration::from_secs(15),
+        }
+    }
+}
+
+/// Settings of every client built by this module, see [`configure`].
+static RETRY: OnceLock<Retry> = OnceLock::new();
+
+/// Sets the retries and timeout of the process's requests.
+///
+/// Must be called before the first client is built; later calls are ignored.
+///
+/// # Returns
+/// `false` if the settings were already fixed.
+pub fn configure(retry: Retry) -> bool {
+    RETRY.set(retry).is_ok()
+}
+
+/// Returns the configured settings, or the defaults.
+pub fn retry() -> &'static Retry {
+    RETRY.get_or_init(Retry::default)
+}
+
+/// Returns `true` if a failure may pass on its own: timeouts, refused or dropped
+/// connections, rate limiting (429) and server errors (500, 502-504).
+pub fn is_transient(error: &WeatherError) -> bool {
+    match error {
+        WeatherError::Network(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+        WeatherError::Status { status, .. } => matches!(status, 429 | 500 | 502..=504),
+        _ => false,
+    }
+}
+
+/// Returns the failure of a response status worth retrying, if it is one.
+fn transient_status(status: u16) -> Option<WeatherError> {
+    let failure: WeatherError = WeatherError::Status {
+        status,
+        message: None,
+    };
+    is_transient(&failure).then_some(failure)
+}
+
+/// Sends a request, retrying transient failures with exponential backoff.
+///
+/// # Arguments
+/// * `request` - Request to send; streamed bodies cannot be retried.
+///
+/// # Returns
+/// The response of the first attempt that did not fail transiently, else the
+/// outcome of the last attempt, so responses may still have an error status.
+#[cfg(feature = "blocking")]
+pub fn send(
+    request: reqwest::blocking::RequestBuilder,
+) -> Result<reqwest::blocking::Response, WeatherError> {
+    let retry: &Retry = retry();
+    let mut delay: Duration = retry.delay;
+    for _ in 0..retry.retries {
+        let Some(attempt) = request.try_clone() else {
+            break;
+        };
+        let failure: WeatherError = match attempt.send() {
+            Ok(response) => match transient_status(response.status().as_u16()) {
+                Some(failure) => failure,
+                None => return Ok(response),
+            },
+            Err(e) => WeatherError::from(e),
+        };
+        if !is_transient(&failure) {
+            return Err(failure);
+        }
+        std::thread::sleep(delay);
+        delay *= 2;
+    }
+    Ok(request.send()?)
+}
+
+/// Async variant of [`send`], waiting between attempts on the caller's tokio
+/// runtime, which needs its time driver enabled.
+pub async fn send_async(
+    request: reqwest::RequestBuilder,
+) -> Result<reqwest::Response, WeatherError> {
+    let retry: &Retry = retry();
+    let mut delay: Duration = retry.delay;
+    for _ in 0..retry.retries {
+        let Some(attempt) = request.try_clone() else {
+            break;
+        };
+        let failure: WeatherError = match attempt.send().await {
+            Ok(response) => match transient_status(response.status().as_u16()) {
+                Some(failure) => failure,
+                None => return Ok(response),
+            },
+            Err(e) => WeatherError::from(e),
+        };
+        if !is_transient(&failure) {
+            return Err(failure);
+        }
+        tokio::time::sleep(delay).await;
+        delay *= 2;
+    }
+    Ok(request.send().await?)
+}
+
+/// Loads the certificates from the bundle named by `MOSM_CA_BUNDLE`, if set.
+fn extra_certificates() -> Result<Vec<reqwest::Certificate>, WeatherError> {
+    let Ok(path) = std::env::var(CA_BUNDLE_ENV) else {
+        return Ok(Vec::new());
+    };
+    let pem: Vec<u8> = std::fs::read(&path)
+        .map_err(|e| WeatherError::CaBundle(format!("Failed to read CA bundle {path}: {e}")))?;
+    reqwest::Certificate::from_pem_bundle(&pem)
+        .map_err(|e| WeatherError::CaBundle(format!("Invalid CA bundle {path}: {e}")))
+}
+
+/// Builds the blocking HTTP client shared by every request.
+///
+/// Certificates from the bundle named by `MOSM_CA_BUNDLE` are trusted in addition
+/// to the built-in roots, and requests time out as set with [`configure`].
+///
+/// # Returns
+/// A configured `reqwest::blocking::Client`, or `WeatherError::CaBundle` if the bundle
+/// cannot be used.
+#[cfg(feature = "blocking")]
+pub fn client() -> Result<reqwest::blocking::Client, WeatherError> {
+    let mut builder = reqwest::blocking::Client::builder().timeout(retry().timeout);
+    for certificate in extra_certificates()? {
+        builder = builder.add_root_certificate(certificate);
+    }
+    Ok(builder.build()?)
+}
+
+/// Builds the async HTTP client, trusting the same certificates and using the same
+/// timeout as [`client`].
+///
+/// # Returns
+/// A configured `reqwest::Client`, to be used from a tokio runtime.
+pub fn async_client() -> Result<reqwest::Client, WeatherError> {
+    let mut builder = reqwest::Client::builder().timeout(retry().timeout);
+    for certificate in extra_certificates()? {
+        builder = builder.add_root_certificate(certificate);
+    }
+    Ok(builder.build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    #[test]
+    fn classifies_transient_failures() {
+        let status = |status: u16| WeatherError::Status {
+            status,
+            message: None,
+        };
+        assert!(is_transient(&status(503)));
+        assert!(is_transient(&status(429)));
+        assert!(!is_transient(&status(404)));
+        assert!(!is_transient(&status(501)));
+        assert!(!is_transient(&WeatherError::InvalidQuery(
+            "no location provided".to_owned()
+        )));
+    }
+
+    /// Answers one connection per status on a local port, and returns its URL.
+    fn serve(statuses: &'static [u16]) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url: String = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for status in statuses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request: Vec<u8> = Vec::new();
+                let mut buffer: [u8; 1024] = [0; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read: usize = stream.read(&mut buffer).unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                )
+                .unwrap();
+            }
+        });
+        url
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn retries_transient_statuses() {
+        let url: String = serve(&[503, 200]);
+        let response = send(client().unwrap().get(url)).unwrap();
+        assert_eq!(response.status(), 200);
+
+        let url: String = serve(&[404]);
+        let response = send(client().unwrap().get(url)).unwrap();
+        assert_eq!(response.status(), 404);
+    }
+
+    #[test]
+    fn retries_transient_statuses_async() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let url: String = serve(&[502, 429, 200]);
+        let response = runtime
+            .block_on(send_async(async_client().unwrap().get(url)))
+            .unwrap();
+        assert_eq!(response.status(), 200);
+
+        let url: String = serve(&[404]);
+        let response = runtime
+            .block_on(send_async(async_client().unwrap().get(url)))
+            .unwrap();
+        assert_eq!(response.status(), 404);
+    }
+}
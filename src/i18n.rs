@@ -0,0 +1,1096 @@
+use std::sync::OnceLock;
+
+use crate::{Condition, WeatherData};
+
+/// Languages of the report labels besides English, by code.
+pub const LANGUAGES: [&str; 6] = ["de", "fr", "es", "it", "pt", "nl"];
+
+/// Report labels in English, followed by their translation in each of [`LANGUAGES`].
+const LABELS: [(&str, [&str; 6]); 41] = [
+    (
+        "Local Time",
+        [
+            "Ortszeit",
+            "Heure locale",
+            "Hora local",
+            "Ora locale",
+            "Hora local",
+            "Lokale tijd",
+        ],
+    ),
+    (
+        "Coordinates",
+        [
+            "Koordinaten",
+            "Coordonnées",
+            "Coordenadas",
+            "Coordinate",
+            "Coordenadas",
+            "Coördinaten",
+        ],
+    ),
+    (
+        "Elevation",
+        [
+            "Höhe",
+            "Altitude",
+            "Altitud",
+            "Altitudine",
+            "Altitude",
+            "Hoogte",
+        ],
+    ),
+    (
+        "Feels like",
+        [
+            "Gefühlt",
+            "Ressenti",
+            "Sensación",
+            "Percepita",
+            "Sensação",
+            "Gevoel",
+        ],
+    ),
+    (
+        "Humidity",
+        [
+            "Luftfeuchte",
+            "Humidité",
+            "Humedad",
+            "Umidità",
+            "Umidade",
+            "Vochtigheid",
+        ],
+    ),
+    (
+        "Rain",
+        ["Regen", "Pluie", "Lluvia", "Pioggia", "Chuva", "Regen"],
+    ),
+    ("Wind", ["Wind", "Vent", "Viento", "Vento", "Vento", "Wind"]),
+    (
+        "Dew Point",
+        [
+            "Taupunkt",
+            "Point de rosée",
+            "Punto de rocío",
+            "Punto di rugiada",
+            "Ponto de orvalho",
+            "Dauwpunt",
+        ],
+    ),
+    (
+        "Visibility",
+        [
+            "Sicht",
+            "Visibilité",
+            "Visibilidad",
+            "Visibilità",
+            "Visibilidade",
+            "Zicht",
+        ],
+    ),
+    (
+        "Forecast",
+        [
+            "Vorhersage",
+            "Prévisions",
+            "Pronóstico",
+            "Previsioni",
+            "Previsão",
+            "Verwachting",
+        ],
+    ),
+    (
+        "Precip",
+        [
+            "Niederschlag",
+            "Précip.",
+            "Precip.",
+            "Precip.",
+            "Precip.",
+            "Neerslag",
+        ],
+    ),
+    ("UV", ["UV"; 6]),
+    ("Time", ["Zeit", "Heure", "Hora", "Ora", "Hora", "Tijd"]),
+    (
+        "Temp",
+        ["Temp.", "Temp.", "Temp.", "Temp.", "Temp.", "Temp."],
+    ),
+    (
+        "Condition",
+        [
+            "Wetter",
+            "Temps",
+            "Estado",
+            "Condizioni",
+            "Condição",
+            "Weer",
+        ],
+    ),
+    (
+        "Location",
+        ["Ort", "Lieu", "Lugar", "Località", "Local", "Locatie"],
+    ),
+    (
+        "Hourly forecast",
+        [
+            "Stündliche Vorhersage",
+            "Prévisions horaires",
+            "Pronóstico por horas",
+            "Previsioni orarie",
+            "Previsão horária",
+            "Verwachting per uur",
+        ],
+    ),
+    (
+        "not available",
+        [
+            "nicht verfügbar",
+            "non disponible",
+            "no disponible",
+            "non disponibile",
+            "indisponível",
+            "niet beschikbaar",
+        ],
+    ),
+    (
+        "Pressure swing",
+        [
+            "Druckschwankung",
+            "Variation de pression",
+            "Cambio de presión",
+            "Sbalzo di pressione",
+            "Variação de pressão",
+            "Drukschommeling",
+        ],
+    ),
+    (
+        "between",
+        ["zwischen", "entre", "entre", "tra", "entre", "tussen"],
+    ),
+    ("and", ["und", "et", "y", "e", "e", "en"]),
+    (
+        "migraine risk",
+        [
+            "Migränerisiko",
+            "risque de migraine",
+            "riesgo de migraña",
+            "rischio di emicrania",
+            "risco de enxaqueca",
+            "migrainerisico",
+        ],
+    ),
+    (
+        "High",
+        [
+            "Maximum", "Maximum", "Máxima", "Massima", "Máxima", "Maximum",
+        ],
+    ),
+    (
+        "Low",
+        [
+            "Minimum", "Minimum", "Mínima", "Minima", "Mínima", "Minimum",
+        ],
+    ),
+    (
+        "Average",
+        ["Mittel", "Moyenne", "Media", "Media", "Média", "Gemiddeld"],
+    ),
+    (
+        "Precipitation",
+        [
+            "Niederschlag",
+            "Précipitations",
+            "Precipitación",
+            "Precipitazioni",
+            "Precipitação",
+            "Neerslag",
+        ],
+    ),
+    (
+        "Max wind",
+        [
+            "Max. Wind",
+            "Vent max.",
+            "Viento máx.",
+            "Vento max.",
+            "Vento máx.",
+            "Max. wind",
+        ],
+    ),
+    (
+        "Today",
+        ["Heute", "Aujourd'hui", "Hoy", "Oggi", "Hoje", "Vandaag"],
+    ),
+    (
+        "Earthquakes",
+        [
+            "Erdbeben",
+            "Séismes",
+            "Terremotos",
+            "Terremoti",
+            "Sismos",
+            "Aardbevingen",
+        ],
+    ),
+    (
+        "within",
+        [
+            "im Umkreis von",
+            "dans un rayon de",
+            "en un radio de",
+            "entro",
+            "num raio de",
+            "binnen",
+        ],
+    ),
+    (
+        "last 30 days",
+        [
+            "letzte 30 Tage",
+            "30 derniers jours",
+            "últimos 30 días",
+            "ultimi 30 giorni",
+            "últimos 30 dias",
+            "afgelopen 30 dagen",
+        ],
+    ),
+    (
+        "Aurora outlook for tonight",
+        [
+            "Polarlicht-Aussicht für heute Nacht",
+            "Prévision d'aurores pour cette nuit",
+            "Previsión de auroras para esta noche",
+            "Previsione aurore per stanotte",
+            "Previsão de auroras para esta noite",
+            "Poollichtverwachting voor vannacht",
+        ],
+    ),
+    (
+        "Active tropical cyclones",
+        [
+            "Aktive tropische Wirbelstürme",
+            "Cyclones tropicaux actifs",
+            "Ciclones tropicales activos",
+            "Cicloni tropicali attivi",
+            "Ciclones tropicais ativos",
+            "Actieve tropische cyclonen",
+        ],
+    ),
+    (
+        "Drone flight check",
+        [
+            "Drohnenflug-Check",
+            "Vérification du vol de drone",
+            "Comprobación de vuelo de dron",
+            "Verifica del volo del drone",
+            "Verificação de voo de drone",
+            "Dronevluchtcontrole",
+        ],
+    ),
+    (
+        "Jobsite safety",
+        [
+            "Baustellensicherheit",
+            "Sécurité du chantier",
+            "Seguridad en la obra",
+            "Sicurezza in cantiere",
+            "Segurança na obra",
+            "Veiligheid op de bouwplaats",
+        ],
+    ),
+    (
+        "Best tailwind heading",
+        [
+            "Bester Rückenwind in Richtung",
+            "Meilleur vent arrière au cap",
+            "Mejor viento de cola con rumbo",
+            "Miglior vento in coda in direzione",
+            "Melhor vento de cauda no rumo",
+            "Beste rugwind richting",
+        ],
+    ),
+    (
+        "Verdict",
+        [
+            "Urteil",
+            "Verdict",
+            "Veredicto",
+            "Verdetto",
+            "Veredito",
+            "Oordeel",
+        ],
+    ),
+    (
+        "Concerns",
+        [
+            "Bedenken",
+            "Points d'attention",
+            "Riesgos",
+            "Criticità",
+            "Riscos",
+            "Aandachtspunten",
+        ],
+    ),
+    (
+        "Day-part",
+        [
+            "Tagesabschnitt",
+            "Période",
+            "Franja",
+            "Fascia",
+            "Período",
+            "Dagdeel",
+        ],
+    ),
+    (
+        "Along",
+        [
+            "Längs",
+            "Dans l'axe",
+            "Longitudinal",
+            "Lungo",
+            "Longitudinal",
+            "Langs",
+        ],
+    ),
+    (
+        "Across",
+        [
+            "Quer",
+            "De travers",
+            "Transversal",
+            "Trasversale",
+            "Transversal",
+            "Dwars",
+        ],
+    ),
+];
+
+/// Weather API condition codes with their English text and its translation in
+/// each of [`LANGUAGES`], for providers without localized condition texts.
+///
+/// Code 1000 is in [`SUNNY`] and [`CLEAR`], as its text depends on the time of day.
+const CONDITIONS: [(i32, &str, [&str; 6]); 47] = [
+    (
+        1003,
+        "Partly cloudy",
+        [
+            "Leicht bewölkt",
+            "Partiellement nuageux",
+            "Parcialmente nublado",
+            "Parzialmente nuvoloso",
+            "Parcialmente nublado",
+            "Half bewolkt",
+        ],
+    ),
+    (
+        1006,
+        "Cloudy",
+        [
+            "Bewölkt", "Nuageux", "Nublado", "Nuvoloso", "Nublado", "Bewolkt",
+        ],
+    ),
+    (
+        1009,
+        "Overcast",
+        [
+            "Bedeckt",
+            "Couvert",
+            "Cubierto",
+            "Coperto",
+            "Encoberto",
+            "Zwaar bewolkt",
+        ],
+    ),
+    (
+        1030,
+        "Mist",
+        ["Dunst", "Brume", "Neblina", "Foschia", "Névoa", "Nevel"],
+    ),
+    (
+        1063,
+        "Patchy rain possible",
+        [
+            "Stellenweise Regen möglich",
+            "Pluie éparse possible",
+            "Posible lluvia dispersa",
+            "Possibile pioggia sparsa",
+            "Possibilidade de chuva isolada",
+            "Plaatselijk regen mogelijk",
+        ],
+    ),
+    (
+        1066,
+        "Patchy snow possible",
+        [
+            "Stellenweise Schnee möglich",
+            "Neige éparse possible",
+            "Posible nieve dispersa",
+            "Possibile neve sparsa",
+            "Possibilidade de neve isolada",
+            "Plaatselijk sneeuw mogelijk",
+        ],
+    ),
+    (
+        1069,
+        "Patchy sleet possible",
+        [
+            "Stellenweise Schneeregen möglich",
+            "Neige fondue éparse possible",
+            "Posible aguanieve dispersa",
+            "Possibile nevischio sparso",
+            "Possibilidade de água-neve isolada",
+            "Plaatselijk natte sneeuw mogelijk",
+        ],
+    ),
+    (
+        1072,
+        "Patchy freezing drizzle possible",
+        [
+            "Stellenweise gefrierender Nieselregen möglich",
+            "Bruine verglaçante éparse possible",
+            "Posible llovizna helada dispersa",
+            "Possibile pioviggine gelata sparsa",
+            "Possibilidade de garoa congelante isolada",
+            "Plaatselijk ijzel mogelijk",
+        ],
+    ),
+    (
+        1087,
+        "Thundery outbreaks possible",
+        [
+            "Gewitter möglich",
+            "Risque d'orages",
+            "Posibles tormentas",
+            "Possibili temporali",
+            "Possibilidade de trovoadas",
+            "Kans op onweer",
+        ],
+    ),
+    (
+        1114,
+        "Blowing snow",
+        [
+            "Schneetreiben",
+            "Chasse-neige",
+            "Nieve con viento",
+            "Neve e vento",
+            "Neve com vento",
+            "Stuifsneeuw",
+        ],
+    ),
+    (
+        1117,
+        "Blizzard",
+        [
+            "Schneesturm",
+            "Blizzard",
+            "Ventisca",
+            "Bufera di neve",
+            "Nevasca",
+            "Sneeuwstorm",
+        ],
+    ),
+    (
+        1135,
+        "Fog",
+        [
+            "Nebel",
+            "Brouillard",
+            "Niebla",
+            "Nebbia",
+            "Nevoeiro",
+            "Mist",
+        ],
+    ),
+    (
+        1147,
+        "Freezing fog",
+        [
+            "Gefrierender Nebel",
+            "Brouillard givrant",
+            "Niebla helada",
+            "Nebbia gelata",
+            "Nevoeiro gelado",
+            "Aanvriezende mist",
+        ],
+    ),
+    (
+        1150,
+        "Patchy light drizzle",
+        [
+            "Stellenweise leichter Nieselregen",
+            "Bruine légère éparse",
+            "Llovizna ligera dispersa",
+            "Pioviggine leggera sparsa",
+            "Garoa fraca isolada",
+            "Plaatselijk lichte motregen",
+        ],
+    ),
+    (
+        1153,
+        "Light drizzle",
+        [
+            "Leichter Nieselregen",
+            "Bruine légère",
+            "Llovizna ligera",
+            "Pioviggine leggera",
+            "Garoa fraca",
+            "Lichte motregen",
+        ],
+    ),
+    (
+        1168,
+        "Freezing drizzle",
+        [
+            "Gefrierender Nieselregen",
+            "Bruine verglaçante",
+            "Llovizna helada",
+            "Pioviggine gelata",
+            "Garoa congelante",
+            "IJzel",
+        ],
+    ),
+    (
+        1171,
+        "Heavy freezing drizzle",
+        [
+            "Starker gefrierender Nieselregen",
+            "Forte bruine verglaçante",
+            "Llovizna helada intensa",
+            "Forte pioviggine gelata",
+            "Garoa congelante forte",
+            "Zware ijzel",
+        ],
+    ),
+    (
+        1180,
+        "Patchy light rain",
+        [
+            "Stellenweise leichter Regen",
+            "Pluie légère éparse",
+            "Lluvia ligera dispersa",
+            "Pioggia leggera sparsa",
+            "Chuva fraca isolada",
+            "Plaatselijk lichte regen",
+        ],
+    ),
+    (
+        1183,
+        "Light rain",
+        [
+            "Leichter Regen",
+            "Pluie légère",
+            "Lluvia ligera",
+            "Pioggia leggera",
+            "Chuva fraca",
+            "Lichte regen",
+        ],
+    ),
+    (
+        1186,
+        "Moderate rain at times",
+        [
+            "Zeitweise mäßiger Regen",
+            "Pluie modérée par moments",
+            "Lluvia moderada a ratos",
+            "Pioggia moderata a tratti",
+            "Chuva moderada por vezes",
+            "Af en toe matige regen",
+        ],
+    ),
+    (
+        1189,
+        "Moderate rain",
+        [
+            "Mäßiger Regen",
+            "Pluie modérée",
+            "Lluvia moderada",
+            "Pioggia moderata",
+            "Chuva moderada",
+            "Matige regen",
+        ],
+    ),
+    (
+        1192,
+        "Heavy rain at times",
+        [
+            "Zeitweise starker Regen",
+            "Forte pluie par moments",
+            "Lluvia intensa a ratos",
+            "Pioggia forte a tratti",
+            "Chuva forte por vezes",
+            "Af en toe zware regen",
+        ],
+    ),
+    (
+        1195,
+        "Heavy rain",
+        [
+            "Starker Regen",
+            "Forte pluie",
+            "Lluvia intensa",
+            "Pioggia forte",
+            "Chuva forte",
+            "Zware regen",
+        ],
+    ),
+    (
+        1198,
+        "Light freezing rain",
+        [
+            "Leichter gefrierender Regen",
+            "Pluie verglaçante légère",
+            "Lluvia helada ligera",
+            "Pioggia gelata leggera",
+            "Chuva congelante fraca",
+            "Lichte aanvriezende regen",
+        ],
+    ),
+    (
+        1201,
+        "Moderate or heavy freezing rain",
+        [
+            "Mäßiger bis starker gefrierender Regen",
+            "Pluie verglaçante modérée à forte",
+            "Lluvia helada moderada o intensa",
+            "Pioggia gelata moderata o forte",
+            "Chuva congelante moderada a forte",
+            "Matige tot zware aanvriezende regen",
+        ],
+    ),
+    (
+        1204,
+        "Light sleet",
+        [
+            "Leichter Schneeregen",
+            "Neige fondue légère",
+            "Aguanieve ligera",
+            "Nevischio leggero",
+            "Água-neve fraca",
+            "Lichte natte sneeuw",
+        ],
+    ),
+    (
+        1207,
+        "Moderate or heavy sleet",
+        [
+            "Mäßiger bis starker Schneeregen",
+            "Neige fondue modérée à forte",
+            "Aguanieve moderada o intensa",
+            "Nevischio moderato o forte",
+            "Água-neve moderada a forte",
+            "Matige tot zware natte sneeuw",
+        ],
+    ),
+    (
+        1210,
+        "Patchy light snow",
+        [
+            "Stellenweise leichter Schneefall",
+            "Neige légère éparse",
+            "Nieve ligera dispersa",
+            "Neve leggera sparsa",
+            "Neve fraca isolada",
+            "Plaatselijk lichte sneeuw",
+        ],
+    ),
+    (
+        1213,
+        "Light snow",
+        [
+            "Leichter Schneefall",
+            "Neige légère",
+            "Nieve ligera",
+            "Neve leggera",
+            "Neve fraca",
+            "Lichte sneeuw",
+        ],
+    ),
+    (
+        1216,
+        "Patchy moderate snow",
+        [
+            "Stellenweise mäßiger Schneefall",
+            "Neige modérée éparse",
+            "Nieve moderada dispersa",
+            "Neve moderata sparsa",
+            "Neve moderada isolada",
+            "Plaatselijk matige sneeuw",
+        ],
+    ),
+    (
+        1219,
+        "Moderate snow",
+        [
+            "Mäßiger Schneefall",
+            "Neige modérée",
+            "Nieve moderada",
+            "Neve moderata",
+            "Neve moderada",
+            "Matige sneeuw",
+        ],
+    ),
+    (
+        1222,
+        "Patchy heavy snow",
+        [
+            "Stellenweise starker Schneefall",
+            "Forte neige éparse",
+            "Nieve intensa dispersa",
+            "Neve forte sparsa",
+            "Neve forte isolada",
+            "Plaatselijk zware sneeuw",
+        ],
+    ),
+    (
+        1225,
+        "Heavy snow",
+        [
+            "Starker Schneefall",
+            "Forte neige",
+            "Nieve intensa",
+            "Neve forte",
+            "Neve forte",
+            "Zware sneeuw",
+        ],
+    ),
+    (
+        1237,
+        "Ice pellets",
+        [
+            "Eiskörner",
+            "Granules de glace",
+            "Hielo granulado",
+            "Granelli di ghiaccio",
+            "Pelotas de gelo",
+            "IJskorrels",
+        ],
+    ),
+    (
+        1240,
+        "Light rain shower",
+        [
+            "Leichter Regenschauer",
+            "Averse de pluie légère",
+            "Chubasco ligero",
+            "Rovescio leggero",
+            "Aguaceiro fraco",
+            "Lichte regenbui",
+        ],
+    ),
+    (
+        1243,
+        "Moderate or heavy rain shower",
+        [
+            "Mäßiger bis starker Regenschauer",
+            "Averse de pluie modérée à forte",
+            "Chubasco moderado o intenso",
+            "Rovescio moderato o forte",
+            "Aguaceiro moderado a forte",
+            "Matige tot zware regenbui",
+        ],
+    ),
+    (
+        1246,
+        "Torrential rain shower",
+        [
+            "Sintflutartiger Regenschauer",
+            "Averse torrentielle",
+            "Chubasco torrencial",
+            "Rovescio torrenziale",
+            "Aguaceiro torrencial",
+            "Stortbui",
+        ],
+    ),
+    (
+        1249,
+        "Light sleet showers",
+        [
+            "Leichte Schneeregenschauer",
+            "Averses de neige fondue légères",
+            "Chubascos ligeros de aguanieve",
+            "Rovesci leggeri di nevischio",
+            "Aguaceiros fracos de água-neve",
+            "Lichte buien met natte sneeuw",
+        ],
+    ),
+    (
+        1252,
+        "Moderate or heavy sleet showers",
+        [
+            "Mäßige bis starke Schneeregenschauer",
+            "Averses de neige fondue modérées à fortes",
+            "Chubascos moderados o intensos de aguanieve",
+            "Rovesci moderati o forti di nevischio",
+            "Aguaceiros moderados a fortes de água-neve",
+            "Matige tot zware buien met natte sneeuw",
+        ],
+    ),
+    (
+        1255,
+        "Light snow showers",
+        [
+            "Leichte Schneeschauer",
+            "Averses de neige légères",
+            "Chubascos ligeros de nieve",
+            "Rovesci leggeri di neve",
+            "Aguaceiros fracos de neve",
+            "Lichte sneeuwbuien",
+        ],
+    ),
+    (
+        1258,
+        "Moderate or heavy snow showers",
+        [
+            "Mäßige bis starke Schneeschauer",
+            "Averses de neige modérées à fortes",
+            "Chubascos moderados o intensos de nieve",
+            "Rovesci moderati o forti di neve",
+            "Aguaceiros moderados a fortes de neve",
+            "Matige tot zware sneeuwbuien",
+        ],
+    ),
+    (
+        1261,
+        "Light showers of ice pellets",
+        [
+            "Leichte Eiskörnerschauer",
+            "Averses de granules de glace légères",
+            "Chubascos ligeros de hielo granulado",
+            "Rovesci leggeri di granelli di ghiaccio",
+            "Aguaceiros fracos de pelotas de gelo",
+            "Lichte buien met ijskorrels",
+        ],
+    ),
+    (
+        1264,
+        "Moderate or heavy showers of ice pellets",
+        [
+            "Mäßige bis starke Eiskörnerschauer",
+            "Averses de granules de glace modérées à fortes",
+            "Chubascos moderados o intensos de hielo granulado",
+            "Rovesci moderati o forti di granelli di ghiaccio",
+            "Aguaceiros moderados a fortes de pelotas de gelo",
+            "Matige tot zware buien met ijskorrels",
+        ],
+    ),
+    (
+        1273,
+        "Patchy light rain with thunder",
+        [
+            "Stellenweise leichter Regen mit Gewitter",
+            "Pluie légère éparse avec orage",
+            "Lluvia ligera dispersa con tormenta",
+            "Pioggia leggera sparsa con temporale",
+            "Chuva fraca isolada com trovoada",
+            "Plaatselijk lichte regen met onweer",
+        ],
+    ),
+    (
+        1276,
+        "Moderate or heavy rain with thunder",
+        [
+            "Mäßiger bis starker Regen mit Gewitter",
+            "Pluie modérée à forte avec orage",
+            "Lluvia moderada o intensa con tormenta",
+            "Pioggia moderata o forte con temporale",
+            "Chuva moderada a forte com trovoada",
+            "Matige tot zware regen met onweer",
+        ],
+    ),
+    (
+        1279,
+        "Patchy light snow with thunder",
+        [
+            "Stellenweise leichter Schneefall mit Gewitter",
+            "Neige légère éparse avec orage",
+            "Nieve ligera dispersa con tormenta",
+            "Neve leggera sparsa con temporale",
+            "Neve fraca isolada com trovoada",
+            "Plaatselijk lichte sneeuw met onweer",
+        ],
+    ),
+    (
+        1282,
+        "Moderate or heavy snow with thunder",
+        [
+            "Mäßiger bis starker Schneefall mit Gewitter",
+            "Neige modérée à forte avec orage",
+            "Nieve moderada o intensa con tormenta",
+            "Neve moderata o forte con temporale",
+            "Neve moderada a forte com trovoada",
+            "Matige tot zware sneeuw met onweer",
+        ],
+    ),
+];
+
+/// Code 1000 by day.
+const SUNNY: [&str; 6] = [
+    "Sonnig",
+    "Ensoleillé",
+    "Soleado",
+    "Soleggiato",
+    "Ensolarado",
+    "Zonnig",
+];
+/// Code 1000 by night.
+const CLEAR: [&str; 6] = [
+    "Klar",
+    "Dégagé",
+    "Despejado",
+    "Sereno",
+    "Céu limpo",
+    "Helder",
+];
+
+/// Index in [`LANGUAGES`] of the labels, `None` for English, see [`configure`].
+static LANGUAGE: OnceLock<Option<usize>> = OnceLock::new();
+
+/// Returns the index in [`LANGUAGES`] of a language code such as `de` or `pt_BR`.
+fn language_index(lang: &str) -> Option<usize> {
+    let code: &str = lang.split(['_', '-']).next().unwrap_or(lang);
+    LANGUAGES
+        .iter()
+        .position(|language| language.eq_ignore_ascii_case(code))
+}
+
+/// Sets the language of the report labels for the process.
+///
+/// Languages outside [`LANGUAGES`] keep the English labels. Must be called before
+/// the first label is rendered; later calls are ignored.
+///
+/// # Returns
+/// `false` if the language was already fixed.
+pub fn configure(lang: &str) -> bool {
+    LANGUAGE.set(language_index(lang)).is_ok()
+}
+
+/// Returns a report label in the configured language.
+///
+/// # Arguments
+/// * `english` - Label in English, one of the table's.
+pub fn label(english: &'static str) -> &'static str {
+    match LANGUAGE.get_or_init(|| None) {
+        Some(index) => translate(*index, english),
+        None => english,
+    }
+}
+
+/// Returns a report label in a language, or in English if it has no translation.
+pub fn label_in(lang: &str, english: &'static str) -> &'static str {
+    match language_index(lang) {
+        Some(index) => translate(index, english),
+        None => english,
+    }
+}
+
+/// Looks up the translation of a label in the language at an index of [`LANGUAGES`].
+fn translate(index: usize, english: &'static str) -> &'static str {
+    LABELS
+        .iter()
+        .find(|(label, _)| *label == english)
+        .map_or(english, |(_, translations)| translations[index])
+}
+
+/// Returns the condition text of a code in a language, or `None` if the language
+/// or code is not in the table.
+///
+/// # Arguments
+/// * `lang` - Language code, such as `de`.
+/// * `code` - Weather API condition code.
+/// * `is_day` - Whether the condition is by day, for code 1000 (sunny or clear).
+pub fn condition_in(lang: &str, code: i32, is_day: bool) -> Option<&'static str> {
+    let index: usize = language_index(lang)?;
+    match code {
+        1000 if is_day => Some(SUNNY[index]),
+        1000 => Some(CLEAR[index]),
+        _ => CONDITIONS
+            .iter()
+            .find(|(known, _, _)| *known == code)
+            .map(|(_, _, translations)| translations[index]),
+    }
+}
+
+/// Returns `true` if a condition text is the English one of its code, so the
+/// provider did not localize it.
+///
+/// The Weather API now says "nearby" where older texts said "possible".
+fn is_english(code: i32, text: &str) -> bool {
+    let text: String = text.trim().to_lowercase().replace(" nearby", " possible");
+    match code {
+        1000 => text == "sunny" || text == "clear",
+        _ => CONDITIONS
+            .iter()
+            .any(|(known, english, _)| *known == code && english.to_lowercase() == text),
+    }
+}
+
+/// Translates the English condition texts of a forecast with the local table,
+/// for providers that ignore the language, such as Open-Meteo.
+///
+/// Texts the provider already localized, and languages outside [`LANGUAGES`], are
+/// left as they are.
+pub fn localize_conditions(weather: &mut WeatherData, lang: &str) {
+    let translate = |condition: &mut Condition, is_day: bool| {
+        if is_english(condition.code, &condition.text)
+            && let Some(text) = condition_in(lang, condition.code, is_day)
+        {
+            condition.text = text.to_owned();
+        }
+    };
+    translate(&mut weather.current.condition, weather.current.is_day == 1);
+    for forecast_day in &mut weather.forecast.forecastday {
+        translate(&mut forecast_day.day.condition, true);
+        for hour in &mut forecast_day.hour {
+            translate(&mut hour.condition, hour.is_day == 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translates_labels() {
+        assert_eq!(label_in("de", "Feels like"), "Gefühlt");
+        assert_eq!(label_in("pt_BR", "Humidity"), "Umidade");
+        assert_eq!(label_in("FR", "Dew Point"), "Point de rosée");
+        assert_eq!(label_in("zh", "Wind"), "Wind");
+        assert_eq!(label_in("fr", "Wind"), "Vent");
+        assert_eq!(label_in("nl", "Hourly forecast"), "Verwachting per uur");
+        assert_eq!(label_in("de", "Gusts"), "Gusts");
+        assert_eq!(label_in("es", "Today"), "Hoy");
+        for (i, (english, _)) in LABELS.iter().enumerate() {
+            assert!(
+                LABELS[..i].iter().all(|(other, _)| other != english),
+                "{english} listed twice"
+            );
+        }
+    }
+
+    #[test]
+    fn translates_english_conditions() {
+        assert_eq!(condition_in("de", 1003, true), Some("Leicht bewölkt"));
+        assert_eq!(condition_in("fr", 1000, false), Some("Dégagé"));
+        assert_eq!(condition_in("zh", 1003, true), None);
+
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        weather.forecast.forecastday[0].day.condition.text = "Sonnig".to_owned();
+        localize_conditions(&mut weather, "de");
+        assert_eq!(weather.current.condition.text, "Sonnig");
+        // "Patchy rain nearby" is today's wording of "Patchy rain possible".
+        assert_eq!(
+            weather.forecast.forecastday[1].day.condition.text,
+            "Stellenweise Regen möglich"
+        );
+        assert_eq!(
+            weather.forecast.forecastday[2].day.condition.text,
+            "Leichter Regen"
+        );
+    }
+}
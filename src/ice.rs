@@ -0,0 +1,133 @@
+use crate::{Hour, WeatherData};
+
+/// Road temperature (°C) at or below which wet surfaces can freeze.
+pub const BLACK_ICE_TEMP_C: f64 = 1.0;
+/// Hours before the hour in question checked for rain, sleet or snow.
+pub const WET_LOOKBACK_HOURS: i64 = 12;
+/// Precipitation (mm) over the lookback that leaves the road wet.
+pub const WET_PRECIP_MM: f64 = 0.1;
+/// Relative humidity (%) at which fog and hoar frost wet the road without precipitation.
+pub const WET_HUMIDITY: i32 = 90;
+/// Average overnight cloud cover (%) below which the road cools radiatively.
+pub const CLEAR_SKY_CLOUD: i32 = 30;
+/// Local hours (`HH`) counted as morning in the daily summary.
+pub const MORNING_HOURS: std::ops::RangeInclusive<u32> = 5..=9;
+
+/// Black ice risk level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IceRisk {
+    /// Freezing temperatures on a wet road.
+    Moderate,
+    /// Freezing temperatures on a wet road after a clear, radiatively cooling night.
+    High,
+}
+
+impl std::fmt::Display for IceRisk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IceRisk::Moderate => write!(f, "moderate"),
+            IceRisk::High => write!(f, "high"),
+        }
+    }
+}
+
+/// Assesses the black ice risk at a forecast hour.
+///
+/// The road must be near or below freezing and wet, either from precipitation in the
+/// preceding hours or from fog. A mostly clear night before the hour raises the risk,
+/// as road surfaces then cool below the air temperature.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `hour` - Forecast hour to assess.
+///
+/// # Returns
+/// The `IceRisk`, or `None` if black ice is unlikely.
+pub fn black_ice_risk(weather: &WeatherData, hour: &Hour) -> Option<IceRisk> {
+    if hour.temp_c > BLACK_ICE_TEMP_C {
+        return None;
+    }
+    let since: i64 = hour.time_epoch - WET_LOOKBACK_HOURS * 3600;
+    let preceding: Vec<&Hour> = weather
+        .hours()
+        .filter(|other| other.time_epoch >= since && other.time_epoch < hour.time_epoch)
+        .collect();
+    let precip_mm: f64 =
+        hour.precip_mm + preceding.iter().map(|other| other.precip_mm).sum::<f64>();
+    if precip_mm < WET_PRECIP_MM && hour.humidity < WET_HUMIDITY {
+        return None;
+    }
+
+    // Overnight hours from midnight up to the hour in question.
+    let night: Vec<&&Hour> = preceding
+        .iter()
+        .filter(|other| other.time[..10] == hour.time[..10])
+        .collect();
+    let clear_night: bool = !night.is_empty()
+        && night.iter().map(|other| other.cloud).sum::<i32>() / (night.len() as i32)
+            < CLEAR_SKY_CLOUD;
+    Some(if clear_night {
+        IceRisk::High
+    } else {
+        IceRisk::Moderate
+    })
+}
+
+/// Returns the highest black ice risk of each forecast morning.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+///
+/// # Returns
+/// `(date, risk)` for every forecast day with a morning risk.
+pub fn morning_risks(weather: &WeatherData) -> Vec<(&str, IceRisk)> {
+    weather
+        .forecast
+        .forecastday
+        .iter()
+        .filter_map(|forecast_day| {
+            forecast_day
+                .hour
+                .iter()
+                .filter(|hour| {
+                    hour.time[11..13]
+                        .parse::<u32>()
+                        .is_ok_and(|h| MORNING_HOURS.contains(&h))
+                })
+                .filter_map(|hour| black_ice_risk(weather, hour))
+                .max()
+                .map(|risk| (forecast_day.date.as_str(), risk))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_freezing_wet_mornings() {
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        assert!(morning_risks(&weather).is_empty());
+
+        // Day 2: rain in the evening, then a clear night freezing by 06:00.
+        weather.forecast.forecastday[0].hour[22].precip_mm = 0.5;
+        for hour in &mut weather.forecast.forecastday[1].hour[..8] {
+            hour.temp_c = -1.0;
+        }
+        // Day 3: freezing fog under cloud.
+        for hour in &mut weather.forecast.forecastday[2].hour[..8] {
+            hour.temp_c = 0.5;
+            hour.humidity = 95;
+            hour.cloud = 90;
+        }
+        assert_eq!(
+            morning_risks(&weather),
+            [
+                ("2025-08-02", IceRisk::High),
+                ("2025-08-03", IceRisk::Moderate),
+            ]
+        );
+    }
+}
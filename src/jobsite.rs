@@ -0,0 +1,277 @@
+use serde::Serialize;
+
+use crate::event::{THUNDER_CODES, Verdict};
+use crate::{Hour, WeatherData};
+
+/// Settings of a `[trade.NAME]` table in the config file.
+pub const THRESHOLD_KEYS: [&str; 4] = ["max_heat_index", "max_wind", "max_gust", "max_precip"];
+
+/// Parts of the working day, with their first and last hour.
+pub const DAY_PARTS: [(&str, u32, u32); 3] = [
+    ("Morning", 6, 11),
+    ("Afternoon", 12, 17),
+    ("Evening", 18, 21),
+];
+
+/// Working limits of a trade.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Thresholds {
+    /// Highest heat index (°C); 5 °C below calls for caution.
+    pub max_heat_index_c: f64,
+    /// Highest sustained wind (kph), e.g. a crane's lifting limit.
+    pub max_wind_kph: f64,
+    /// Highest gust (kph).
+    pub max_gust_kph: f64,
+    /// Highest precipitation (mm per hour).
+    pub max_precip_mm: f64,
+}
+
+impl Default for Thresholds {
+    /// Limits for general outdoor work: the heat index "extreme caution" band of
+    /// the US National Weather Service and a near-gale wind.
+    fn default() -> Self {
+        Thresholds {
+            max_heat_index_c: 39.0,
+            max_wind_kph: 50.0,
+            max_gust_kph: 62.0,
+            max_precip_mm: 4.0,
+        }
+    }
+}
+
+impl Thresholds {
+    /// Returns the built-in limits of a trade: `general`, `crane` or `roofing`.
+    pub fn preset(trade: &str) -> Option<Thresholds> {
+        match trade {
+            "general" => Some(Thresholds::default()),
+            "crane" => Some(Thresholds {
+                max_wind_kph: 32.0,
+                max_gust_kph: 45.0,
+                ..Thresholds::default()
+            }),
+            "roofing" => Some(Thresholds {
+                max_heat_index_c: 35.0,
+                max_wind_kph: 40.0,
+                max_gust_kph: 50.0,
+                max_precip_mm: 0.0,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Sets one of [`THRESHOLD_KEYS`].
+    ///
+    /// # Returns
+    /// The reason if the key is unknown or the value negative.
+    pub fn set(&mut self, key: &str, value: f64) -> Result<(), String> {
+        if value < 0.0 && key != "max_heat_index" {
+            return Err(format!("{key} must not be negative"));
+        }
+        match key {
+            "max_heat_index" => self.max_heat_index_c = value,
+            "max_wind" => self.max_wind_kph = value,
+            "max_gust" => self.max_gust_kph = value,
+            "max_precip" => self.max_precip_mm = value,
+            _ => {
+                return Err(format!(
+                    "unknown trade setting {key:?}, expected one of {}",
+                    THRESHOLD_KEYS.join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Hazard checked against a threshold.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Hazard {
+    Heat,
+    Wind,
+    Gust,
+    Precip,
+    Lightning,
+}
+
+/// A hazard at or beyond the caution threshold of its limit.
+#[derive(Serialize, Debug)]
+pub struct Concern {
+    pub hazard: Hazard,
+    /// Worst forecast value of the day-part, in the unit of the limit.
+    pub value: f64,
+    pub limit: f64,
+    /// `NoGo` beyond the limit, `Caution` close to it.
+    pub verdict: Verdict,
+}
+
+/// Safety summary of a part of a working day.
+#[derive(Serialize, Debug)]
+pub struct DayPart {
+    /// Local date (`YYYY-MM-DD`).
+    pub date: String,
+    /// `Morning`, `Afternoon` or `Evening`, see [`DAY_PARTS`].
+    pub name: &'static str,
+    /// Worst verdict of the concerns, `Go` without any.
+    pub verdict: Verdict,
+    pub concerns: Vec<Concern>,
+    /// Hazards the forecast has no value for, which call for caution.
+    pub unknown: Vec<Hazard>,
+}
+
+/// Summarizes the hazards of each remaining day-part of the forecast.
+///
+/// A value beyond a limit stops work; one within 20% of it (5 °C for the heat
+/// index) calls for caution, as does a hazard without any forecast value.
+/// Thunderstorms always stop work.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `thresholds` - Limits of the trade.
+pub fn day_parts(weather: &WeatherData, thresholds: &Thresholds) -> Vec<DayPart> {
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    let mut parts: Vec<DayPart> = Vec::new();
+    for day in &weather.forecast.forecastday {
+        for (name, first, last) in DAY_PARTS {
+            let hours: Vec<&Hour> = day
+                .hour
+                .iter()
+                .filter(|hour| hour.time_epoch > from)
+                .filter(|hour| {
+                    let clock: u32 = hour.time[11..13].parse().unwrap_or(0);
+                    (first..=last).contains(&clock)
+                })
+                .collect();
+            if hours.is_empty() {
+                continue;
+            }
+            let (concerns, unknown): (Vec<Concern>, Vec<Hazard>) = concerns(&hours, thresholds);
+            parts.push(DayPart {
+                date: day.date.clone(),
+                name,
+                verdict: concerns
+                    .iter()
+                    .map(|concern| concern.verdict)
+                    .chain((!unknown.is_empty()).then_some(Verdict::Caution))
+                    .max()
+                    .unwrap_or(Verdict::Go),
+                concerns,
+                unknown,
+            });
+        }
+    }
+    parts
+}
+
+/// Checks the worst hour of each hazard against the thresholds.
+///
+/// # Returns
+/// The concerns, and the hazards no hour has a value for.
+fn concerns(hours: &[&Hour], thresholds: &Thresholds) -> (Vec<Concern>, Vec<Hazard>) {
+    let max = |value: fn(&Hour) -> Option<f64>| -> Option<f64> {
+        hours.iter().filter_map(|hour| value(hour)).reduce(f64::max)
+    };
+    let checks: [(Hazard, Option<f64>, f64, f64); 4] = [
+        (
+            Hazard::Heat,
+            max(|hour| hour.heatindex_c),
+            thresholds.max_heat_index_c,
+            thresholds.max_heat_index_c - 5.0,
+        ),
+        (
+            Hazard::Wind,
+            max(|hour| Some(hour.wind_kph)),
+            thresholds.max_wind_kph,
+            thresholds.max_wind_kph * 0.8,
+        ),
+        (
+            Hazard::Gust,
+            max(|hour| hour.gust_kph),
+            thresholds.max_gust_kph,
+            thresholds.max_gust_kph * 0.8,
+        ),
+        (
+            Hazard::Precip,
+            max(|hour| Some(hour.precip_mm)),
+            thresholds.max_precip_mm,
+            (thresholds.max_precip_mm * 0.8).max(f64::MIN_POSITIVE),
+        ),
+    ];
+    let mut unknown: Vec<Hazard> = Vec::new();
+    let mut concerns: Vec<Concern> = checks
+        .into_iter()
+        .filter_map(|(hazard, value, limit, caution_from)| {
+            let Some(value) = value else {
+                unknown.push(hazard);
+                return None;
+            };
+            let verdict: Verdict = if value > limit {
+                Verdict::NoGo
+            } else if value >= caution_from {
+                Verdict::Caution
+            } else {
+                return None;
+            };
+            Some(Concern {
+                hazard,
+                value,
+                limit,
+                verdict,
+            })
+        })
+        .collect();
+    if hours
+        .iter()
+        .any(|hour| THUNDER_CODES.contains(&hour.condition.code))
+    {
+        concerns.push(Concern {
+            hazard: Hazard::Lightning,
+            value: 1.0,
+            limit: 0.0,
+            verdict: Verdict::NoGo,
+        });
+    }
+    (concerns, unknown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn summarizes_day_parts() {
+        let weather: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let parts: Vec<DayPart> = day_parts(&weather, &Thresholds::default());
+        // The forecast starts at 14:00: today's morning is over.
+        assert_eq!(parts[0].name, "Afternoon");
+        assert_eq!(parts.len(), 2 + 3 * 2);
+        assert!(parts.iter().all(|part| part.verdict == Verdict::Go));
+
+        let mut strict: Thresholds = Thresholds::preset("crane").unwrap();
+        strict.set("max_gust", 10.0).unwrap();
+        strict.set("max_heat_index", 20.0).unwrap();
+        assert!(strict.set("max_wind", -1.0).is_err());
+        assert!(strict.set("max_snow", 1.0).is_err());
+        let parts: Vec<DayPart> = day_parts(&weather, &strict);
+        assert_eq!(parts[0].verdict, Verdict::NoGo);
+        let hazards: Vec<Hazard> = parts[0]
+            .concerns
+            .iter()
+            .map(|concern| concern.hazard)
+            .collect();
+        assert!(hazards.contains(&Hazard::Gust) && hazards.contains(&Hazard::Heat));
+        assert!(Thresholds::preset("diving").is_none());
+
+        let mut gustless: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        for forecast_day in &mut gustless.forecast.forecastday {
+            for hour in &mut forecast_day.hour {
+                hour.gust_kph = None;
+            }
+        }
+        let parts: Vec<DayPart> = day_parts(&gustless, &Thresholds::default());
+        assert_eq!(parts[0].verdict, Verdict::Caution);
+        assert_eq!(parts[0].unknown, [Hazard::Gust]);
+    }
+}
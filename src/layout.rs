@@ -0,0 +1,156 @@
+/// Characters rendered with zero width (combining marks, joiners, variation selectors).
+const ZERO_WIDTH: &[(u32, u32)] = &[
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x200B, 0x200F),
+    (0x20D0, 0x20FF),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0x1F3FB, 0x1F3FF),
+    (0xE0100, 0xE01EF),
+];
+
+/// Characters rendered two columns wide (East Asian wide/fullwidth and emoji).
+const DOUBLE_WIDTH: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x303E),
+    (0x3041, 0x33FF),
+    (0x3400, 0x4DBF),
+    (0x4E00, 0x9FFF),
+    (0xA000, 0xA4CF),
+    (0xA960, 0xA97F),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE6F),
+    (0xFF00, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F200, 0x1F251),
+    (0x1F300, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F900, 0x1F9FF),
+    (0x1FA70, 0x1FAFF),
+    (0x20000, 0x3FFFD),
+];
+
+fn in_ranges(c: char, ranges: &[(u32, u32)]) -> bool {
+    let c = c as u32;
+    ranges
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns the number of terminal columns a character occupies.
+pub fn char_width(c: char) -> usize {
+    if c.is_control() || in_ranges(c, ZERO_WIDTH) {
+        0
+    } else if in_ranges(c, DOUBLE_WIDTH) {
+        2
+    } else {
+        1
+    }
+}
+
+/// Returns the number of terminal columns a string occupies.
+pub fn display_width(text: &str) -> usize {
+    text.chars().map(char_width).sum()
+}
+
+/// Pads a string with spaces on the right up to `width` columns.
+pub fn pad_right(text: &str, width: usize) -> String {
+    let padding: usize = width.saturating_sub(display_width(text));
+    format!("{text}{}", " ".repeat(padding))
+}
+
+/// Draws values as a row of block characters, one column each.
+///
+/// # Arguments
+/// * `values` - Values from 0 to `max`; values outside are clamped.
+/// * `max` - Value drawn as a full block.
+pub fn sparkline(values: impl IntoIterator<Item = f64>, max: f64) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    values
+        .into_iter()
+        .map(|value| {
+            let level: f64 = (value / max).clamp(0.0, 1.0) * (BLOCKS.len() - 1) as f64;
+            BLOCKS[level.round() as usize]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_wide_and_zero_width_characters() {
+        assert_eq!(display_width("London"), 6);
+        assert_eq!(display_width("東京"), 4);
+        assert_eq!(display_width("서울"), 4);
+        assert_eq!(display_width("⛔ NO-GO"), 8);
+        assert_eq!(display_width("e\u{301}"), 1);
+        assert_eq!(display_width("☀\u{FE0F}"), 1);
+    }
+
+    #[test]
+    fn pads_by_display_width() {
+        assert_eq!(pad_right("東京", 6), "東京  ");
+        assert_eq!(pad_right("Tokyo", 6), "Tokyo ");
+        assert_eq!(pad_right("Reykjavík", 4), "Reykjavík");
+    }
+
+    #[test]
+    fn draws_sparklines() {
+        assert_eq!(sparkline([0.0, 15.0, 50.0, 100.0, 120.0], 100.0), "▁▂▅██");
+        assert_eq!(display_width(&sparkline([0.0; 24], 100.0)), 24);
+    }
+}
@@ -0,0 +1,69 @@
+//! Weather data from weatherapi.com or Open-Meteo and the renderers behind the `mosm-rs` binary.
+//!
+//! ```no_run
+//! use mosm_rs::{WeatherClient, WeatherData};
+//!
+//! let client = WeatherClient::new("your-weatherapi-key");
+//! let weather: WeatherData = client.fetch_forecast("London", 3)?;
+//! println!("{}: {}°C", weather.location, weather.current.temp_c);
+//! # Ok::<(), mosm_rs::WeatherError>(())
+//! ```
+
+pub mod air;
+pub mod applet;
+pub mod art;
+pub mod aurora;
+pub mod auxiliary;
+pub mod cache;
+pub mod chart;
+pub mod client;
+pub mod color;
+pub mod commute;
+pub mod config;
+pub mod credentials;
+pub mod drift;
+pub mod drone;
+pub mod enrich;
+pub mod error;
+pub mod event;
+pub mod fasting;
+pub mod favorites;
+pub mod frost;
+pub mod geo;
+pub mod health;
+pub mod heatmap;
+pub mod hourly;
+pub mod http;
+pub mod i18n;
+pub mod ice;
+pub mod jobsite;
+pub mod layout;
+pub mod model;
+pub mod open_meteo;
+pub mod pets;
+pub mod pressure;
+pub mod provider;
+pub mod quakes;
+pub mod render;
+pub mod schedule;
+pub mod schema;
+pub mod session;
+pub mod share;
+pub mod snow;
+pub mod storms;
+pub mod sun;
+pub mod tailwind;
+pub mod time;
+pub mod units;
+pub mod ventilation;
+pub mod windows;
+
+pub use client::{SearchResult, WeatherClient};
+pub use error::WeatherError;
+pub use model::{
+    AirQuality, Alert, Alerts, Astro, Condition, Current, Day, Forecast, ForecastDay, FutureData,
+    FutureDay, FutureForecast, FutureSummary, HistoryData, Hour, Location, WeatherData,
+};
+pub use open_meteo::OpenMeteoClient;
+#[cfg(feature = "blocking")]
+pub use provider::WeatherProvider;
@@ -1,239 +1,334 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::io::{stdin, stdout, Write};
-
-/// Air quality data for a location, including pollutant levels and indices.
-#[derive(Serialize, Deserialize, Debug)]
-struct AirQuality {
-    co: f64,
-    no2: f64,
-    o3: f64,
-    so2: f64,
-    pm2_5: f64,
-    pm10: f64,
-    /// US EPA Air Quality Index (1-6).
-    #[serde(rename = "us-epa-index")]
-    us_epa_index: i32,
-    /// UK DEFRA Air Quality Index.
-    #[serde(rename = "gb-defra-index")]
-    gb_defra_index: i32,
-}
-
-/// Weather condition details.
-#[derive(Serialize, Deserialize, Debug)]
-struct Condition {
-    text: String,
-    icon: String,
-    code: i32,
-}
-
-/// Current weather data for a location.
-#[derive(Serialize, Deserialize, Debug)]
-struct Current {
-    last_updated_epoch: i32,
-    last_updated: String,
-    temp_c: f64,
-    temp_f: f64,
-    is_day: i32,
-    condition: Condition,
-    wind_mph: f64,
-    wind_kph: f64,
-    wind_degree: i32,
-    wind_dir: String,
-    pressure_mb: f64,
-    pressure_in: f64,
-    precip_mm: f64,
-    precip_in: f64,
-    humidity: i32,
-    cloud: i32,
-    feelslike_c: f64,
-    feelslike_f: f64,
-    windchill_c: f64,
-    windchill_f: f64,
-    heatindex_c: f64,
-    heatindex_f: f64,
-    dewpoint_c: f64,
-    dewpoint_f: f64,
-    vis_km: f64,
-    vis_miles: f64,
-    uv: f64,
-    gust_mph: f64,
-    gust_kph: f64,
-    air_quality: AirQuality,
-    short_rad: f64,
-    diff_rad: f64,
-    dni: f64,
-    gti: f64,
-}
-
-/// Location data for weather information.
-#[derive(Serialize, Deserialize, Debug)]
-struct Location {
-    name: String,
-    region: String,
-    country: String,
-    lat: f64,
-    lon: f64,
-    tz_id: String,
-    localtime_epoch: i32,
-    localtime: String,
-}
-
-/// Daily weather forecast data.
-#[derive(Serialize, Deserialize, Debug)]
-struct Day {
-    maxtemp_c: f64,
-    maxtemp_f: f64,
-    mintemp_c: f64,
-    mintemp_f: f64,
-    avgtemp_c: f64,
-    avgtemp_f: f64,
-    maxwind_mph: f64,
-    maxwind_kph: f64,
-    totalprecip_mm: f64,
-    totalprecip_in: f64,
-    totalsnow_cm: f64,
-    avgvis_km: f64,
-    avgvis_miles: f64,
-    avghumidity: u32,
-    daily_will_it_rain: i32,
-    daily_chance_of_rain: i32,
-    daily_will_it_snow: i32,
-    daily_chance_of_snow: i32,
-    condition: Condition,
-    uv: f64,
-    air_quality: AirQuality,
-}
-
-/// Astronomical data for a specific day.
-#[derive(Serialize, Deserialize, Debug)]
-struct Astro {
-    sunrise: String,
-    sunset: String,
-    moonrise: String,
-    moonset: String,
-    moon_phase: String,
-    moon_illumination: u32,
-    is_moon_up: i32,
-    is_sun_up: i32,
-}
-
-/// Hourly weather forecast data.
-#[derive(Serialize, Deserialize, Debug)]
-struct Hour {
-    time_epoch: i64,
-    time: String,
-    temp_c: f64,
-    temp_f: f64,
-    is_day: i32,
-    condition: Condition,
-    wind_mph: f64,
-    wind_kph: f64,
-    wind_degree: i32,
-    wind_dir: String,
-    pressure_mb: f64,
-    pressure_in: f64,
-    precip_mm: f64,
-    precip_in: f64,
-    snow_cm: f64,
-    humidity: i32,
-    cloud: i32,
-    feelslike_c: f64,
-    feelslike_f: f64,
-    windchill_c: f64,
-    windchill_f: f64,
-    heatindex_c: f64,
-    heatindex_f: f64,
-    dewpoint_c: f64,
-    dewpoint_f: f64,
-    will_it_rain: i32,
-    chance_of_rain: i32,
-    will_it_snow: i32,
-    chance_of_snow: i32,
-    vis_km: f64,
-    vis_miles: f64,
-    gust_kph: f64,
-    gust_mph: f64,
-    uv: f64,
-    air_quality: AirQuality,
-    short_rad: f64,
-    diff_rad: f64,
-    dni: f64,
-    gti: f64,
-}
-
-/// Single day's forecast data.
-#[derive(Serialize, Deserialize, Debug)]
-struct ForecastDay {
-    /// Date of the forecast (YYYY-MM-DD).
-    date: String,
-    /// UNIX timestamp of the date.
-    date_epoch: i64,
-    /// Daily weather summary.
-    day: Day,
-    /// Astronomical data for the day.
-    astro: Astro,
-    /// Hourly forecast data.
-    hour: Vec<Hour>,
-}
-
-/// Weather forecast data for multiple days.
-#[derive(Serialize, Deserialize, Debug)]
-struct Forecast {
-    /// List of daily forecasts.
-    forecastday: Vec<ForecastDay>,
-}
-
-/// Weather data combining location and current conditions.
-#[derive(Serialize, Deserialize, Debug)]
-struct WeatherData {
-    /// Location details.
-    location: Location,
-    /// Current weather conditions.
-    current: Current,
-    /// Weather forecast data.
-    forecast: Forecast,
-}
-
-/// Returns a mapping of wind directions to Unicode arrows.
-fn get_wind_arrows() -> HashMap<&'static str, &'static str> {
-    HashMap::from([
-        ("N", "⬆"),
-        ("NNE", "↗"),
-        ("NE", "↗"),
-        ("ENE", "➡"),
-        ("E", "➡"),
-        ("ESE", "↘"),
-        ("SE", "↘"),
-        ("SSE", "⬇"),
-        ("S", "⬇"),
-        ("SSW", "↙"),
-        ("SW", "↙"),
-        ("WSW", "⬅"),
-        ("W", "⬅"),
-        ("WNW", "↖"),
-        ("NW", "↖"),
-        ("NNW", "⬆"),
-    ])
-}
-
-/// Returns a mapping of US EPA Air Quality Index values to descriptions.
-fn get_us_epa_index() -> HashMap<u8, &'static str> {
-    HashMap::from([
-        (1, "Good"),
-        (2, "Moderate"),
-        (3, "Unhealthy for sensitive group"),
-        (4, "Unhealthy"),
-        (5, "Very Unhealthy"),
-        (6, "Hazardous"),
-    ])
+mod cli;
+
+use mosm_rs::cache::{self, Cache};
+use mosm_rs::client::{self, AUTO_IP_QUERY, FUTURE_DAYS_AHEAD};
+use mosm_rs::config::{self, Config};
+use mosm_rs::credentials;
+use mosm_rs::enrich::{self, Enrichment};
+use mosm_rs::favorites::{self, Favorites};
+use mosm_rs::health::{self, Health};
+use mosm_rs::http::{self, Retry};
+use mosm_rs::provider::{PROVIDERS, WeatherProvider};
+use mosm_rs::render::{self, REPORT_WIDTH};
+use mosm_rs::{
+    FutureData, HistoryData, Hour, OpenMeteoClient, SearchResult, WeatherClient, WeatherData,
+    WeatherError,
+};
+use mosm_rs::{
+    applet, aurora, auxiliary, chart, commute, drift, drone, error, event, fasting, frost, geo,
+    heatmap, hourly, i18n, jobsite, quakes, schedule, schema, session, share, storms, sun,
+    tailwind, time, units, ventilation, windows,
+};
+use serde::Serialize;
+use std::io::{BufWriter, IsTerminal, Write, stdin, stdout};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// Number of forecast days requested from the API (free limit: 3).
+const FORECAST_DAYS: u32 = 3;
+
+/// Creates a Weather API client with the configured API key and language.
+fn weather_client(config: &Config) -> Result<WeatherClient, WeatherError> {
+    let client: WeatherClient = WeatherClient::new(load_api_key(config)?);
+    Ok(match &config.lang {
+        Some(lang) => client.lang(lang.as_str()),
+        None => client,
+    })
+}
+
+/// Returns the provider chosen with `--provider` or the config, exiting if unknown.
+fn provider_name<'a>(matches: &'a cli::Matches, config: &'a Config) -> &'a str {
+    let name: &str = matches
+        .value("provider")
+        .or(config.provider.as_deref())
+        .unwrap_or("weatherapi");
+    if !PROVIDERS.contains(&name) {
+        exit_invalid(&format!(
+            "Invalid value for --provider, expected {}.",
+            PROVIDERS.join(" or ")
+        ));
+    }
+    name
+}
+
+/// Creates a forecast provider with the configured settings.
+///
+/// Only the Weather API needs an API key.
+fn weather_provider(name: &str, config: &Config) -> Result<Box<dyn WeatherProvider>, WeatherError> {
+    match name {
+        "open-meteo" => {
+            let client: OpenMeteoClient = OpenMeteoClient::new();
+            Ok(Box::new(match &config.lang {
+                Some(lang) => client.lang(lang.as_str()),
+                None => client,
+            }))
+        }
+        _ => Ok(Box::new(weather_client(config)?)),
+    }
+}
+
+/// Fetches the forecast for the location given on the command line, or asks for one.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments with the optional location.
+/// * `config` - User defaults.
+/// * `days` - Number of forecast days to request.
+fn fetch_weather(
+    matches: &cli::Matches,
+    config: &Config,
+    days: u32,
+) -> Result<WeatherData, WeatherError> {
+    let query: String = query_from(matches, config)?;
+    let mut weather: WeatherData =
+        serde_json::from_value(fetch_response(matches, config, &query, days)?)?;
+    enrich_weather(matches, config, &query, &mut weather);
+    Ok(weather)
+}
+
+/// Adds the place name, elevation and translations asked for on the command line to a
+/// forecast, printing a note for each lookup that failed.
+///
+/// # Arguments
+/// * `query` - Location query the forecast was requested with.
+fn enrich_weather(matches: &cli::Matches, config: &Config, query: &str, weather: &mut WeatherData) {
+    let enrichment: Enrichment = Enrichment {
+        place: matches.flag("place"),
+        context: matches.flag("context"),
+        lang: config.lang.as_deref(),
+    };
+    let lookup: OpenMeteoClient = match &config.lang {
+        Some(lang) => OpenMeteoClient::new().lang(lang.as_str()),
+        None => OpenMeteoClient::new(),
+    };
+    for note in enrich::enrich(weather, query, &enrichment, &lookup) {
+        eprintln!("Note: {note}");
+    }
+}
+
+/// Fetches the raw forecast response through the on-disk cache.
+///
+/// A cached response is reused while younger than `cache_ttl`, and whatever its age
+/// when the API cannot be reached. When the provider fails and `fallback_provider`
+/// is set, the fallback is asked instead and a note names the source used; a
+/// provider that keeps failing is tried after its fallback for a while.
+fn fetch_response(
+    matches: &cli::Matches,
+    config: &Config,
+    query: &str,
+    days: u32,
+) -> Result<serde_json::Value, WeatherError> {
+    let primary: &str = provider_name(matches, config);
+    let mut providers: Vec<&str> = std::iter::once(primary)
+        .chain(
+            config
+                .fallback_provider
+                .as_deref()
+                .filter(|fallback| *fallback != primary),
+        )
+        .collect();
+    let health_path: Option<std::path::PathBuf> = Health::default_path();
+    let health: Health = health_path.as_deref().map(Health::load).unwrap_or_default();
+    let now: u64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    if providers.len() > 1 && health.is_down(primary, now) && !health.is_down(providers[1], now) {
+        eprintln!("{primary} keeps failing, trying {} first", providers[1]);
+        providers.reverse();
+    }
+
+    let ttl: u32 = config.cache_ttl.unwrap_or(cache::DEFAULT_TTL_MINUTES);
+    let lang: Option<&str> = config.lang.as_deref();
+    let caches: Vec<Option<Cache>> = providers
+        .iter()
+        .map(|provider| match Cache::default_dir() {
+            Some(dir) if ttl > 0 && !matches.flag("no-cache") => {
+                Some(Cache::new(dir.join(provider), ttl))
+            }
+            _ => None,
+        })
+        .collect();
+    let cached: Vec<Option<session::Session>> = caches
+        .iter()
+        .map(|cache| {
+            cache
+                .as_ref()
+                .and_then(|cache| cache.load(query, days, lang))
+        })
+        .collect();
+    for (cache, entry) in caches.iter().zip(&cached) {
+        if let (Some(cache), Some(entry)) = (cache, entry)
+            && cache.is_fresh(entry)
+        {
+            return strict_checked(matches, entry.response.clone());
+        }
+    }
+
+    let mut failure: Option<WeatherError> = None;
+    for (provider, cache) in providers.iter().zip(&caches) {
+        // A provider that cannot be set up, e.g. without an API key, fails over too.
+        let (fetched, set_up): (Result<String, WeatherError>, bool) =
+            match weather_provider(provider, config) {
+                Ok(client) => (client.fetch_forecast_json(query, days), true),
+                Err(e) => (Err(e), false),
+            };
+        match fetched {
+            Ok(json_body) => {
+                let response: serde_json::Value = serde_json::from_str(&json_body)?;
+                if let Some(cache) = cache {
+                    cache
+                        .store(&session::Session::new(query, days, response.clone()), lang)
+                        .unwrap_or_else(|e| eprintln!("Failed to update the cache: {e}"));
+                }
+                update_health(health_path.as_deref(), |health| {
+                    health.record_success(provider, now)
+                });
+                if *provider != primary {
+                    eprintln!("Forecast from {provider} (fallback)");
+                }
+                return strict_checked(matches, response);
+            }
+            Err(e) if !set_up || health::warrants_failover(&e) => {
+                update_health(health_path.as_deref(), |health| {
+                    health.record_failure(provider, now, &e)
+                });
+                if providers.len() > 1 {
+                    eprintln!("{provider}: {e}");
+                }
+                failure = Some(e);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    match failure {
+        Some(e @ WeatherError::Network(_)) => match cached.into_iter().flatten().next() {
+            Some(entry) => {
+                let minutes: u64 = Cache::age_secs(&entry) / 60;
+                if matches.flag("strict") {
+                    return Err(WeatherError::Strict(format!(
+                        "{e}, cached data from {minutes} minutes ago not used"
+                    )));
+                }
+                eprintln!("{e}, using cached data from {minutes} minutes ago");
+                Ok(entry.response)
+            }
+            None => Err(e),
+        },
+        Some(e) => Err(e),
+        None => unreachable!("at least one provider is tried"),
+    }
+}
+
+/// Fails with `--strict` if a response has fields unknown to or missing from the model.
+fn strict_checked(
+    matches: &cli::Matches,
+    response: serde_json::Value,
+) -> Result<serde_json::Value, WeatherError> {
+    if matches.flag("strict") {
+        let drift: drift::Drift = drift::response_drift(&response)?;
+        if !drift.is_empty() {
+            return Err(WeatherError::Strict(format!(
+                "response fields differ from the model: {drift}"
+            )));
+        }
+    }
+    Ok(response)
+}
+
+/// Serializes the provider health updates of the threads of `fetch_all`.
+static HEALTH_LOCK: Mutex<()> = Mutex::new(());
+
+/// Records an outcome in the provider health file, reloading it first so the
+/// updates of concurrent fetches are kept, and reports but otherwise ignores
+/// failures to write it.
+fn update_health(path: Option<&std::path::Path>, update: impl FnOnce(&mut Health)) {
+    let Some(path) = path else {
+        return;
+    };
+    let _lock = HEALTH_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut health: Health = Health::load(path);
+    update(&mut health);
+    health
+        .save(path)
+        .unwrap_or_else(|e| eprintln!("Failed to update the provider health: {e}"));
+}
+
+/// Prints the config, API key, cache and provider health, then fetches a forecast
+/// and lists its fields unknown to or missing from the model, to spot API changes.
+///
+/// The forecast is always fetched live, for the location given or London.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `doctor` subcommand.
+fn run_doctor(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let provider: &str = provider_name(matches, config);
+    let path_or_none = |path: Option<std::path::PathBuf>| -> String {
+        path.map_or("no home directory".to_owned(), |path| {
+            path.display().to_string()
+        })
+    };
+    let config_path: Option<std::path::PathBuf> = matches
+        .value("config")
+        .map(std::path::PathBuf::from)
+        .or_else(Config::default_path);
+    let config_found: &str = match &config_path {
+        Some(path) if path.exists() => "",
+        _ => " (not found, using defaults)",
+    };
+    let mut checks: Vec<(&str, String)> = vec![
+        (
+            "Config",
+            format!("{}{config_found}", path_or_none(config_path.clone())),
+        ),
+        (
+            "API key",
+            match load_api_key(config) {
+                Ok(key) => credentials::mask(&key),
+                Err(e) => e.to_string(),
+            },
+        ),
+        (
+            "Provider",
+            match &config.fallback_provider {
+                Some(fallback) => format!("{provider}, falling back to {fallback}"),
+                None => provider.to_owned(),
+            },
+        ),
+        ("Cache", path_or_none(Cache::default_dir())),
+    ];
+    let health_path: Option<std::path::PathBuf> = Health::default_path();
+    let health: Health = health_path.as_deref().map(Health::load).unwrap_or_default();
+    checks.push(("State", path_or_none(health_path)));
+    for (name, provider_health) in &health.providers {
+        checks.push((
+            name,
+            match &provider_health.last_error {
+                Some(error) if provider_health.consecutive_failures > 0 => format!(
+                    "{} failures in a row, last: {error}",
+                    provider_health.consecutive_failures
+                ),
+                _ => "ok".to_owned(),
+            },
+        ));
+    }
+
+    let query: &str = matches.positional().unwrap_or("London");
+    let drift: Result<drift::Drift, String> = weather_provider(provider, config)
+        .and_then(|weather| weather.fetch_forecast_json(query, 1))
+        .and_then(|body| drift::forecast_drift(&body))
+        .map_err(|e| e.to_string());
+    let mut out = BufWriter::new(stdout().lock());
+    render::render_doctor(&checks, drift.as_ref().map_err(String::clone), &mut out)
+        .and_then(|()| out.flush())
+        .expect("Failed to write report");
+    Ok(())
 }
 
 /// Reads a location query from the console.
 ///
 /// # Returns
-/// A `String` containing the user-provided location.
-fn get_query_from_user() -> String {
+/// A `String` containing the user-provided location, or the IP lookup query if
+/// none was entered.
+fn get_query_from_user() -> Result<String, WeatherError> {
     print!("Enter Location: ");
     stdout().flush().unwrap();
     let mut input_query = String::new();
@@ -241,139 +336,1931 @@ fn get_query_from_user() -> String {
         .read_line(&mut input_query)
         .expect("Failed to read line");
     if input_query.trim().is_empty() {
-        println!("No Location is provided");
-        println!("Entry city name, IP address, Latitude/Longitude (decimal degree)\nUS Zipcode, Uk Postcode, Canada Postalcode.");
-        std::process::exit(0);
+        eprintln!("No location provided, locating your IP address");
+        Ok(AUTO_IP_QUERY.to_owned())
     } else {
-        input_query
+        Ok(input_query)
     }
 }
 
-/// Loads the Weather API key from the environment or a provided key.
+/// Returns the API key of the config, see [`load_config`] for where it comes from.
 ///
 /// # Arguments
-/// * `user_api_key` - A `String` containing the user-provided API key, or empty to load from `.env`.
+/// * `config` - User defaults, with the API key already resolved.
 ///
 /// # Returns
 /// A `String` containing the validated API key.
-fn load_api_key(user_api_key: String) -> String {
-    dotenv::dotenv().ok();
+fn load_api_key(config: &Config) -> Result<String, WeatherError> {
+    let api_key: &str = config.api_key.as_deref().unwrap_or("");
+    validate_api_key(api_key)?;
+    Ok(api_key.to_owned())
+}
+
+/// Checks that an API key is present and long enough to be a WeatherAPI key.
+fn validate_api_key(api_key: &str) -> Result<(), WeatherError> {
     let user_api_min_length: usize = 20;
-    if user_api_key.trim().is_empty() {
-        match std::env::var("WEATHER_API_KEY") {
-            Ok(val) => val,
-            Err(e) => {
-                eprintln!("Error: {}", e.to_string().to_uppercase());
-                std::process::exit(0);
+    if api_key.trim().is_empty() {
+        Err(WeatherError::MissingApiKey)
+    } else if api_key.trim().len() < user_api_min_length {
+        Err(WeatherError::InvalidApiKey(format!(
+            "too short, expected at least {user_api_min_length} characters"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Loads the config file (`--config`, or the default path) and applies environment overrides.
+///
+/// The API key comes from `--api-key`, else `WEATHER_API_KEY` in the environment, else
+/// the key saved with `auth set`, else `WEATHER_API_KEY` in `.env`, else `config.toml`.
+fn load_config(matches: &cli::Matches) -> Result<Config, WeatherError> {
+    let key_in_env: bool = std::env::var_os("WEATHER_API_KEY").is_some();
+    dotenv::dotenv().ok();
+    let mut config: Config = match matches.value("config") {
+        Some(path) if !std::path::Path::new(path).exists() => {
+            return Err(WeatherError::Config(format!("{path}: file not found")));
+        }
+        Some(path) => Config::load(std::path::Path::new(path))?,
+        None => match Config::default_path() {
+            Some(path) => Config::load(&path)?,
+            None => Config::default(),
+        },
+    };
+    config.apply_env()?;
+    if let Some(lang) = matches.value("lang") {
+        config.lang = Some(lang.to_owned());
+    }
+    if let Some(key) = matches.value("api-key") {
+        config.api_key = Some(key.to_owned());
+    } else if !key_in_env
+        && let Some(path) = credentials::default_path()
+        && let Some(key) = credentials::load(&path)?
+    {
+        config.api_key = Some(key);
+    }
+    Ok(config)
+}
+
+/// Saves, shows or removes the API key kept in the user's mosm directory.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `auth` subcommand.
+/// * `config` - User defaults, with the API key in effect.
+fn run_auth(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let path: std::path::PathBuf = credentials::default_path()
+        .ok_or_else(|| WeatherError::Config("no home directory for api_key".to_owned()))?;
+    let write_error = |e: std::io::Error| WeatherError::Config(format!("{}: {e}", path.display()));
+    let args: Vec<&str> = matches.positionals().iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["set", key @ ..] if key.len() <= 1 => {
+            let key: String = match key.first() {
+                Some(key) => (*key).to_owned(),
+                None => {
+                    if stdin().is_terminal() {
+                        eprint!("WeatherAPI key: ");
+                    }
+                    let mut line: String = String::new();
+                    stdin()
+                        .read_line(&mut line)
+                        .map_err(|e| WeatherError::Config(format!("stdin: {e}")))?;
+                    line.trim().to_owned()
+                }
+            };
+            validate_api_key(&key)?;
+            credentials::save(&path, &key).map_err(write_error)?;
+            println!("Saved the API key to {}", path.display());
+        }
+        ["show"] => {
+            let saved: Option<String> = credentials::load(&path)?;
+            match &saved {
+                Some(key) => println!("Saved key: {} ({})", credentials::mask(key), path.display()),
+                None => println!("No API key saved."),
+            }
+            match &config.api_key {
+                Some(key) if saved.as_ref() != Some(key) => println!(
+                    "In use: {} from --api-key, WEATHER_API_KEY, .env or config.toml",
+                    credentials::mask(key)
+                ),
+                Some(_) => {}
+                None => println!("No API key in use."),
+            }
+        }
+        ["remove"] => {
+            if credentials::remove(&path).map_err(write_error)? {
+                println!("Removed the API key from {}", path.display());
+            } else {
+                println!("No API key saved.");
+            }
+        }
+        _ => exit_invalid("Invalid auth command, expected set [KEY], show or remove."),
+    }
+    Ok(())
+}
+
+/// Applies the retry settings of the config and the `--timeout` option to all requests.
+fn configure_http(matches: &cli::Matches, config: &Config) {
+    let defaults: Retry = Retry::default();
+    let timeout: Option<u64> = option_value::<u64>(matches, "timeout")
+        .inspect(|secs| {
+            if !(1..=300).contains(secs) {
+                exit_invalid("Invalid value for --timeout, expected 1 to 300 seconds.");
             }
+        })
+        .or(config.timeout.map(u64::from));
+    http::configure(Retry {
+        retries: config.retries.unwrap_or(defaults.retries),
+        delay: config.retry_delay.map_or(defaults.delay, |millis| {
+            Duration::from_millis(millis.into())
+        }),
+        timeout: timeout.map_or(defaults.timeout, Duration::from_secs),
+    });
+}
+
+/// Prints a compact text block for sharing by SMS or messengers.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `share` subcommand.
+fn run_share(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let with_link: bool = matches.flag("link");
+    let weather: WeatherData = fetch_weather(matches, config, FORECAST_DAYS)?;
+    let (lat, lon) = (weather.location.lat, weather.location.lon);
+    // Only the Weather API has location pages; other providers, or a failed
+    // lookup, link to a map of the coordinates.
+    let link: Option<String> = with_link.then(|| {
+        (provider_name(matches, config) == "weatherapi")
+            .then(|| {
+                weather_client(config).and_then(|client| client.search(&format!("{lat},{lon}")))
+            })
+            .and_then(Result::ok)
+            .and_then(|results| results.into_iter().next())
+            .map_or_else(
+                || share::map_link(lat, lon),
+                |result| format!("https://www.weatherapi.com/weather/q/{}", result.url),
+            )
+    });
+    println!(
+        "{}",
+        share::share_text(&weather, units_from(matches, config), link.as_deref())
+    );
+    Ok(())
+}
+
+/// Prints an hourly heatmap of a metric across the forecast days.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `heatmap` subcommand.
+fn run_heatmap(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let metric: heatmap::Metric = match matches.value("metric") {
+        None => heatmap::Metric::Temp,
+        Some(name) => heatmap::Metric::from_name(name).unwrap_or_else(|| {
+            exit_invalid("Invalid value for --metric, expected temp, rain, wind, humidity or uv.")
+        }),
+    };
+    let weather: WeatherData = fetch_weather(matches, config, forecast_days(matches, config))?;
+
+    let mut out = BufWriter::new(stdout().lock());
+    heatmap::render(&weather, metric, &mut out)
+        .and_then(|()| out.flush())
+        .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints a line chart of the hourly temperature over the coming days.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `chart` subcommand.
+fn run_chart(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let days: u32 = match option_value::<u32>(matches, "days") {
+        None => 1,
+        Some(days @ 1..=3) => days,
+        Some(_) => exit_invalid("Invalid value for --days, expected 1 to 3."),
+    };
+    // One more day, as the chart starts at the hour in progress.
+    let weather: WeatherData = fetch_weather(matches, config, days + 1)?;
+
+    let mut out = BufWriter::new(stdout().lock());
+    chart::render(&weather, days as usize, &mut out)
+        .and_then(|()| out.flush())
+        .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints recent earthquakes near a location.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `quakes` subcommand.
+fn run_quakes(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let positive = |name: &str, default: f64| -> f64 {
+        match option_value::<f64>(matches, name) {
+            None => default,
+            Some(value) if value > 0.0 => value,
+            Some(_) => exit_invalid(&format!(
+                "Invalid value for --{name}, expected a positive number."
+            )),
         }
-    } else if user_api_key.len() < user_api_min_length {
-        eprintln!("Invalid User API KEY (Leave empty to load from .env)");
-        std::process::exit(0);
+    };
+    let radius_km: f64 = positive("radius", quakes::DEFAULT_RADIUS_KM);
+    let min_magnitude: f64 = positive("min-magnitude", quakes::DEFAULT_MIN_MAGNITUDE);
+    let weather: WeatherData = fetch_weather(matches, config, 1)?;
+    let feed: quakes::QuakeFeed = quakes::fetch_quakes(
+        weather.location.lat,
+        weather.location.lon,
+        radius_km,
+        min_magnitude,
+    )?;
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        write_json(&feed, &mut out)
     } else {
-        user_api_key
+        render::render_quakes(&weather, &feed, radius_km, min_magnitude, &mut out)
     }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
 }
 
-/// Fetches and parses weather data from the Weather API.
+/// Prints tonight's aurora visibility estimate for a location.
 ///
 /// # Arguments
-/// * `query` - A `String` representing the location query (e.g., city name, coordinates).
+/// * `matches` - Parsed arguments of the `aurora` subcommand.
+/// * `config` - User defaults.
+fn run_aurora(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let weather: WeatherData = fetch_weather(matches, config, 2)?;
+    let outlook: aurora::AuroraOutlook = aurora::outlook(&weather, &aurora::fetch_kp_forecast()?);
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        write_json(&outlook, &mut out)
+    } else {
+        render::render_aurora(&weather, &outlook, &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints a drone flight verdict for each coming hour.
 ///
-/// # Returns
-/// A `WeatherData` struct containing the parsed weather information.
-fn fetch_parsed_json(query: String, days: u32) -> WeatherData {
-    let api_key = load_api_key("2aed558640c64add927135819250108".to_owned()); // Provide WeatherAPI Key, or leave empty to load form .env
-    let aqi: String = "yes".to_owned();
-    let url: String = format!(
-        "https://api.weatherapi.com/v1/forecast.json?key={api_key}&q={query}&days={days}&aqi={aqi}"
-    );
-    let url: reqwest::Url = reqwest::Url::parse(&url).unwrap();
-    let response: reqwest::blocking::Response =
-        reqwest::blocking::get(url).expect("Failed to fetch weather data");
-    if response.status() != 200 {
-        println!(
-            "Failed to fetch weather data, status code {}",
-            response.status()
-        );
-        std::process::exit(0);
+/// # Arguments
+/// * `matches` - Parsed arguments of the `drone` subcommand.
+fn run_drone(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let hours: usize = match option_value::<usize>(matches, "hours") {
+        None => 12,
+        Some(value @ 1..=48) => value,
+        Some(_) => exit_invalid("Invalid value for --hours, expected 1 to 48."),
+    };
+    let mut limits: drone::Limits = drone::Limits::default();
+    for (name, limit, expected) in [
+        ("max-wind", &mut limits.max_wind_kph, "kph"),
+        ("max-gust", &mut limits.max_gust_kph, "kph"),
+        ("max-precip", &mut limits.max_precip_mm, "mm"),
+        ("min-visibility", &mut limits.min_vis_km, "km"),
+        ("max-kp", &mut limits.max_kp, "a K index"),
+    ] {
+        match option_value::<f64>(matches, name) {
+            None => {}
+            Some(value) if value >= 0.0 => *limit = value,
+            Some(_) => exit_invalid(&format!("Invalid value for --{name}, expected {expected}.")),
+        }
+    }
+    if let Some(value) = option_value::<f64>(matches, "min-temp") {
+        limits.min_temp_c = value;
     }
-    let json_body = response.text().unwrap();
-    let json_parsed: WeatherData =
-        serde_json::from_str(&json_body).expect("Failed to parse Json to the structs");
-    json_parsed
+    if let Some(value) = option_value::<f64>(matches, "max-temp") {
+        limits.max_temp_c = value;
+    }
+    if limits.min_temp_c > limits.max_temp_c {
+        exit_invalid(&format!(
+            "Invalid value for --min-temp, expected at most the maximum of {}°C.",
+            limits.max_temp_c
+        ));
+    }
+    let weather: WeatherData = fetch_weather(matches, config, 3)?;
+    let kp_forecast: Vec<aurora::KpPeriod> = aurora::fetch_kp_forecast().unwrap_or_else(|e| {
+        eprintln!("Failed to fetch the Kp forecast: {e}");
+        Vec::new()
+    });
+    let checks: Vec<drone::HourCheck> = drone::check_hours(&weather, &kp_forecast, &limits, hours);
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "limits": limits,
+            "hours": checks,
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_drone(
+            &weather,
+            &limits,
+            &checks,
+            units_from(matches, config),
+            &mut out,
+        )
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
 }
 
-/// Main function to run the weather application.
-fn main() {
-    let query: String;
-    let args: Vec<String> = std::env::args().collect();
-    let days: u32 = 3; // Free limit: 3
-    if args.len() > 2 {
-        println!("Invalid argument!, Use \"\" quotations if location have whitespace.");
-        std::process::exit(0);
-    } else if args.len() == 2 && !args[1].trim().is_empty() {
-        query = args[1].clone();
+/// Prints a jobsite safety summary for each remaining part of the working days.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `jobsite` subcommand.
+fn run_jobsite(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let trade: &str = matches.value("trade").unwrap_or("general");
+    let Some(thresholds) = config.thresholds(trade) else {
+        let mut trades: Vec<&str> = vec!["general", "crane", "roofing"];
+        trades.extend(config.trades.keys().map(String::as_str));
+        trades.sort_unstable();
+        trades.dedup();
+        exit_invalid(&format!(
+            "Unknown trade {trade}, expected {} or a [trade.NAME] table in the config.",
+            trades.join(", ")
+        ));
+    };
+    let weather: WeatherData = fetch_weather(matches, config, forecast_days(matches, config))?;
+    let parts: Vec<jobsite::DayPart> = jobsite::day_parts(&weather, &thresholds);
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "trade": trade,
+            "thresholds": thresholds,
+            "day_parts": parts,
+        });
+        write_json(&value, &mut out)
     } else {
-        query = get_query_from_user();
+        render::render_jobsite(
+            &weather,
+            trade,
+            &thresholds,
+            &parts,
+            units_from(matches, config),
+            &mut out,
+        )
     }
-    let weather: WeatherData = fetch_parsed_json(query, days);
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
 
-    println!("<>{}<>", "-".repeat(70));
-    println!(
-        "{} ({}, {})\nLocal Time: {}\n",
-        weather.location.name,
-        weather.location.region,
-        weather.location.country,
-        weather.location.localtime,
-    );
+/// Prints the frost stage of each night at one or more field locations.
+///
+/// Without locations on the command line, every saved location is checked, or
+/// the usual single location when none are saved or with `--auto`.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `frost` subcommand.
+fn run_frost(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let mut stages: frost::Stages = frost::Stages::default();
+    for (name, start) in [
+        ("advisory", &mut stages.advisory_c),
+        ("warning", &mut stages.warning_c),
+        ("critical", &mut stages.critical_c),
+    ] {
+        if let Some(value) = option_value::<f64>(matches, name) {
+            *start = value;
+        }
+    }
+    if !(stages.advisory_c > stages.warning_c && stages.warning_c > stages.critical_c) {
+        exit_invalid("Invalid stage temperatures, expected --advisory > --warning > --critical.");
+    }
+    let favorites: Favorites = load_favorites()?;
+    let queries: Vec<String> = if !matches.positionals().is_empty() {
+        matches
+            .positionals()
+            .iter()
+            .map(|query| favorites.resolve(query).map(str::to_owned))
+            .collect::<Result<_, _>>()?
+    } else if !favorites.locations.is_empty() && !matches.flag("auto") {
+        favorites.locations.values().cloned().collect()
+    } else {
+        vec![query_from(matches, config)?]
+    };
+    let queries: Vec<&str> = queries.iter().map(String::as_str).collect();
+    let weathers: Vec<WeatherData> =
+        fetch_all(matches, config, &queries, forecast_days(matches, config))?;
+    let fields: Vec<(&WeatherData, Vec<frost::Night>)> = weathers
+        .iter()
+        .map(|weather| (weather, frost::nights(weather, &stages)))
+        .collect();
 
-    println!(
-        "{} | {}°C / {}°F\tUV: {}\n",
-        weather.current.condition.text,
-        weather.current.temp_c,
-        weather.current.temp_f,
-        weather.current.uv
-    );
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "stages": stages,
+            "locations": fields
+                .iter()
+                .map(|(weather, nights)| serde_json::json!({
+                    "location": weather.location,
+                    "nights": nights,
+                }))
+                .collect::<Vec<_>>(),
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_frost(&fields, &stages, units_from(matches, config), &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
 
-    println!(
-        "Feels like: {}°C / {}°F\tHumidity: {}%\tPrecip: {} mm",
-        weather.current.feelslike_c,
-        weather.current.feelslike_f,
-        weather.current.humidity,
-        weather.current.precip_mm
-    );
+/// Prints active tropical cyclones relative to a location.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `storms` subcommand.
+/// * `config` - User defaults.
+fn run_storms(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let radius_km: f64 = match option_value::<f64>(matches, "radius") {
+        None => storms::DEFAULT_TRACK_RADIUS_KM,
+        Some(value) if value > 0.0 => value,
+        Some(_) => exit_invalid("Invalid value for --radius, expected a positive number."),
+    };
+    let weather: WeatherData = fetch_weather(matches, config, 1)?;
+    let mut active: Vec<(storms::Storm, Vec<storms::TrackPoint>)> = Vec::new();
+    for storm in storms::fetch_storms()?.active_storms {
+        // A missing track leaves the storm listed with its current position only.
+        let track: Vec<storms::TrackPoint> = storms::fetch_track(&storm).unwrap_or_else(|e| {
+            eprintln!("Note: no forecast track for {}: {e}", storm.name);
+            Vec::new()
+        });
+        active.push((storm, track));
+    }
 
-    let wind_dir: &str = weather.current.wind_dir.as_str();
-    println!(
-        "Wind: {} {}kph / {}mph \tDew Point: {}°C / {}°F",
-        get_wind_arrows().get(wind_dir).unwrap_or(&"❓"),
-        weather.current.wind_kph,
-        weather.current.wind_mph,
-        weather.current.dewpoint_c,
-        weather.current.dewpoint_f
-    );
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "storms": active
+                .iter()
+                .map(|(storm, track)| serde_json::json!({
+                    "storm": storm,
+                    "track": track,
+                }))
+                .collect::<Vec<_>>(),
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_storms(&weather, &active, radius_km, &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
 
-    println!(
-        "AQI: {}\tPM2.5: {:.1} μg/m³\tPM10: {:.1} μg/m³",
-        get_us_epa_index()
-            .get(&(weather.current.air_quality.us_epa_index as u8))
-            .unwrap_or(&"Unknown"),
-        weather.current.air_quality.pm2_5,
-        weather.current.air_quality.pm10,
+/// Prints an hour-by-hour table for the coming hours or a chosen forecast day.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `hourly` subcommand.
+/// * `config` - User defaults.
+fn run_hourly(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let count: Option<usize> = match option_value::<usize>(matches, "hours") {
+        Some(0) => exit_invalid("Invalid value for --hours, expected a positive number."),
+        count => count,
+    };
+    let units: units::Units = units_from(matches, config);
+    let days: u32 = forecast_days(matches, config);
+    let weather: WeatherData = fetch_weather(matches, config, days)?;
+    let hours: Vec<&Hour> = match matches.value("day") {
+        None => hourly::upcoming(&weather, count.unwrap_or(24)),
+        Some(day) => {
+            let Some(date) = hourly::resolve_day(&weather, day) else {
+                eprintln!("{day} is outside the {days}-day forecast for this location.");
+                std::process::exit(error::EXIT_USAGE);
+            };
+            hourly::day_hours(&weather, date, count.unwrap_or(24))
+        }
+    };
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        write_json(&hours, &mut out)
+    } else {
+        render::render_hourly(&weather, &hours, units, &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints the sun's azimuth, elevation and shadow length for a location and time.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `sunpos` subcommand.
+/// * `config` - User defaults.
+fn run_sunpos(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let at: Option<&str> = match matches.value("at") {
+        Some(value) if commute::is_valid_time(value) || event::is_valid_datetime(value) => {
+            Some(value)
+        }
+        Some(_) => exit_invalid("Invalid value for --at, expected HH:MM or \"YYYY-MM-DD HH:MM\"."),
+        None => None,
+    };
+    let weather: WeatherData = fetch_weather(matches, config, 1)?;
+    let location: &mosm_rs::Location = &weather.location;
+    // A time of day is taken as today's; the current UTC offset is assumed.
+    let (local_time, epoch): (String, i64) = match at {
+        None => (
+            location.localtime.clone(),
+            i64::from(location.localtime_epoch),
+        ),
+        Some(value) => {
+            let local_time: String = if commute::is_valid_time(value) {
+                format!("{} {value}", &location.localtime[..10])
+            } else {
+                value.to_owned()
+            };
+            let epoch: i64 = time::local_epoch(&local_time, time::utc_offset(location));
+            (local_time, epoch)
+        }
+    };
+    let position: sun::SunPosition = sun::position(location.lat, location.lon, epoch);
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": location,
+            "time": local_time,
+            "position": position,
+            "shadow_ratio": position.shadow_ratio(),
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_sunpos(&weather, &local_time, &position, &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints dawn and sunset fasting times for the coming days.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `fasting` subcommand.
+/// * `config` - User defaults.
+fn run_fasting(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let days: u32 = match option_value::<u32>(matches, "days") {
+        None => 7,
+        Some(value @ 1..=30) => value,
+        Some(_) => exit_invalid("Invalid value for --days, expected 1 to 30."),
+    };
+    let dawn_angle: f64 = match option_value::<f64>(matches, "dawn-angle") {
+        None => fasting::DEFAULT_DAWN_ANGLE,
+        Some(value) if (0.0..=30.0).contains(&value) => value,
+        Some(_) => exit_invalid("Invalid value for --dawn-angle, expected 0 to 30 degrees."),
+    };
+    let weather: WeatherData = fetch_weather(matches, config, 1)?;
+    let fasting_days: Vec<fasting::FastingDay> =
+        fasting::fasting_days(&weather.location, days, dawn_angle);
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "dawn_angle": dawn_angle,
+            "days": fasting_days,
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_fasting(
+            &weather,
+            &fasting_days,
+            dawn_angle,
+            matches.flag("generic"),
+            &mut out,
+        )
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints or exports the daily sun schedule.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `schedule` subcommand.
+fn run_schedule(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let days: u32 = match option_value::<u32>(matches, "days") {
+        None => 7,
+        Some(value @ 1..=366) => value,
+        Some(_) => exit_invalid("Invalid value for --days, expected 1 to 366."),
+    };
+    let twilight: schedule::Twilight = option_value(matches, "twilight").unwrap_or_default();
+    let weather: WeatherData = fetch_weather(matches, config, 1)?;
+    let schedules: Vec<schedule::DaySchedule> =
+        schedule::day_schedules(&weather.location, days, twilight.depression());
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "twilight_depression": twilight.depression(),
+            "days": schedules,
+        });
+        write_json(&value, &mut out)
+    } else if matches.flag("ics") {
+        let now: i64 = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+        schedule::write_ics(&weather.location, &schedules, now, &mut out)
+    } else {
+        render::render_schedule(&weather, &schedules, twilight.depression(), &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Lists, saves and removes named locations, or sets the default one.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `locations` subcommand: an action and its operands.
+fn run_locations(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let path: std::path::PathBuf = Favorites::default_path()
+        .ok_or_else(|| WeatherError::Config("no home directory for locations.json".to_owned()))?;
+    let mut favorites: Favorites = Favorites::load(&path)?;
+    let args: Vec<&str> = matches.positionals().iter().map(String::as_str).collect();
+    let message: String = match args.as_slice() {
+        [] | ["list"] => {
+            let mut out = BufWriter::new(stdout().lock());
+            if matches.flag("json") {
+                write_json(&favorites, &mut out)
+            } else {
+                render::render_locations(&favorites, &mut out)
+            }
+            .and_then(|()| out.flush())
+            .expect("Failed to write report");
+            return Ok(());
+        }
+        ["add", name, query @ ..] if !query.is_empty() => {
+            favorites
+                .add(name, &query.join(" "))
+                .unwrap_or_else(|reason| exit_invalid(&format!("Invalid location: {reason}.")));
+            format!("Saved @{}", name.trim_start_matches('@').to_lowercase())
+        }
+        ["import", file] => {
+            let format: &str = match matches.value("from") {
+                Some(format) if favorites::IMPORT_FORMATS.contains(&format) => format,
+                _ => exit_invalid("Invalid value for --from, expected wttr, owm-cli or csv."),
+            };
+            let text: String = std::fs::read_to_string(file)
+                .map_err(|e| WeatherError::Config(format!("{file}: {e}")))?;
+            let entries: Vec<(String, String)> = favorites::parse_import(format, &text)
+                .map_err(|reason| WeatherError::Config(format!("{file}: {reason}")))?;
+            let (saved, skipped) = favorites.import(&entries);
+            for (name, reason) in &skipped {
+                eprintln!("Skipped {name:?}: {reason}");
+            }
+            let names: String = saved.iter().map(|name| format!(" @{name}")).collect();
+            format!(
+                "Imported {} of {} locations{names}",
+                saved.len(),
+                entries.len()
+            )
+        }
+        ["remove", name] => {
+            if !favorites.remove(name) {
+                exit_invalid(&format!("No saved location named {name}."));
+            }
+            format!("Removed {name}")
+        }
+        ["default", name] => {
+            if !favorites.set_default(name) {
+                exit_invalid(&format!("No saved location named {name}."));
+            }
+            if let Some(location) = &config.location {
+                eprintln!(
+                    "Note: the location {location:?} set in the config or MOSM_LOCATION comes first."
+                );
+            }
+            format!("Default location: {name}")
+        }
+        _ => exit_invalid(
+            "Invalid locations command, expected list, add NAME LOCATION, import FILE, remove NAME or default NAME.",
+        ),
+    };
+    favorites
+        .save(&path)
+        .map_err(|e| WeatherError::Config(format!("{}: {e}", path.display())))?;
+    println!("{message}");
+    Ok(())
+}
+
+/// Prints a go/no-go summary for an outdoor event.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `event` subcommand.
+fn run_event(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let at: String = match matches.value("at") {
+        Some(value) if event::is_valid_datetime(value) => value.to_owned(),
+        Some(_) => exit_invalid("Invalid value for --at, expected \"YYYY-MM-DD HH:MM\"."),
+        None => exit_invalid("Missing --at \"YYYY-MM-DD HH:MM\" for the event start."),
+    };
+    let duration_minutes: u32 = match matches.value("duration") {
+        None => 120,
+        Some(value) => event::parse_duration(value).unwrap_or_else(|| {
+            exit_invalid("Invalid value for --duration, expected e.g. 3h, 90m or 1h30m.")
+        }),
+    };
+    let days: u32 = forecast_days(matches, config);
+    let weather: WeatherData = fetch_weather(matches, config, days)?;
+    let hours: Vec<&Hour> = event::select_hours(&weather, &at, duration_minutes);
+    if hours.is_empty() {
+        eprintln!("{at} is outside the {days}-day forecast for this location.");
+        std::process::exit(error::EXIT_USAGE);
+    }
+
+    let mut out = BufWriter::new(stdout().lock());
+    render::render_event(
+        &weather,
+        &at,
+        duration_minutes,
+        &hours,
+        units_from(matches, config),
+        &mut out,
+    )
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints conditions for the outbound and return legs of a commute.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `commute` subcommand.
+fn run_commute(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let mut legs: Vec<(&str, String)> = Vec::new();
+    for (name, label) in [("leave", "Leave"), ("return", "Return")] {
+        match matches.value(name) {
+            Some(value) if commute::is_valid_time(value) => legs.push((label, value.to_owned())),
+            Some(_) => exit_invalid(&format!("Invalid value for --{name}, expected HH:MM.")),
+            None => {}
+        }
+    }
+    if legs.is_empty() {
+        exit_invalid("Provide --leave HH:MM and/or --return HH:MM.");
+    }
+    let weather: WeatherData = fetch_weather(matches, config, 2)?;
+
+    let mut out = BufWriter::new(stdout().lock());
+    render::render_commute(&weather, &legs, units_from(matches, config), &mut out)
+        .and_then(|()| out.flush())
+        .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints the next dry, calm windows for a walk.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `walk` subcommand.
+fn run_walk(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let mut criteria: windows::Criteria = windows::Criteria::default();
+    let count: usize = match option_value::<usize>(matches, "count") {
+        None => 3,
+        Some(value) if value > 0 => value,
+        Some(_) => exit_invalid("Invalid value for --count, expected a positive number."),
+    };
+    let min_minutes: u32 = match matches.value("min-duration") {
+        None => 60,
+        Some(value) => event::parse_duration(value).unwrap_or_else(|| {
+            exit_invalid("Invalid value for --min-duration, expected e.g. 1h, 45m or 1h30m.")
+        }),
+    };
+    match option_value::<f64>(matches, "max-wind") {
+        None => {}
+        Some(value) if value >= 0.0 => criteria.max_wind_kph = value,
+        Some(_) => exit_invalid("Invalid value for --max-wind, expected kph."),
+    }
+    match option_value::<i32>(matches, "max-rain") {
+        None => {}
+        Some(value @ 0..=100) => criteria.max_chance_of_rain = value,
+        Some(_) => exit_invalid("Invalid value for --max-rain, expected a percentage."),
+    }
+    criteria.daylight_only = !matches.flag("any-time");
+    let weather: WeatherData = fetch_weather(matches, config, 2)?;
+    let found: Vec<windows::Window> =
+        windows::find_windows(&weather, &criteria, min_minutes, count);
+
+    let mut out = BufWriter::new(stdout().lock());
+    render::render_walk(
+        &weather,
+        &criteria,
+        min_minutes,
+        &found,
+        units_from(matches, config),
+        &mut out,
+    )
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints the time ranges left today to open the windows.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `ventilate` subcommand.
+/// * `config` - User defaults, with the indoor target.
+fn run_ventilate(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let mut indoor: ventilation::Indoor = ventilation::Indoor::default();
+    match option_value::<f64>(matches, "indoor-temp").or(config.indoor_temp.map(f64::from)) {
+        None => {}
+        Some(value @ -10.0..=40.0) => indoor.temp_c = value,
+        Some(_) => exit_invalid("Invalid value for --indoor-temp, expected -10 to 40 °C."),
+    }
+    match option_value::<f64>(matches, "indoor-humidity").or(config.indoor_humidity.map(f64::from))
+    {
+        None => {}
+        Some(value @ 1.0..=100.0) => indoor.humidity = value,
+        Some(_) => exit_invalid("Invalid value for --indoor-humidity, expected 1 to 100 %."),
+    }
+    let weather: WeatherData = fetch_weather(matches, config, 1)?;
+    let found: Vec<windows::Window> = ventilation::airing_windows(&weather, &indoor);
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "indoor": indoor,
+            "indoor_dewpoint_c": indoor.dewpoint_c(),
+            "windows": found
+                .iter()
+                .map(|window| serde_json::json!({
+                    "start": window.start(),
+                    "end": window.end(),
+                }))
+                .collect::<Vec<_>>(),
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_ventilation(
+            &weather,
+            &indoor,
+            &found,
+            units_from(matches, config),
+            &mut out,
+        )
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints the coming hours with the values of an auxiliary source, such as
+/// electricity prices, next to the temperature and sunshine.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `energy` subcommand.
+fn run_energy(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let name: &str = matches.value("source").unwrap_or("awattar-de");
+    let Some(source) = auxiliary::source(name) else {
+        exit_invalid(&format!(
+            "Invalid value for --source, expected {}.",
+            auxiliary::SOURCES.join(" or ")
+        ));
+    };
+    let hours: usize = match option_value::<usize>(matches, "hours") {
+        None => 24,
+        Some(value @ 1..=48) => value,
+        Some(_) => exit_invalid("Invalid value for --hours, expected 1 to 48."),
+    };
+    let weather: WeatherData = fetch_weather(matches, config, 3)?;
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    let values: Vec<auxiliary::AuxValue> = source.fetch(from, from + hours as i64 * 3600)?;
+    let rows: Vec<auxiliary::AuxHour> = auxiliary::alongside(&weather, &values, hours);
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let (label, unit) = source.label();
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "source": source.name(),
+            "label": label,
+            "unit": unit,
+            "hours": rows,
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_energy(
+            &weather,
+            source.label(),
+            &rows,
+            units_from(matches, config),
+            &mut out,
+        )
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints the weather observed on a past date beside today's forecast.
+///
+/// History comes from the Weather API whatever the provider, while today's
+/// forecast goes through the configured provider and cache.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `history` subcommand.
+fn run_history(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let now: i64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    // Today in UTC, as for the future window.
+    let today: String = time::format_local(now, 0)[..10].to_owned();
+    let date: &str = match matches.value("date") {
+        Some(date)
+            if time::is_valid_date(date) && date >= "2010-01-01" && date <= today.as_str() =>
+        {
+            date
+        }
+        Some(_) => exit_invalid(&format!(
+            "Invalid value for --date, expected YYYY-MM-DD from 2010-01-01 to {today}."
+        )),
+        None => exit_invalid("Missing --date YYYY-MM-DD for the past date."),
+    };
+    let query: String = query_from(matches, config)?;
+    let history: HistoryData = weather_client(config)?.fetch_history(&query, date)?;
+    let today: WeatherData = serde_json::from_value(fetch_response(matches, config, &query, 1)?)?;
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": history.location,
+            "date": history.day(),
+            "today": today.forecast.forecastday.first(),
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_history(&history, &today, units_from(matches, config), &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints the long-range forecast of a date 14 to 300 days ahead.
+///
+/// Like history, it comes from the Weather API whatever the provider.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `future` subcommand.
+fn run_future(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let now: i64 = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64);
+    // The window is checked against today in UTC; the API counts from the
+    // location's date, which differs by a day at most.
+    let today: String = time::format_local(now, 0)[..10].to_owned();
+    let date: &str = match matches.value("date") {
+        Some(date)
+            if time::is_valid_date(date)
+                && FUTURE_DAYS_AHEAD.contains(&time::days_between(&today, date)) =>
+        {
+            date
+        }
+        Some(_) => exit_invalid(&format!(
+            "Invalid value for --date, expected YYYY-MM-DD {} to {} days after {today}.",
+            FUTURE_DAYS_AHEAD.start(),
+            FUTURE_DAYS_AHEAD.end()
+        )),
+        None => exit_invalid("Missing --date YYYY-MM-DD for the forecast date."),
+    };
+    let query: String = query_from(matches, config)?;
+    let future: FutureData = weather_client(config)?.fetch_future(&query, date)?;
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        write_json(&future, &mut out)
+    } else {
+        render::render_future(&future, units_from(matches, config), &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints the coming hours with the best tailwind along a heading.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the `tailwind` subcommand.
+fn run_tailwind(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let heading_deg: f64 = match matches.value("heading").map(geo::parse_bearing) {
+        Some(Some(heading)) => heading,
+        Some(None) => exit_invalid(
+            "Invalid value for --heading, expected degrees (0 to 360) or a compass point.",
+        ),
+        None => exit_invalid("Missing --heading DEGREES for the direction of travel."),
+    };
+    let hours: usize = match option_value::<usize>(matches, "hours") {
+        None => 24,
+        Some(value @ 1..=72) => value,
+        Some(_) => exit_invalid("Invalid value for --hours, expected 1 to 72."),
+    };
+    let count: usize = match option_value::<usize>(matches, "count") {
+        None => 5,
+        Some(value) if value > 0 => value,
+        Some(_) => exit_invalid("Invalid value for --count, expected a positive number."),
+    };
+    let weather: WeatherData = fetch_weather(matches, config, hours.div_ceil(24) as u32 + 1)?;
+    let ranked: Vec<tailwind::TailwindHour> = tailwind::best_hours(
+        &weather,
+        heading_deg,
+        hours,
+        !matches.flag("any-time"),
+        count,
     );
 
-    println!("\n▶ Forecast:");
-    for forecast_day in weather.forecast.forecastday {
-        println!(
-            "  - {}: {}°C / {}°F, {} (Precip: {} mm, UV: {})",
-            forecast_day.date,
-            forecast_day.day.maxtemp_c,
-            forecast_day.day.maxtemp_f,
-            forecast_day.day.condition.text,
-            forecast_day.day.totalprecip_mm,
-            forecast_day.day.uv
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = serde_json::json!({
+            "location": weather.location,
+            "heading_deg": heading_deg,
+            "hours": ranked,
+        });
+        write_json(&value, &mut out)
+    } else {
+        render::render_tailwind(
+            &weather,
+            heading_deg,
+            &ranked,
+            units_from(matches, config),
+            &mut out,
+        )
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Writes a value as pretty-printed JSON followed by a newline, with the
+/// `schema_version` of the output.
+fn write_json<W: Write, T: Serialize>(value: &T, out: &mut W) -> std::io::Result<()> {
+    let value: serde_json::Value = schema::versioned(serde_json::to_value(value)?);
+    serde_json::to_writer_pretty(&mut *out, &value)?;
+    writeln!(out)
+}
+
+/// Prints an error about the command-line arguments and exits.
+fn exit_invalid(reason: &str) -> ! {
+    eprintln!("{reason}\nRun with --help for usage.");
+    std::process::exit(error::EXIT_USAGE);
+}
+
+/// Parses an option value, exiting with a message if it does not parse.
+fn option_value<T: std::str::FromStr>(matches: &cli::Matches, name: &str) -> Option<T> {
+    matches
+        .parse_value(name)
+        .unwrap_or_else(|e| exit_invalid(&e.to_string()))
+}
+
+/// Returns the location given on the command line, in the config or saved as the
+/// default, or asks for one. Saved names are replaced by their location, and
+/// `--auto` locates the public IP address.
+fn query_from(matches: &cli::Matches, config: &Config) -> Result<String, WeatherError> {
+    if matches.flag("auto") {
+        if matches.positional().is_some() {
+            exit_invalid("--auto takes no location.");
+        }
+        return Ok(AUTO_IP_QUERY.to_owned());
+    }
+    let favorites: Favorites = load_favorites()?;
+    match matches
+        .positional()
+        .or(config.location.as_deref())
+        .or(favorites.default_query())
+    {
+        Some(query) => {
+            let resolved: &str = favorites.resolve(query)?;
+            if matches.positional() == Some(resolved) {
+                disambiguate(matches, config, resolved)
+            } else {
+                Ok(resolved.to_owned())
+            }
+        }
+        None => disambiguate(matches, config, &get_query_from_user()?),
+    }
+}
+
+/// Picks one place when a typed place name matches several, asking on a terminal
+/// unless `--first` is given.
+///
+/// Only Weather API queries are searched; coordinates, prefixed queries such as
+/// `auto:ip`, and queries whose search fails are kept as typed.
+///
+/// # Returns
+/// The query as typed, or the `id:` query of the chosen place.
+fn disambiguate(
+    matches: &cli::Matches,
+    config: &Config,
+    query: &str,
+) -> Result<String, WeatherError> {
+    let query: &str = query.trim();
+    if provider_name(matches, config) != "weatherapi"
+        || query.contains(':')
+        || !query.chars().any(char::is_alphabetic)
+    {
+        return Ok(query.to_owned());
+    }
+    let Ok(results) = weather_client(config).and_then(|client| client.search(query)) else {
+        return Ok(query.to_owned());
+    };
+    let found: Vec<&SearchResult> = client::candidates(query, &results);
+    if found.len() < 2 {
+        return Ok(query.to_owned());
+    }
+    if matches.flag("first") {
+        return Ok(found[0].id_query());
+    }
+    if !stdin().is_terminal() {
+        eprintln!(
+            "{query} matches {} places, using {}; pass --first to skip this note",
+            found.len(),
+            found[0]
         );
+        return Ok(found[0].id_query());
+    }
+    eprintln!("{query} matches {} places:", found.len());
+    for (number, place) in found.iter().enumerate() {
+        eprintln!("  {}. {place}", number + 1);
+    }
+    eprint!("Pick one [1-{}, Enter for 1]: ", found.len());
+    let mut choice: String = String::new();
+    stdin().read_line(&mut choice).expect("Failed to read line");
+    match choice.trim() {
+        "" => Ok(found[0].id_query()),
+        choice => match choice.parse::<usize>() {
+            Ok(number @ 1..) if number <= found.len() => Ok(found[number - 1].id_query()),
+            _ => exit_invalid(&format!("Invalid choice, expected 1 to {}.", found.len())),
+        },
+    }
+}
+
+/// Reads the saved locations, if any.
+fn load_favorites() -> Result<Favorites, WeatherError> {
+    match Favorites::default_path() {
+        Some(path) => Favorites::load(&path),
+        None => Ok(Favorites::default()),
+    }
+}
+
+/// Returns the number of forecast days requested with `--days` or in the config.
+fn forecast_days(matches: &cli::Matches, config: &Config) -> u32 {
+    match option_value::<u32>(matches, "days") {
+        None => config.days.unwrap_or(FORECAST_DAYS),
+        Some(days @ 1..=14) => days,
+        Some(_) => exit_invalid("Invalid value for --days, expected 1 to 14."),
+    }
+}
+
+/// Returns the unit system requested with `--units` or in the config.
+fn units_from(matches: &cli::Matches, config: &Config) -> units::Units {
+    option_value(matches, "units")
+        .or(config.units)
+        .unwrap_or_default()
+}
+
+/// Returns whether to color values by severity: on a terminal, without
+/// `--no-color` or a non-empty `NO_COLOR` (<https://no-color.org>).
+fn colors_from(matches: &cli::Matches) -> bool {
+    stdout().is_terminal()
+        && !matches.flag("no-color")
+        && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+}
+
+/// Builds the command-line interface.
+fn cli() -> cli::Command {
+    use cli::{Arg, Command};
+
+    let json = || Arg::flag("json", "Print the data as JSON");
+    Command::new("mosm-rs", "A Simple Weather Cli")
+        .positionals("LOCATION")
+        .arg(Arg::option("days", "N", "Forecast days, 1 to 14 (free plan: 3)").global())
+        .arg(Arg::option("units", "SYSTEM", "metric, imperial or both").global())
+        .arg(
+            Arg::option(
+                "lang",
+                "CODE",
+                "Language of condition texts and labels, e.g. de or fr",
+            )
+            .global(),
+        )
+        .arg(
+            Arg::option(
+                "config",
+                "FILE",
+                "Config file (default: ~/.config/mosm/config.toml)",
+            )
+            .global(),
+        )
+        .arg(
+            Arg::option(
+                "provider",
+                "NAME",
+                "weatherapi (default) or open-meteo (no API key)",
+            )
+            .global(),
+        )
+        .arg(
+            Arg::option(
+                "api-key",
+                "KEY",
+                "WeatherAPI key, over WEATHER_API_KEY and `auth set`",
+            )
+            .global(),
+        )
+        .arg(Arg::flag("no-cache", "Always fetch fresh data from the API").global())
+        .arg(
+            Arg::option(
+                "timeout",
+                "SECS",
+                "Seconds a request may take (default: 15)",
+            )
+            .global(),
+        )
+        .arg(Arg::flag("no-color", "Print without colors (also NO_COLOR)").global())
+        .arg(Arg::flag("auto", "Locate your IP address instead of a location").global())
+        .arg(
+            Arg::flag(
+                "place",
+                "Name lat,lon locations after the nearest place and show both",
+            )
+            .global(),
+        )
+        .arg(
+            Arg::flag(
+                "context",
+                "Show the elevation of the location under its name",
+            )
+            .global(),
+        )
+        .arg(Arg::flag(
+            "schema",
+            "Print the JSON Schema of the --format json report",
+        ))
+        .arg(
+            Arg::flag(
+                "strict",
+                "Fail on unknown or missing response fields and on stale cached data",
+            )
+            .global(),
+        )
+        .arg(
+            Arg::flag(
+                "first",
+                "Use the best match when a place name matches several",
+            )
+            .global(),
+        )
+        .arg(json())
+        .arg(Arg::flag("full", "Print the full report even when piped"))
+        .arg(Arg::flag(
+            "alerts-only",
+            "Print only active weather warnings, nothing if none",
+        ))
+        .arg(Arg::option(
+            "format",
+            "FORMAT",
+            "full, summary, json, argos or genmon",
+        ))
+        .arg(Arg::option(
+            "record",
+            "FILE",
+            "Save the fetched response to a session file",
+        ))
+        .arg(Arg::option(
+            "replay",
+            "FILE",
+            "Render a recorded session offline",
+        ))
+        .arg(Arg::flag(
+            "redact",
+            "Hide the resolved place name and coordinates",
+        ))
+        .arg(Arg::option(
+            "label",
+            "NAME",
+            "Name shown instead of the location with --redact",
+        ))
+        .subcommand(
+            Command::new("current", "Current conditions")
+                .positional("LOCATION")
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("forecast", "Daily forecast")
+                .positional("LOCATION")
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("astro", "Sunrise, sunset and moon")
+                .positional("LOCATION")
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("aqi", "Air quality: pollutants, indices and health advice")
+                .positional("LOCATION")
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("hourly", "Hour-by-hour forecast")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "hours",
+                    "N",
+                    "Number of hours to show (default: 24)",
+                ))
+                .arg(Arg::option(
+                    "day",
+                    "DAY",
+                    "Forecast day from midnight: 0 = today, 1 = tomorrow, or YYYY-MM-DD",
+                ))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("sunpos", "Sun azimuth, elevation and shadow length")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "at",
+                    "TIME",
+                    "Local time, HH:MM today or \"YYYY-MM-DD HH:MM\" (default: now)",
+                ))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("fasting", "Suhoor and iftar (dawn to sunset) times")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "dawn-angle",
+                    "DEGREES",
+                    "Sun depression at dawn (default: 18)",
+                ))
+                .arg(Arg::flag("generic", "Label the times dawn and dusk"))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new(
+                "schedule",
+                "Daily dawn, sunrise, solar noon, sunset and dusk times",
+            )
+            .positional("LOCATION")
+            .arg(Arg::option(
+                "twilight",
+                "KIND",
+                "civil (default), nautical or astronomical",
+            ))
+            .arg(Arg::flag("ics", "Print an iCalendar file"))
+            .arg(json()),
+        )
+        .subcommand(
+            Command::new(
+                "locations",
+                "Saved locations: list, add NAME LOCATION, import FILE, remove NAME, default NAME",
+            )
+            .positionals("ARGS")
+            .arg(Arg::option(
+                "from",
+                "FORMAT",
+                "Format of the import FILE: wttr, owm-cli or csv",
+            ))
+            .arg(json()),
+        )
+        .subcommand(
+            Command::new("quakes", "Recent earthquakes nearby (USGS)")
+                .positional("LOCATION")
+                .arg(Arg::option("radius", "KM", "Search radius (default: 300)"))
+                .arg(Arg::option(
+                    "min-magnitude",
+                    "MAG",
+                    "Minimum magnitude (default: 2.5)",
+                ))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("storms", "Active tropical cyclones nearby (NHC)")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "radius",
+                    "KM",
+                    "Show forecast track points within this radius (default: 1000)",
+                ))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("aurora", "Aurora visibility tonight (NOAA SWPC)")
+                .positional("LOCATION")
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("event", "Go/no-go verdict for an outdoor event")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "at",
+                    "\"YYYY-MM-DD HH:MM\"",
+                    "Local start time",
+                ))
+                .arg(Arg::option(
+                    "duration",
+                    "DURATION",
+                    "e.g. 3h, 90m, 1h30m (default: 2h)",
+                )),
+        )
+        .subcommand(
+            Command::new("commute", "Conditions at departure and return times")
+                .positional("LOCATION")
+                .arg(Arg::option("leave", "HH:MM", "Departure time"))
+                .arg(Arg::option("return", "HH:MM", "Return time")),
+        )
+        .subcommand(
+            Command::new("walk", "Next dry, calm windows for a walk")
+                .positional("LOCATION")
+                .arg(Arg::option("count", "N", "Number of windows (default: 3)"))
+                .arg(Arg::option(
+                    "min-duration",
+                    "DURATION",
+                    "Minimum length (default: 1h)",
+                ))
+                .arg(Arg::option("max-wind", "KPH", "Maximum wind (default: 20)"))
+                .arg(Arg::option(
+                    "max-rain",
+                    "PERCENT",
+                    "Maximum chance of rain (default: 20)",
+                ))
+                .arg(Arg::flag("any-time", "Include night hours")),
+        )
+        .subcommand(
+            Command::new("ventilate", "Hours left today to open the windows")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "indoor-temp",
+                    "C",
+                    "Indoor temperature to keep (default: 22)",
+                ))
+                .arg(Arg::option(
+                    "indoor-humidity",
+                    "PERCENT",
+                    "Indoor humidity to keep (default: 50)",
+                ))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("tailwind", "Hours with the best tailwind for a ride or run")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "heading",
+                    "DEGREES",
+                    "Direction of travel, in degrees or as a compass point (W)",
+                ))
+                .arg(Arg::option(
+                    "hours",
+                    "N",
+                    "Hours ahead, 1 to 72 (default: 24)",
+                ))
+                .arg(Arg::option(
+                    "count",
+                    "N",
+                    "Number of hours shown (default: 5)",
+                ))
+                .arg(Arg::flag("any-time", "Include night hours"))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new("drone", "Hourly GO/CAUTION/NO-GO for drone flights")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "hours",
+                    "N",
+                    "Hours ahead, 1 to 48 (default: 12)",
+                ))
+                .arg(Arg::option("max-wind", "KPH", "Maximum wind (default: 30)"))
+                .arg(Arg::option(
+                    "max-gust",
+                    "KPH",
+                    "Maximum gusts (default: 38)",
+                ))
+                .arg(Arg::option(
+                    "max-precip",
+                    "MM",
+                    "Maximum precipitation per hour (default: 0)",
+                ))
+                .arg(Arg::option(
+                    "min-visibility",
+                    "KM",
+                    "Minimum visibility (default: 3)",
+                ))
+                .arg(Arg::option(
+                    "min-temp",
+                    "C",
+                    "Minimum temperature (default: 0)",
+                ))
+                .arg(Arg::option(
+                    "max-temp",
+                    "C",
+                    "Maximum temperature (default: 40)",
+                ))
+                .arg(Arg::option("max-kp", "KP", "Maximum Kp index (default: 5)"))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new(
+                "jobsite",
+                "Outdoor work safety per morning, afternoon and evening",
+            )
+            .positional("LOCATION")
+            .arg(Arg::option(
+                "trade",
+                "NAME",
+                "general (default), crane, roofing or a [trade.NAME] config table",
+            ))
+            .arg(json()),
+        )
+        .subcommand(
+            Command::new("frost", "Overnight frost stages for field locations")
+                .positionals("LOCATION")
+                .arg(Arg::option(
+                    "advisory",
+                    "C",
+                    "Minimum starting the advisory stage (default: 3)",
+                ))
+                .arg(Arg::option(
+                    "warning",
+                    "C",
+                    "Minimum starting the warning stage (default: 0)",
+                ))
+                .arg(Arg::option(
+                    "critical",
+                    "C",
+                    "Minimum starting the critical stage (default: -2)",
+                ))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new(
+                "energy",
+                "Day-ahead electricity prices with temperature and sunshine",
+            )
+            .positional("LOCATION")
+            .arg(Arg::option(
+                "source",
+                "NAME",
+                "awattar-de (default) or awattar-at",
+            ))
+            .arg(Arg::option(
+                "hours",
+                "N",
+                "Hours ahead, 1 to 48 (default: 24)",
+            ))
+            .arg(json()),
+        )
+        .subcommand(
+            Command::new("history", "Weather on a past date beside today's")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "date",
+                    "YYYY-MM-DD",
+                    "Past date; the free plan covers the last 7 days",
+                ))
+                .arg(json()),
+        )
+        .subcommand(
+            Command::new(
+                "future",
+                "Long-range forecast of a date 14 to 300 days ahead",
+            )
+            .positional("LOCATION")
+            .arg(Arg::option(
+                "date",
+                "YYYY-MM-DD",
+                "Date 14 to 300 days ahead; needs a paid plan",
+            ))
+            .arg(json()),
+        )
+        .subcommand(
+            Command::new(
+                "doctor",
+                "Check the setup and compare a live response with the model",
+            )
+            .positional("LOCATION"),
+        )
+        .subcommand(
+            Command::new(
+                "auth",
+                "WeatherAPI key: set [KEY] (asked if omitted), show, remove",
+            )
+            .positionals("ARGS"),
+        )
+        .subcommand(
+            Command::new("share", "Short text for SMS or messengers")
+                .positional("LOCATION")
+                .arg(Arg::flag(
+                    "link",
+                    "Append the location's weatherapi.com page, or a map of it",
+                )),
+        )
+        .subcommand(
+            Command::new("heatmap", "Hourly heatmap across forecast days")
+                .positional("LOCATION")
+                .arg(Arg::option(
+                    "metric",
+                    "METRIC",
+                    "temp, rain, wind, humidity or uv",
+                )),
+        )
+        .subcommand(
+            Command::new(
+                "chart",
+                "Line chart of the hourly temperature (--days 1 to 3)",
+            )
+            .positional("LOCATION"),
+        )
+}
+
+/// Prints a section of the weather data (current, forecast, astro or aqi).
+///
+/// # Arguments
+/// * `matches` - Parsed arguments of the subcommand.
+/// * `section` - Name of the subcommand selecting the section.
+fn run_section(matches: &cli::Matches, config: &Config, section: &str) -> Result<(), WeatherError> {
+    let units: units::Units = units_from(matches, config);
+    let colors: bool = colors_from(matches);
+    let weather: WeatherData = fetch_weather(matches, config, forecast_days(matches, config))?;
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        let value: serde_json::Value = match section {
+            "current" => serde_json::json!({
+                "location": weather.location,
+                "current": weather.current,
+            }),
+            "forecast" => serde_json::json!({
+                "location": weather.location,
+                "forecast": weather.forecast,
+            }),
+            "astro" => serde_json::json!({
+                "location": weather.location,
+                "daylight": sun::daylight_trend(&weather.location),
+                "astro": weather
+                    .forecast
+                    .forecastday
+                    .iter()
+                    .map(|forecast_day| serde_json::json!({
+                        "date": forecast_day.date,
+                        "astro": forecast_day.astro,
+                    }))
+                    .collect::<Vec<_>>(),
+            }),
+            _ => serde_json::json!({
+                "location": weather.location,
+                "air_quality": weather.current.air_quality,
+            }),
+        };
+        write_json(&value, &mut out)
+            .and_then(|()| out.flush())
+            .expect("Failed to write report");
+        return Ok(());
+    }
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")
+        .and_then(|()| render::render_header(&weather, &mut out))
+        .and_then(|()| match section {
+            "current" => render::render_current(&weather, units, colors, &mut out),
+            "forecast" => render::render_forecast(&weather, units, colors, &mut out)
+                .and_then(|()| render::render_snow(&weather, units, &mut out)),
+            "astro" => render::render_astro(&weather, &mut out),
+            _ => render::render_aqi(weather.current.air_quality.as_ref(), colors, &mut out),
+        })
+        .and_then(|()| writeln!(out, "<>{:-<REPORT_WIDTH$}<>", ""))
+        .and_then(|()| out.flush())
+        .expect("Failed to write report");
+    Ok(())
+}
+
+/// Prints a comparison table of the locations given on the command line.
+///
+/// The forecasts are fetched concurrently; locations that fail are reported and
+/// left out of the table.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments with two or more locations.
+fn run_compare(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    if matches.value("record").is_some() || matches.value("replay").is_some() {
+        exit_invalid("--record and --replay take a single location.");
+    }
+    let days: u32 = forecast_days(matches, config);
+    let favorites: Favorites = load_favorites()?;
+    let queries: Vec<&str> = matches
+        .positionals()
+        .iter()
+        .map(|query| favorites.resolve(query))
+        .collect::<Result<_, _>>()?;
+    let weathers: Vec<WeatherData> = fetch_all(matches, config, &queries, days)?;
+
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("json") {
+        write_json(&weathers, &mut out)
+    } else {
+        render::render_compare(&weathers, units_from(matches, config), &mut out)
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Fetches the forecasts of several locations concurrently.
+///
+/// Locations that fail are reported and left out.
+///
+/// # Returns
+/// The forecasts in the order of `queries`, or the first error if none could be fetched.
+fn fetch_all(
+    matches: &cli::Matches,
+    config: &Config,
+    queries: &[&str],
+    days: u32,
+) -> Result<Vec<WeatherData>, WeatherError> {
+    let results: Vec<Result<WeatherData, WeatherError>> = std::thread::scope(|scope| {
+        let handles: Vec<_> = queries
+            .iter()
+            .map(|query| {
+                scope.spawn(move || -> Result<WeatherData, WeatherError> {
+                    let mut weather: WeatherData =
+                        serde_json::from_value(fetch_response(matches, config, query, days)?)?;
+                    enrich_weather(matches, config, query, &mut weather);
+                    Ok(weather)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("fetch thread panicked"))
+            .collect()
+    });
+    let mut weathers: Vec<WeatherData> = Vec::new();
+    let mut first_error: Option<WeatherError> = None;
+    for (query, result) in queries.iter().zip(results) {
+        match result {
+            Ok(weather) => weathers.push(weather),
+            Err(e) => {
+                eprintln!("Error: {query}: {e}");
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+    if let (true, Some(e)) = (weathers.is_empty(), first_error) {
+        return Err(e);
+    }
+    Ok(weathers)
+}
+
+/// Prints the full weather report, or the applet/summary/JSON output.
+///
+/// # Arguments
+/// * `matches` - Parsed arguments without a subcommand.
+fn run_report(matches: &cli::Matches, config: &Config) -> Result<(), WeatherError> {
+    let units: units::Units = units_from(matches, config);
+    let days: u32 = forecast_days(matches, config);
+    let format: Option<&str> = if matches.flag("json") {
+        Some("json")
+    } else if matches.flag("full") {
+        Some("full")
+    } else {
+        matches.value("format").or(config.format.as_deref())
+    };
+    if let Some(name) = format
+        && !config::FORMATS.contains(&name)
+        && applet::AppletFormat::from_name(name).is_none()
+    {
+        exit_invalid(&format!(
+            "Invalid value for --format, expected {}.",
+            config::FORMATS.join(", ")
+        ));
+    }
+    let (query, mut weather): (String, WeatherData) = match matches.value("replay") {
+        Some(path) => {
+            let session: session::Session = session::Session::load(path).unwrap_or_else(|e| {
+                eprintln!("Failed to read session {path}: {e}");
+                std::process::exit(error::EXIT_USAGE);
+            });
+            (session.query, serde_json::from_value(session.response)?)
+        }
+        None => {
+            let query: String = query_from(matches, config)?;
+            let response: serde_json::Value = fetch_response(matches, config, &query, days)?;
+            if let Some(path) = matches.value("record") {
+                session::Session::new(&query, days, response.clone())
+                    .save(path)
+                    .unwrap_or_else(|e| eprintln!("Failed to record session {path}: {e}"));
+            }
+            (query, serde_json::from_value(response)?)
+        }
+    };
+    enrich_weather(matches, config, &query, &mut weather);
+    if matches.flag("redact") {
+        weather
+            .location
+            .redact(matches.value("label").unwrap_or("Redacted location"));
+    }
+    let mut out = BufWriter::new(stdout().lock());
+    if matches.flag("alerts-only") {
+        if format == Some("json") {
+            write_json(&weather.alerts.alert, &mut out)
+        } else {
+            render::render_alerts(&weather.alerts.alert, &mut out)
+        }
+        .and_then(|()| out.flush())
+        .expect("Failed to write report");
+        return Ok(());
+    }
+    // Pipes and redirects get a single stable line unless another format is requested.
+    match format {
+        Some("json") => write_json(&weather, &mut out),
+        Some("full") => render::render_report(
+            &weather,
+            units,
+            REPORT_WIDTH,
+            colors_from(matches),
+            &mut out,
+        ),
+        Some("summary") => render::render_summary_line(&weather, units, &mut out),
+        Some(name) => {
+            let applet_format: applet::AppletFormat =
+                applet::AppletFormat::from_name(name).expect("applet format validated above");
+            applet::render(&weather, applet_format, units, &mut out)
+        }
+        None if stdout().is_terminal() => render::render_report(
+            &weather,
+            units,
+            REPORT_WIDTH,
+            colors_from(matches),
+            &mut out,
+        ),
+        None => render::render_summary_line(&weather, units, &mut out),
+    }
+    .and_then(|()| out.flush())
+    .expect("Failed to write report");
+    Ok(())
+}
+
+/// Main function to run the weather application.
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let matches: cli::Matches = match cli().parse(&args) {
+        Ok(matches) => matches,
+        Err(cli::CliError::Help(help)) => {
+            print!("{help}");
+            return;
+        }
+        Err(cli::CliError::Invalid(reason)) => exit_invalid(&reason),
+    };
+    let config: Config = load_config(&matches).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        std::process::exit(e.exit_code());
+    });
+    if matches.flag("schema") {
+        print!("{}", schema::REPORT_SCHEMA);
+        return;
+    }
+    configure_http(&matches, &config);
+    if let Some(lang) = &config.lang {
+        i18n::configure(lang);
+    }
+    let result: Result<(), WeatherError> = match matches.subcommand {
+        None if matches.positionals().len() > 1 => run_compare(&matches, &config),
+        None => run_report(&matches, &config),
+        Some(section @ ("current" | "forecast" | "astro" | "aqi")) => {
+            run_section(&matches, &config, section)
+        }
+        Some("hourly") => run_hourly(&matches, &config),
+        Some("sunpos") => run_sunpos(&matches, &config),
+        Some("fasting") => run_fasting(&matches, &config),
+        Some("schedule") => run_schedule(&matches, &config),
+        Some("locations") => run_locations(&matches, &config),
+        Some("quakes") => run_quakes(&matches, &config),
+        Some("storms") => run_storms(&matches, &config),
+        Some("aurora") => run_aurora(&matches, &config),
+        Some("event") => run_event(&matches, &config),
+        Some("commute") => run_commute(&matches, &config),
+        Some("walk") => run_walk(&matches, &config),
+        Some("drone") => run_drone(&matches, &config),
+        Some("jobsite") => run_jobsite(&matches, &config),
+        Some("frost") => run_frost(&matches, &config),
+        Some("tailwind") => run_tailwind(&matches, &config),
+        Some("ventilate") => run_ventilate(&matches, &config),
+        Some("energy") => run_energy(&matches, &config),
+        Some("history") => run_history(&matches, &config),
+        Some("future") => run_future(&matches, &config),
+        Some("doctor") => run_doctor(&matches, &config),
+        Some("auth") => run_auth(&matches, &config),
+        Some("share") => run_share(&matches, &config),
+        Some("heatmap") => run_heatmap(&matches, &config),
+        Some("chart") => run_chart(&matches, &config),
+        Some(other) => unreachable!("subcommand {other} is declared but not handled"),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {e}");
+        std::process::exit(e.exit_code());
     }
-    println!("<>{}<>", "-".repeat(70));
 }
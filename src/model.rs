@@ -0,0 +1,418 @@
+use serde::{Deserialize, Serialize};
+
+/// Air quality data for a location, including pollutant levels and indices.
+///
+/// Pollutants missing from the response are 0.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct AirQuality {
+    pub co: f64,
+    pub no2: f64,
+    pub o3: f64,
+    pub so2: f64,
+    pub pm2_5: f64,
+    pub pm10: f64,
+    /// US EPA Air Quality Index (1-6).
+    #[serde(rename = "us-epa-index")]
+    pub us_epa_index: i32,
+    /// UK DEFRA Air Quality Index.
+    #[serde(rename = "gb-defra-index")]
+    pub gb_defra_index: i32,
+}
+
+/// Weather condition details.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Condition {
+    pub text: String,
+    #[serde(default)]
+    pub icon: String,
+    pub code: i32,
+}
+
+/// Current weather data for a location.
+///
+/// Optional readings are missing from some plans and responses, and are `None`
+/// rather than a made-up 0. Other defaulted fields read as 0.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Current {
+    pub last_updated_epoch: i32,
+    pub last_updated: String,
+    pub temp_c: f64,
+    pub temp_f: f64,
+    pub is_day: i32,
+    pub condition: Condition,
+    pub wind_mph: f64,
+    pub wind_kph: f64,
+    pub wind_degree: i32,
+    pub wind_dir: String,
+    pub pressure_mb: f64,
+    pub pressure_in: f64,
+    pub precip_mm: f64,
+    pub precip_in: f64,
+    pub humidity: i32,
+    pub cloud: i32,
+    pub feelslike_c: f64,
+    pub feelslike_f: f64,
+    pub windchill_c: Option<f64>,
+    pub windchill_f: Option<f64>,
+    pub heatindex_c: Option<f64>,
+    pub heatindex_f: Option<f64>,
+    pub dewpoint_c: Option<f64>,
+    pub dewpoint_f: Option<f64>,
+    pub vis_km: Option<f64>,
+    pub vis_miles: Option<f64>,
+    pub uv: Option<f64>,
+    pub gust_mph: Option<f64>,
+    pub gust_kph: Option<f64>,
+    /// Present only when requested with `aqi=yes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub air_quality: Option<AirQuality>,
+    #[serde(default)]
+    pub short_rad: f64,
+    #[serde(default)]
+    pub diff_rad: f64,
+    #[serde(default)]
+    pub dni: f64,
+    #[serde(default)]
+    pub gti: f64,
+}
+
+/// Location data for weather information.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Location {
+    pub name: String,
+    pub region: String,
+    pub country: String,
+    pub lat: f64,
+    pub lon: f64,
+    #[serde(default)]
+    pub tz_id: String,
+    pub localtime_epoch: i32,
+    pub localtime: String,
+    /// Set by the CLI for coordinate queries named with `--place`, to print the
+    /// coordinates beside the name. Not part of the API response.
+    #[serde(skip)]
+    pub show_coordinates: bool,
+    /// Ground elevation (m), set by the CLI with `--context`. Not part of the API
+    /// response.
+    #[serde(skip)]
+    pub elevation_m: Option<f64>,
+}
+
+impl Location {
+    /// Returns the place name followed by the country, if known.
+    pub fn short_name(&self) -> String {
+        if self.country.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}, {}", self.name, self.country)
+        }
+    }
+
+    /// Replaces every identifying detail with a user-supplied label.
+    pub fn redact(&mut self, label: &str) {
+        self.name = label.to_owned();
+        self.region.clear();
+        self.country.clear();
+        self.tz_id.clear();
+        self.lat = 0.0;
+        self.lon = 0.0;
+        self.show_coordinates = false;
+        self.elevation_m = None;
+    }
+}
+
+impl std::fmt::Display for Location {
+    /// Formats the location as `Name (Region, Country)`, skipping empty parts.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let details: Vec<&str> = [self.region.as_str(), self.country.as_str()]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect();
+        if details.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{} ({})", self.name, details.join(", "))
+        }
+    }
+}
+
+/// Daily weather forecast data.
+///
+/// Defaulted fields are missing from some plans and responses, and read as 0.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Day {
+    pub maxtemp_c: f64,
+    pub maxtemp_f: f64,
+    pub mintemp_c: f64,
+    pub mintemp_f: f64,
+    pub avgtemp_c: f64,
+    pub avgtemp_f: f64,
+    pub maxwind_mph: f64,
+    pub maxwind_kph: f64,
+    pub totalprecip_mm: f64,
+    pub totalprecip_in: f64,
+    #[serde(default)]
+    pub totalsnow_cm: f64,
+    #[serde(default)]
+    pub avgvis_km: f64,
+    #[serde(default)]
+    pub avgvis_miles: f64,
+    pub avghumidity: u32,
+    pub daily_will_it_rain: i32,
+    pub daily_chance_of_rain: i32,
+    #[serde(default)]
+    pub daily_will_it_snow: i32,
+    #[serde(default)]
+    pub daily_chance_of_snow: i32,
+    pub condition: Condition,
+    #[serde(default)]
+    pub uv: f64,
+    /// Present only when requested with `aqi=yes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub air_quality: Option<AirQuality>,
+}
+
+/// Astronomical data for a specific day.
+///
+/// Defaulted fields are missing from some plans and responses, and read as 0.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Astro {
+    pub sunrise: String,
+    pub sunset: String,
+    pub moonrise: String,
+    pub moonset: String,
+    pub moon_phase: String,
+    #[serde(default)]
+    pub moon_illumination: u32,
+    #[serde(default)]
+    pub is_moon_up: i32,
+    #[serde(default)]
+    pub is_sun_up: i32,
+}
+
+/// Hourly weather forecast data.
+///
+/// Optional readings are missing from some plans and responses, and are `None`
+/// rather than a made-up 0. Other defaulted fields read as 0.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Hour {
+    pub time_epoch: i64,
+    pub time: String,
+    pub temp_c: f64,
+    pub temp_f: f64,
+    pub is_day: i32,
+    pub condition: Condition,
+    pub wind_mph: f64,
+    pub wind_kph: f64,
+    pub wind_degree: i32,
+    pub wind_dir: String,
+    pub pressure_mb: f64,
+    pub pressure_in: f64,
+    pub precip_mm: f64,
+    pub precip_in: f64,
+    #[serde(default)]
+    pub snow_cm: f64,
+    pub humidity: i32,
+    pub cloud: i32,
+    pub feelslike_c: f64,
+    pub feelslike_f: f64,
+    pub windchill_c: Option<f64>,
+    pub windchill_f: Option<f64>,
+    pub heatindex_c: Option<f64>,
+    pub heatindex_f: Option<f64>,
+    pub dewpoint_c: Option<f64>,
+    pub dewpoint_f: Option<f64>,
+    pub will_it_rain: i32,
+    pub chance_of_rain: i32,
+    #[serde(default)]
+    pub will_it_snow: i32,
+    #[serde(default)]
+    pub chance_of_snow: i32,
+    pub vis_km: Option<f64>,
+    pub vis_miles: Option<f64>,
+    pub gust_kph: Option<f64>,
+    pub gust_mph: Option<f64>,
+    pub uv: Option<f64>,
+    /// Present only when requested with `aqi=yes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub air_quality: Option<AirQuality>,
+    #[serde(default)]
+    pub short_rad: f64,
+    #[serde(default)]
+    pub diff_rad: f64,
+    #[serde(default)]
+    pub dni: f64,
+    #[serde(default)]
+    pub gti: f64,
+}
+
+/// Single day's forecast data.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ForecastDay {
+    /// Date of the forecast (YYYY-MM-DD).
+    pub date: String,
+    /// UNIX timestamp of the date.
+    pub date_epoch: i64,
+    /// Daily weather summary.
+    pub day: Day,
+    /// Astronomical data for the day.
+    pub astro: Astro,
+    /// Hourly forecast data.
+    pub hour: Vec<Hour>,
+}
+
+/// Weather forecast data for multiple days.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Forecast {
+    /// List of daily forecasts.
+    pub forecastday: Vec<ForecastDay>,
+}
+
+/// Government weather warning issued for the location.
+///
+/// Fields the issuing agency leaves out are empty.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(default)]
+pub struct Alert {
+    pub headline: String,
+    /// `Alert`, `Update` or `Cancel`.
+    pub msgtype: String,
+    /// `Minor`, `Moderate`, `Severe`, `Extreme` or `Unknown`.
+    pub severity: String,
+    pub urgency: String,
+    /// Affected areas, separated by semicolons.
+    pub areas: String,
+    pub category: String,
+    pub certainty: String,
+    /// Type of hazard, e.g. `Yellow Thunderstorm Warning`.
+    pub event: String,
+    pub note: String,
+    /// Start of validity (ISO 8601 with offset).
+    pub effective: String,
+    /// End of validity (ISO 8601 with offset).
+    pub expires: String,
+    pub desc: String,
+    pub instruction: String,
+}
+
+/// Active weather warnings.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Alerts {
+    #[serde(default)]
+    pub alert: Vec<Alert>,
+}
+
+/// Weather data combining location and current conditions.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct WeatherData {
+    /// Location details.
+    pub location: Location,
+    /// Current weather conditions.
+    pub current: Current,
+    /// Weather forecast data.
+    pub forecast: Forecast,
+    /// Active weather warnings, empty if the provider has none.
+    #[serde(default)]
+    pub alerts: Alerts,
+}
+
+/// Weather observed at a location on a past date, from the history endpoint.
+///
+/// The response has the shape of a forecast without current conditions: one
+/// forecast day with the observed daily summary and hours.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HistoryData {
+    /// Location details.
+    pub location: Location,
+    /// The observed day.
+    pub forecast: Forecast,
+}
+
+impl HistoryData {
+    /// Returns the observed day, if the response has one.
+    pub fn day(&self) -> Option<&ForecastDay> {
+        self.forecast.forecastday.first()
+    }
+}
+
+/// Long-range forecast of a date 14 to 300 days ahead, from the future endpoint.
+///
+/// Unlike [`HistoryData`], days hold only a summary: the endpoint has no rain or
+/// snow chances, and its hours are not kept.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FutureData {
+    /// Location details.
+    pub location: Location,
+    /// The forecast day.
+    pub forecast: FutureForecast,
+}
+
+impl FutureData {
+    /// Returns the forecast day, if the response has one.
+    pub fn day(&self) -> Option<&FutureDay> {
+        self.forecast.forecastday.first()
+    }
+}
+
+/// Days of a long-range forecast.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FutureForecast {
+    pub forecastday: Vec<FutureDay>,
+}
+
+/// Single day of a long-range forecast.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FutureDay {
+    /// Date of the forecast (YYYY-MM-DD).
+    pub date: String,
+    /// UNIX timestamp of the date.
+    pub date_epoch: i64,
+    /// Daily weather summary.
+    pub day: FutureSummary,
+    /// Astronomical data for the day.
+    pub astro: Astro,
+}
+
+/// Daily summary of a long-range forecast.
+///
+/// Defaulted fields are missing from some plans and responses, and read as 0.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FutureSummary {
+    pub maxtemp_c: f64,
+    pub maxtemp_f: f64,
+    pub mintemp_c: f64,
+    pub mintemp_f: f64,
+    pub avgtemp_c: f64,
+    pub avgtemp_f: f64,
+    pub maxwind_mph: f64,
+    pub maxwind_kph: f64,
+    pub totalprecip_mm: f64,
+    pub totalprecip_in: f64,
+    #[serde(default)]
+    pub avgvis_km: f64,
+    #[serde(default)]
+    pub avgvis_miles: f64,
+    pub avghumidity: u32,
+    pub condition: Condition,
+    #[serde(default)]
+    pub uv: f64,
+}
+
+impl WeatherData {
+    /// Iterates over the hourly forecast of every forecast day, in order.
+    pub fn hours(&self) -> impl Iterator<Item = &Hour> {
+        self.forecast
+            .forecastday
+            .iter()
+            .flat_map(|forecast_day| forecast_day.hour.iter())
+    }
+
+    /// Returns the forecast hour containing a local time (`YYYY-MM-DD HH:MM`).
+    pub fn hour_at(&self, time: &str) -> Option<&Hour> {
+        // Hour times share the same format, so the hour containing `time` is the
+        // one with the same `YYYY-MM-DD HH` prefix.
+        self.hours()
+            .find(|hour| hour.time.get(..13) == time.get(..13))
+    }
+}
@@ -0,0 +1,784 @@
+use serde::Deserialize;
+#[cfg(feature = "blocking")]
+use std::sync::OnceLock;
+
+use crate::geo;
+use crate::time::local_epoch;
+use crate::{
+    Alerts, Astro, Condition, Current, Day, Forecast, ForecastDay, Hour, Location, WeatherData,
+    WeatherError,
+};
+
+/// Hourly variables requested from the forecast endpoint.
+#[cfg(feature = "blocking")]
+const HOURLY_VARIABLES: &str = "temperature_2m,relative_humidity_2m,dew_point_2m,\
+    apparent_temperature,precipitation_probability,precipitation,snowfall,weather_code,\
+    pressure_msl,cloud_cover,visibility,wind_speed_10m,wind_direction_10m,wind_gusts_10m,\
+    uv_index,is_day,shortwave_radiation,diffuse_radiation,direct_normal_irradiance,\
+    global_tilted_irradiance";
+
+/// Current variables requested; the others are taken from the hour in progress.
+#[cfg(feature = "blocking")]
+const CURRENT_VARIABLES: &str = "temperature_2m,relative_humidity_2m,apparent_temperature,\
+    is_day,precipitation,weather_code,cloud_cover,pressure_msl,wind_speed_10m,\
+    wind_direction_10m,wind_gusts_10m";
+
+/// Daily variables requested from the forecast endpoint.
+#[cfg(feature = "blocking")]
+const DAILY_VARIABLES: &str = "weather_code,temperature_2m_max,temperature_2m_min,sunrise,sunset,\
+    uv_index_max,precipitation_sum,snowfall_sum,wind_speed_10m_max";
+
+/// Place returned by the Open-Meteo geocoding endpoint.
+#[derive(Deserialize, Debug)]
+pub struct Place {
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    #[serde(default)]
+    pub country: String,
+    /// First-level administrative area (state, region...).
+    #[serde(default)]
+    pub admin1: String,
+}
+
+/// Response of the geocoding endpoint.
+#[cfg(feature = "blocking")]
+#[derive(Deserialize, Debug)]
+struct Geocoding {
+    #[serde(default)]
+    results: Vec<Place>,
+}
+
+/// Response of the IP geolocation service (ipapi.co).
+#[cfg(feature = "blocking")]
+#[derive(Deserialize, Debug)]
+struct IpLocation {
+    #[serde(default)]
+    city: String,
+    #[serde(default)]
+    region: String,
+    #[serde(default)]
+    country_name: String,
+    latitude: f64,
+    longitude: f64,
+}
+
+/// Response of the forecast endpoint, with local ISO 8601 times (`2025-08-01T14:00`).
+#[derive(Deserialize, Debug)]
+struct Response {
+    timezone: String,
+    utc_offset_seconds: i64,
+    current: CurrentValues,
+    hourly: HourlyValues,
+    daily: DailyValues,
+}
+
+#[derive(Deserialize, Debug)]
+struct CurrentValues {
+    time: String,
+    temperature_2m: f64,
+    relative_humidity_2m: f64,
+    apparent_temperature: f64,
+    is_day: f64,
+    precipitation: f64,
+    weather_code: f64,
+    cloud_cover: f64,
+    pressure_msl: f64,
+    wind_speed_10m: f64,
+    wind_direction_10m: f64,
+    wind_gusts_10m: f64,
+}
+
+/// Hourly series; values are `null` where the models have no data.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct HourlyValues {
+    time: Vec<String>,
+    temperature_2m: Vec<Option<f64>>,
+    relative_humidity_2m: Vec<Option<f64>>,
+    dew_point_2m: Vec<Option<f64>>,
+    apparent_temperature: Vec<Option<f64>>,
+    precipitation_probability: Vec<Option<f64>>,
+    precipitation: Vec<Option<f64>>,
+    snowfall: Vec<Option<f64>>,
+    weather_code: Vec<Option<f64>>,
+    pressure_msl: Vec<Option<f64>>,
+    cloud_cover: Vec<Option<f64>>,
+    visibility: Vec<Option<f64>>,
+    wind_speed_10m: Vec<Option<f64>>,
+    wind_direction_10m: Vec<Option<f64>>,
+    wind_gusts_10m: Vec<Option<f64>>,
+    uv_index: Vec<Option<f64>>,
+    is_day: Vec<Option<f64>>,
+    shortwave_radiation: Vec<Option<f64>>,
+    diffuse_radiation: Vec<Option<f64>>,
+    direct_normal_irradiance: Vec<Option<f64>>,
+    global_tilted_irradiance: Vec<Option<f64>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct DailyValues {
+    time: Vec<String>,
+    weather_code: Vec<Option<f64>>,
+    temperature_2m_max: Vec<Option<f64>>,
+    temperature_2m_min: Vec<Option<f64>>,
+    sunrise: Vec<String>,
+    sunset: Vec<String>,
+    uv_index_max: Vec<Option<f64>>,
+    precipitation_sum: Vec<Option<f64>>,
+    snowfall_sum: Vec<Option<f64>>,
+    wind_speed_10m_max: Vec<Option<f64>>,
+}
+
+/// Client for Open-Meteo (open-meteo.com), which needs no API key.
+///
+/// Forecasts are converted to the WeatherAPI structures, so every renderer works
+/// unchanged. Open-Meteo has no air quality in its forecast and no moonrise or
+/// moonset; those are left empty and the moon phase is computed from the date.
+#[derive(Debug, Default)]
+pub struct OpenMeteoClient {
+    lang: Option<String>,
+    #[cfg(feature = "blocking")]
+    http: OnceLock<reqwest::blocking::Client>,
+}
+
+impl OpenMeteoClient {
+    /// Creates a client.
+    pub fn new() -> OpenMeteoClient {
+        OpenMeteoClient::default()
+    }
+
+    /// Looks up place names in another language (e.g. `fr`, `de`, `zh`).
+    pub fn lang(mut self, lang: impl Into<String>) -> OpenMeteoClient {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Returns the blocking HTTP client, building it on first use.
+    #[cfg(feature = "blocking")]
+    fn http(&self) -> Result<&reqwest::blocking::Client, WeatherError> {
+        if self.http.get().is_none() {
+            let _ = self.http.set(crate::http::client()?);
+        }
+        Ok(self.http.get().expect("HTTP client initialized above"))
+    }
+
+    /// Fetches a URL and returns its body, mapping errors to `WeatherError`.
+    #[cfg(feature = "blocking")]
+    fn get(&self, url: reqwest::Url) -> Result<String, WeatherError> {
+        let response: reqwest::blocking::Response = crate::http::send(self.http()?.get(url))?;
+        let status: u16 = response.status().as_u16();
+        let body: String = response.text()?;
+        if status != 200 {
+            // Errors come as `{"error": true, "reason": "..."}`.
+            let message: Option<String> = serde_json::from_str::<serde_json::Value>(&body)
+                .ok()
+                .and_then(|value| value.get("reason")?.as_str().map(str::to_owned));
+            return Err(WeatherError::Status { status, message });
+        }
+        Ok(body)
+    }
+
+    /// Resolves a location query: `lat,lon`, a place name, or
+    /// [`AUTO_IP_QUERY`](crate::client::AUTO_IP_QUERY) to locate the public IP address.
+    ///
+    /// # Returns
+    /// The best matching `Place`, or `WeatherError::InvalidQuery` if none matches.
+    #[cfg(feature = "blocking")]
+    pub fn geocode(&self, query: &str) -> Result<Place, WeatherError> {
+        let query: &str = query.trim();
+        if query.is_empty() {
+            return Err(WeatherError::InvalidQuery(
+                "no location provided".to_owned(),
+            ));
+        }
+        if let Some(place) = coordinates_place(query) {
+            return Ok(place);
+        }
+        if query == crate::client::AUTO_IP_QUERY {
+            // Open-Meteo has no IP lookup of its own.
+            let url: reqwest::Url = reqwest::Url::parse("https://ipapi.co/json/")
+                .map_err(|e| WeatherError::InvalidQuery(e.to_string()))?;
+            let found: IpLocation = serde_json::from_str(&self.get(url)?)?;
+            return Ok(Place {
+                name: found.city,
+                latitude: found.latitude,
+                longitude: found.longitude,
+                country: found.country_name,
+                admin1: found.region,
+            });
+        }
+        let mut url: reqwest::Url = reqwest::Url::parse_with_params(
+            "https://geocoding-api.open-meteo.com/v1/search",
+            [("name", query), ("count", "1"), ("format", "json")],
+        )
+        .map_err(|e| WeatherError::InvalidQuery(e.to_string()))?;
+        if let Some(lang) = &self.lang {
+            url.query_pairs_mut().append_pair("language", lang);
+        }
+        let geocoding: Geocoding = serde_json::from_str(&self.get(url)?)?;
+        geocoding
+            .results
+            .into_iter()
+            .next()
+            .ok_or_else(|| WeatherError::InvalidQuery("No matching location found.".to_owned()))
+    }
+
+    /// Finds the place nearest to coordinates with OpenStreetMap's Nominatim, as
+    /// Open-Meteo has no reverse geocoding.
+    ///
+    /// # Returns
+    /// The nearest `Place` at the given coordinates, or `WeatherError::InvalidQuery`
+    /// if none is near, e.g. at sea.
+    #[cfg(feature = "blocking")]
+    pub fn reverse_geocode(&self, lat: f64, lon: f64) -> Result<Place, WeatherError> {
+        let mut url: reqwest::Url = reqwest::Url::parse_with_params(
+            "https://nominatim.openstreetmap.org/reverse",
+            [
+                ("lat", lat.to_string().as_str()),
+                ("lon", &lon.to_string()),
+                ("format", "jsonv2"),
+                ("zoom", "10"),
+            ],
+        )
+        .map_err(|e| WeatherError::InvalidQuery(e.to_string()))?;
+        if let Some(lang) = &self.lang {
+            url.query_pairs_mut().append_pair("accept-language", lang);
+        }
+        // Nominatim's usage policy asks for an identifying User-Agent.
+        let request: reqwest::blocking::RequestBuilder = self.http()?.get(url).header(
+            reqwest::header::USER_AGENT,
+            concat!("mosm-rs/", env!("CARGO_PKG_VERSION")),
+        );
+        let response: reqwest::blocking::Response = crate::http::send(request)?;
+        let status: u16 = response.status().as_u16();
+        let body: String = response.text()?;
+        if status != 200 {
+            return Err(WeatherError::Status {
+                status,
+                message: None,
+            });
+        }
+        Ok(Place {
+            latitude: lat,
+            longitude: lon,
+            ..parse_reverse(&body)?
+        })
+    }
+
+    /// Fetches the ground elevation of coordinates from Open-Meteo's 90 m
+    /// digital elevation model.
+    ///
+    /// # Returns
+    /// The elevation in metres above sea level.
+    #[cfg(feature = "blocking")]
+    pub fn elevation(&self, lat: f64, lon: f64) -> Result<f64, WeatherError> {
+        let url: reqwest::Url = reqwest::Url::parse_with_params(
+            "https://api.open-meteo.com/v1/elevation",
+            [
+                ("latitude", lat.to_string().as_str()),
+                ("longitude", &lon.to_string()),
+            ],
+        )
+        .map_err(|e| WeatherError::InvalidQuery(e.to_string()))?;
+        parse_elevation(&self.get(url)?)
+    }
+
+    /// Fetches and converts the forecast for a location.
+    ///
+    /// # Arguments
+    /// * `query` - Place name or `lat,lon` coordinates.
+    /// * `days` - Number of forecast days to request (up to 16).
+    ///
+    /// # Returns
+    /// A `WeatherData` struct in the same shape as the Weather API's.
+    #[cfg(feature = "blocking")]
+    pub fn fetch_forecast(&self, query: &str, days: u32) -> Result<WeatherData, WeatherError> {
+        let place: Place = self.geocode(query)?;
+        let url: reqwest::Url = reqwest::Url::parse_with_params(
+            "https://api.open-meteo.com/v1/forecast",
+            [
+                ("latitude", place.latitude.to_string().as_str()),
+                ("longitude", &place.longitude.to_string()),
+                ("timezone", "auto"),
+                ("forecast_days", &days.to_string()),
+                ("current", CURRENT_VARIABLES),
+                ("hourly", HOURLY_VARIABLES),
+                ("daily", DAILY_VARIABLES),
+            ],
+        )
+        .map_err(|e| WeatherError::InvalidQuery(e.to_string()))?;
+        parse_forecast(place, &self.get(url)?)
+    }
+}
+
+/// Builds a place for a `lat,lon` query, which needs no geocoding.
+#[cfg(feature = "blocking")]
+fn coordinates_place(query: &str) -> Option<Place> {
+    let (latitude, longitude) = geo::parse_coordinates(query)?;
+    Some(Place {
+        name: query.to_owned(),
+        latitude,
+        longitude,
+        country: String::new(),
+        admin1: String::new(),
+    })
+}
+
+/// Response of the Nominatim reverse geocoding endpoint.
+#[derive(Deserialize, Debug)]
+struct ReversePlace {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    address: std::collections::BTreeMap<String, String>,
+    /// Set instead of the place, e.g. `Unable to geocode` at sea.
+    #[serde(default)]
+    error: Option<String>,
+}
+
+/// Parses a Nominatim reverse geocoding response into the nearest place.
+///
+/// ```text
+/// {"name":"London","address":{"city":"London","state":"England",
+///   "country":"United Kingdom",...},...}
+/// ```
+///
+/// # Returns
+/// The place, with the coordinates left at 0, or `WeatherError::InvalidQuery` if
+/// no place is near.
+pub fn parse_reverse(body: &str) -> Result<Place, WeatherError> {
+    let found: ReversePlace = serde_json::from_str(body)?;
+    if let Some(error) = found.error {
+        return Err(WeatherError::InvalidQuery(error));
+    }
+    let field = |key: &str| found.address.get(key).cloned().unwrap_or_default();
+    let name: String = [
+        "city",
+        "town",
+        "village",
+        "hamlet",
+        "municipality",
+        "county",
+    ]
+    .iter()
+    .find_map(|key| found.address.get(*key).cloned())
+    .unwrap_or(found.name);
+    if name.is_empty() {
+        return Err(WeatherError::InvalidQuery("no place nearby".to_owned()));
+    }
+    Ok(Place {
+        name,
+        latitude: 0.0,
+        longitude: 0.0,
+        country: field("country"),
+        admin1: field("state"),
+    })
+}
+
+/// Response of the elevation endpoint, one value per requested point.
+#[derive(Deserialize, Debug)]
+struct Elevation {
+    elevation: Vec<f64>,
+}
+
+/// Parses an elevation endpoint response, e.g. `{"elevation":[35.0]}`.
+pub fn parse_elevation(body: &str) -> Result<f64, WeatherError> {
+    let found: Elevation = serde_json::from_str(body)?;
+    found
+        .elevation
+        .first()
+        .copied()
+        .ok_or_else(|| WeatherError::InvalidQuery("no elevation for this location".to_owned()))
+}
+
+/// Converts a forecast endpoint response to `WeatherData`.
+///
+/// # Arguments
+/// * `place` - Place the forecast was requested for.
+/// * `body` - JSON body of the forecast response.
+pub fn parse_forecast(place: Place, body: &str) -> Result<WeatherData, WeatherError> {
+    let response: Response = serde_json::from_str(body)?;
+    let offset: i64 = response.utc_offset_seconds;
+    let hourly: &HourlyValues = &response.hourly;
+    let mut hours = (0..hourly.time.len())
+        .map(|i| hour(hourly, i, offset))
+        .peekable();
+
+    let current_values: &CurrentValues = &response.current;
+    let current_hour: Option<Hour> = hourly
+        .time
+        .iter()
+        .position(|time| time.get(..13) == current_values.time.get(..13))
+        .map(|i| hour(hourly, i, offset));
+
+    let daily: &DailyValues = &response.daily;
+    let mut forecastday: Vec<ForecastDay> = Vec::new();
+    for (d, date) in daily.time.iter().enumerate() {
+        let day_hours: Vec<Hour> =
+            std::iter::from_fn(|| hours.next_if(|hour| hour.time.starts_with(date.as_str())))
+                .collect();
+        let (moon_phase, moon_illumination): (&str, u32) =
+            moon_phase(local_epoch(date, offset) + 12 * 3600);
+        forecastday.push(ForecastDay {
+            date: date.clone(),
+            date_epoch: local_epoch(date, 0),
+            day: day(daily, d, &day_hours),
+            astro: Astro {
+                sunrise: daily
+                    .sunrise
+                    .get(d)
+                    .map(|t| clock_12h(t))
+                    .unwrap_or_default(),
+                sunset: daily
+                    .sunset
+                    .get(d)
+                    .map(|t| clock_12h(t))
+                    .unwrap_or_default(),
+                moonrise: "N/A".to_owned(),
+                moonset: "N/A".to_owned(),
+                moon_phase: moon_phase.to_owned(),
+                moon_illumination,
+                is_moon_up: 0,
+                is_sun_up: 0,
+            },
+            hour: day_hours,
+        });
+    }
+
+    Ok(WeatherData {
+        location: Location {
+            name: place.name,
+            region: place.admin1,
+            country: place.country,
+            lat: place.latitude,
+            lon: place.longitude,
+            tz_id: response.timezone,
+            localtime_epoch: local_epoch(&current_values.time, offset) as i32,
+            localtime: current_values.time.replace('T', " "),
+            show_coordinates: false,
+            elevation_m: None,
+        },
+        current: current(current_values, current_hour.as_ref(), offset),
+        forecast: Forecast { forecastday },
+        alerts: Alerts::default(),
+    })
+}
+
+/// Builds the current conditions, completed with the hour in progress.
+fn current(values: &CurrentValues, hour: Option<&Hour>, offset: i64) -> Current {
+    let from_hour = |field: fn(&Hour) -> f64| hour.map_or(0.0, field);
+    let is_day: bool = values.is_day == 1.0;
+    let dewpoint_c: Option<f64> = hour.and_then(|h| h.dewpoint_c);
+    let vis_km: Option<f64> = hour.and_then(|h| h.vis_km);
+    Current {
+        last_updated_epoch: local_epoch(&values.time, offset) as i32,
+        last_updated: values.time.replace('T', " "),
+        temp_c: values.temperature_2m,
+        temp_f: fahrenheit(values.temperature_2m),
+        is_day: i32::from(is_day),
+        condition: condition(values.weather_code as i32, is_day),
+        wind_mph: mph(values.wind_speed_10m),
+        wind_kph: values.wind_speed_10m,
+        wind_degree: values.wind_direction_10m as i32,
+        wind_dir: geo::compass_point(values.wind_direction_10m).to_owned(),
+        pressure_mb: values.pressure_msl,
+        pressure_in: inches_hg(values.pressure_msl),
+        precip_mm: values.precipitation,
+        precip_in: inches(values.precipitation),
+        humidity: values.relative_humidity_2m as i32,
+        cloud: values.cloud_cover as i32,
+        feelslike_c: values.apparent_temperature,
+        feelslike_f: fahrenheit(values.apparent_temperature),
+        windchill_c: Some(values.apparent_temperature),
+        windchill_f: Some(fahrenheit(values.apparent_temperature)),
+        heatindex_c: Some(values.apparent_temperature),
+        heatindex_f: Some(fahrenheit(values.apparent_temperature)),
+        dewpoint_c,
+        dewpoint_f: dewpoint_c.map(fahrenheit),
+        vis_km,
+        vis_miles: vis_km.map(miles),
+        uv: hour.and_then(|h| h.uv),
+        gust_mph: Some(mph(values.wind_gusts_10m)),
+        gust_kph: Some(values.wind_gusts_10m),
+        air_quality: None,
+        short_rad: from_hour(|h| h.short_rad),
+        diff_rad: from_hour(|h| h.diff_rad),
+        dni: from_hour(|h| h.dni),
+        gti: from_hour(|h| h.gti),
+    }
+}
+
+/// Builds the forecast hour at index `i` of the hourly series.
+fn hour(values: &HourlyValues, i: usize, offset: i64) -> Hour {
+    let reading = |series: &Vec<Option<f64>>| series.get(i).copied().flatten();
+    let at = |series: &Vec<Option<f64>>| reading(series).unwrap_or(0.0);
+    let temp_c: f64 = at(&values.temperature_2m);
+    let feelslike_c: f64 = at(&values.apparent_temperature);
+    let dewpoint_c: Option<f64> = reading(&values.dew_point_2m);
+    let precip_mm: f64 = at(&values.precipitation);
+    let snow_cm: f64 = at(&values.snowfall);
+    let wind_kph: f64 = at(&values.wind_speed_10m);
+    let gust_kph: Option<f64> = reading(&values.wind_gusts_10m);
+    let wind_degree: f64 = at(&values.wind_direction_10m);
+    let pressure_mb: f64 = at(&values.pressure_msl);
+    let vis_km: Option<f64> = reading(&values.visibility).map(|metres| metres / 1000.0);
+    let chance: i32 = at(&values.precipitation_probability) as i32;
+    let code: i32 = at(&values.weather_code) as i32;
+    let is_day: bool = at(&values.is_day) == 1.0;
+    let snowing: bool = snow_cm > 0.0 || matches!(code, 71..=77 | 85 | 86);
+    let time: &str = values.time.get(i).map_or("", String::as_str);
+    Hour {
+        time_epoch: local_epoch(time, offset),
+        time: time.replace('T', " "),
+        temp_c,
+        temp_f: fahrenheit(temp_c),
+        is_day: i32::from(is_day),
+        condition: condition(code, is_day),
+        wind_mph: mph(wind_kph),
+        wind_kph,
+        wind_degree: wind_degree as i32,
+        wind_dir: geo::compass_point(wind_degree).to_owned(),
+        pressure_mb,
+        pressure_in: inches_hg(pressure_mb),
+        precip_mm,
+        precip_in: inches(precip_mm),
+        snow_cm,
+        humidity: at(&values.relative_humidity_2m) as i32,
+        cloud: at(&values.cloud_cover) as i32,
+        feelslike_c,
+        feelslike_f: fahrenheit(feelslike_c),
+        windchill_c: Some(feelslike_c),
+        windchill_f: Some(fahrenheit(feelslike_c)),
+        heatindex_c: Some(feelslike_c),
+        heatindex_f: Some(fahrenheit(feelslike_c)),
+        dewpoint_c,
+        dewpoint_f: dewpoint_c.map(fahrenheit),
+        will_it_rain: i32::from(precip_mm > 0.0 && !snowing),
+        chance_of_rain: if snowing { 0 } else { chance },
+        will_it_snow: i32::from(snowing),
+        chance_of_snow: if snowing { chance } else { 0 },
+        vis_km,
+        vis_miles: vis_km.map(miles),
+        gust_kph,
+        gust_mph: gust_kph.map(mph),
+        uv: reading(&values.uv_index),
+        air_quality: None,
+        short_rad: at(&values.shortwave_radiation),
+        diff_rad: at(&values.diffuse_radiation),
+        dni: at(&values.direct_normal_irradiance),
+        gti: at(&values.global_tilted_irradiance),
+    }
+}
+
+/// Builds the summary of day `d`, averaging its forecast hours.
+fn day(values: &DailyValues, d: usize, hours: &[Hour]) -> Day {
+    let at = |series: &Vec<Option<f64>>| series.get(d).copied().flatten().unwrap_or(0.0);
+    let average =
+        |field: fn(&Hour) -> f64| hours.iter().map(field).sum::<f64>() / hours.len().max(1) as f64;
+    let maxtemp_c: f64 = at(&values.temperature_2m_max);
+    let mintemp_c: f64 = at(&values.temperature_2m_min);
+    let avgtemp_c: f64 = round1(average(|h| h.temp_c));
+    let maxwind_kph: f64 = at(&values.wind_speed_10m_max);
+    let totalprecip_mm: f64 = at(&values.precipitation_sum);
+    let visibilities: Vec<f64> = hours.iter().filter_map(|h| h.vis_km).collect();
+    let avgvis_km: f64 =
+        round1(visibilities.iter().sum::<f64>() / visibilities.len().max(1) as f64);
+    Day {
+        maxtemp_c,
+        maxtemp_f: fahrenheit(maxtemp_c),
+        mintemp_c,
+        mintemp_f: fahrenheit(mintemp_c),
+        avgtemp_c,
+        avgtemp_f: fahrenheit(avgtemp_c),
+        maxwind_mph: mph(maxwind_kph),
+        maxwind_kph,
+        totalprecip_mm,
+        totalprecip_in: inches(totalprecip_mm),
+        totalsnow_cm: at(&values.snowfall_sum),
+        avgvis_km,
+        avgvis_miles: miles(avgvis_km),
+        avghumidity: average(|h| f64::from(h.humidity)).round() as u32,
+        daily_will_it_rain: hours.iter().map(|h| h.will_it_rain).max().unwrap_or(0),
+        daily_chance_of_rain: hours.iter().map(|h| h.chance_of_rain).max().unwrap_or(0),
+        daily_will_it_snow: hours.iter().map(|h| h.will_it_snow).max().unwrap_or(0),
+        daily_chance_of_snow: hours.iter().map(|h| h.chance_of_snow).max().unwrap_or(0),
+        condition: condition(at(&values.weather_code) as i32, true),
+        uv: at(&values.uv_index_max),
+        air_quality: None,
+    }
+}
+
+/// Maps a WMO weather code to the equivalent Weather API condition.
+///
+/// The Weather API codes are kept so code-based checks (e.g. thunderstorms) keep working.
+pub fn condition(wmo_code: i32, is_day: bool) -> Condition {
+    let (code, icon, text): (i32, u32, &str) = match wmo_code {
+        0 if is_day => (1000, 113, "Sunny"),
+        0 => (1000, 113, "Clear"),
+        1 => (1003, 116, "Partly cloudy"),
+        2 => (1006, 119, "Cloudy"),
+        3 => (1009, 122, "Overcast"),
+        45 => (1135, 248, "Fog"),
+        48 => (1147, 260, "Freezing fog"),
+        51 => (1150, 263, "Patchy light drizzle"),
+        53 | 55 => (1153, 266, "Light drizzle"),
+        56 => (1168, 281, "Freezing drizzle"),
+        57 => (1171, 284, "Heavy freezing drizzle"),
+        61 => (1183, 296, "Light rain"),
+        63 => (1189, 302, "Moderate rain"),
+        65 => (1195, 308, "Heavy rain"),
+        66 => (1198, 311, "Light freezing rain"),
+        67 => (1201, 314, "Moderate or heavy freezing rain"),
+        71 => (1213, 326, "Light snow"),
+        73 => (1219, 332, "Moderate snow"),
+        75 => (1225, 338, "Heavy snow"),
+        77 => (1237, 350, "Ice pellets"),
+        80 => (1240, 353, "Light rain shower"),
+        81 => (1243, 356, "Moderate or heavy rain shower"),
+        82 => (1246, 359, "Torrential rain shower"),
+        85 => (1255, 368, "Light snow showers"),
+        86 => (1258, 371, "Moderate or heavy snow showers"),
+        95 | 96 | 99 => (1276, 389, "Moderate or heavy rain with thunder"),
+        _ => (1009, 122, "Unknown"),
+    };
+    let period: &str = if is_day { "day" } else { "night" };
+    Condition {
+        text: text.to_owned(),
+        icon: format!("//cdn.weatherapi.com/weather/64x64/{period}/{icon}.png"),
+        code,
+    }
+}
+
+/// Returns the moon phase name and illumination (%) at a UNIX time.
+fn moon_phase(epoch: i64) -> (&'static str, u32) {
+    const SYNODIC_MONTH_DAYS: f64 = 29.530_588_853;
+    // New moon of 2000-01-06 18:14 UTC.
+    let age: f64 = ((epoch - 947_182_440) as f64 / 86_400.0).rem_euclid(SYNODIC_MONTH_DAYS);
+    let fraction: f64 = age / SYNODIC_MONTH_DAYS;
+    let illumination: f64 = (1.0 - (fraction * std::f64::consts::TAU).cos()) / 2.0 * 100.0;
+    const PHASES: [&str; 8] = [
+        "New Moon",
+        "Waxing Crescent",
+        "First Quarter",
+        "Waxing Gibbous",
+        "Full Moon",
+        "Waning Gibbous",
+        "Last Quarter",
+        "Waning Crescent",
+    ];
+    (
+        PHASES[((fraction * 8.0).round() as usize) % 8],
+        illumination.round() as u32,
+    )
+}
+
+/// Formats the time of `YYYY-MM-DDTHH:MM` as `05:23 AM`, like the Weather API.
+fn clock_12h(time: &str) -> String {
+    let hour: u32 = time.get(11..13).and_then(|s| s.parse().ok()).unwrap_or(0);
+    let minute: &str = time.get(14..16).unwrap_or("00");
+    let suffix: &str = if hour < 12 { "AM" } else { "PM" };
+    format!("{:02}:{minute} {suffix}", (hour + 11) % 12 + 1)
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn fahrenheit(celsius: f64) -> f64 {
+    round1(celsius * 9.0 / 5.0 + 32.0)
+}
+
+fn mph(kph: f64) -> f64 {
+    round1(kph / 1.609_344)
+}
+
+fn miles(km: f64) -> f64 {
+    round1(km / 1.609_344)
+}
+
+fn inches(mm: f64) -> f64 {
+    (mm / 25.4 * 100.0).round() / 100.0
+}
+
+fn inches_hg(mb: f64) -> f64 {
+    (mb * 0.029_53 * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_forecast_response() {
+        let place: Place = coordinates_place("51.5, -0.12").unwrap();
+        let body = r#"{"timezone":"Europe/London","utc_offset_seconds":3600,
+            "current":{"time":"2025-08-01T14:15","temperature_2m":21.0,
+                "relative_humidity_2m":55,"apparent_temperature":20.4,"is_day":1,
+                "precipitation":0.0,"weather_code":2,"cloud_cover":60,"pressure_msl":1016.2,
+                "wind_speed_10m":14.4,"wind_direction_10m":225,"wind_gusts_10m":27.0},
+            "hourly":{"time":["2025-08-01T13:00","2025-08-01T14:00"],
+                "temperature_2m":[20.0,21.0],"dew_point_2m":[11.0,11.5],
+                "precipitation":[0.4,null],"precipitation_probability":[60,20],
+                "snowfall":[0.0,0.0],"weather_code":[61,95],"is_day":[1,1],
+                "visibility":[24000,null],"uv_index":[5.5,6.0]},
+            "daily":{"time":["2025-08-01"],"weather_code":[95],
+                "temperature_2m_max":[23.0],"temperature_2m_min":[14.0],
+                "sunrise":["2025-08-01T05:23"],"sunset":["2025-08-01T20:51"],
+                "precipitation_sum":[0.4]}}"#;
+        let weather: WeatherData = parse_forecast(place, body).unwrap();
+
+        assert_eq!(weather.location.localtime, "2025-08-01 14:15");
+        assert_eq!(weather.location.localtime_epoch, 1_754_054_100);
+        assert_eq!(weather.current.temp_f, 69.8);
+        assert_eq!(weather.current.wind_dir, "SW");
+        assert_eq!(weather.current.uv, Some(6.0));
+        // Missing readings stay missing rather than reading as fog or calm.
+        assert_eq!(weather.current.vis_km, None);
+        assert_eq!(weather.current.condition.text, "Cloudy");
+
+        let forecast_day: &ForecastDay = &weather.forecast.forecastday[0];
+        assert_eq!(forecast_day.astro.sunrise, "05:23 AM");
+        assert_eq!(forecast_day.astro.sunset, "08:51 PM");
+        assert_eq!(forecast_day.day.condition.code, 1276);
+        assert_eq!(forecast_day.day.daily_chance_of_rain, 60);
+        assert_eq!(forecast_day.day.avgtemp_c, 20.5);
+        assert_eq!(forecast_day.hour.len(), 2);
+        assert_eq!(forecast_day.hour[0].will_it_rain, 1);
+        assert_eq!(forecast_day.hour[1].precip_mm, 0.0);
+        assert_eq!(forecast_day.hour[1].time_epoch, 1_754_053_200);
+        assert_eq!(forecast_day.hour[0].vis_km, Some(24.0));
+        assert_eq!(forecast_day.hour[0].gust_kph, None);
+        assert_eq!(forecast_day.day.avgvis_km, 24.0);
+    }
+
+    #[test]
+    fn parses_place_lookups() {
+        let place: Place = parse_reverse(
+            r#"{"place_id":1,"name":"Westminster","address":{"city":"London",
+                "state":"England","country":"United Kingdom","country_code":"gb"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            (
+                place.name.as_str(),
+                place.admin1.as_str(),
+                place.country.as_str()
+            ),
+            ("London", "England", "United Kingdom")
+        );
+        assert!(parse_reverse(r#"{"error":"Unable to geocode"}"#).is_err());
+        assert_eq!(parse_elevation(r#"{"elevation":[35.0]}"#).unwrap(), 35.0);
+        assert!(parse_elevation(r#"{"elevation":[]}"#).is_err());
+    }
+
+    #[test]
+    fn computes_moon_phase() {
+        // Full moon of 2025-08-09 07:55 UTC.
+        let (phase, illumination): (&str, u32) = moon_phase(1_754_726_100);
+        assert_eq!(phase, "Full Moon");
+        assert!(illumination >= 98);
+        assert_eq!(moon_phase(947_182_440).0, "New Moon");
+    }
+}
@@ -0,0 +1,147 @@
+use serde::Serialize;
+
+use crate::{Hour, WeatherData};
+
+/// Pavement temperature (°C) at which paw pads burn within a minute.
+pub const PAVEMENT_UNSAFE_C: f64 = 52.0;
+/// Pavement temperature (°C) uncomfortable for paw pads on a longer walk.
+pub const PAVEMENT_CAUTION_C: f64 = 45.0;
+/// Heat index (°C) at which dogs risk heatstroke on a walk.
+pub const HEAT_INDEX_UNSAFE_C: f64 = 32.0;
+/// Heat index (°C) at which short-nosed, old and heavy dogs struggle.
+pub const HEAT_INDEX_CAUTION_C: f64 = 27.0;
+/// Local hours (`HH`) counted as afternoon in the daily summary.
+pub const AFTERNOON_HOURS: std::ops::RangeInclusive<u32> = 12..=17;
+
+/// Rise of sunlit asphalt above the air temperature per W/m² of solar radiation.
+const ASPHALT_GAIN_C_PER_W: f64 = 0.027;
+/// Solar radiation (W/m²) per UV index point, when the radiation is missing.
+const RADIATION_PER_UV: f64 = 100.0;
+
+/// Heat risk of walking a dog.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum PetRisk {
+    /// Keep walks short and on grass or in the shade.
+    Caution,
+    /// Do not walk on pavement or in the heat.
+    Unsafe,
+}
+
+impl std::fmt::Display for PetRisk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PetRisk::Caution => write!(f, "caution"),
+            PetRisk::Unsafe => write!(f, "unsafe"),
+        }
+    }
+}
+
+/// Heat risk of an hour, with the values it is assessed from.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PetHeat {
+    /// Local time of the hour (`YYYY-MM-DD HH:MM`).
+    pub time: String,
+    pub pavement_c: f64,
+    pub heat_index_c: f64,
+    pub risk: PetRisk,
+}
+
+/// Estimates the temperature of sunlit asphalt.
+///
+/// Asphalt in full sun runs about 25 °C above the air; the rise scales with the
+/// solar radiation, or the UV index in responses without it. Without either, the
+/// estimate is the air temperature.
+pub fn pavement_temp_c(hour: &Hour) -> f64 {
+    let radiation: f64 = if hour.short_rad > 0.0 {
+        hour.short_rad
+    } else if hour.is_day == 1 {
+        hour.uv.map_or(0.0, |uv| uv * RADIATION_PER_UV)
+    } else {
+        0.0
+    };
+    hour.temp_c + radiation * ASPHALT_GAIN_C_PER_W
+}
+
+/// Assesses the heat risk of walking a dog at a forecast hour.
+///
+/// Hot pavement burns paw pads, while heat and humidity (the heat index) cause
+/// heatstroke, as dogs cool themselves by panting.
+///
+/// # Returns
+/// The `PetHeat`, or `None` if a walk is safe.
+pub fn pet_heat(hour: &Hour) -> Option<PetHeat> {
+    let pavement_c: f64 = pavement_temp_c(hour);
+    // The heat index is missing from some plans; it is never below the air temperature.
+    let heat_index_c: f64 = hour.heatindex_c.map_or(hour.temp_c, |c| c.max(hour.temp_c));
+    let risk: PetRisk = if pavement_c >= PAVEMENT_UNSAFE_C || heat_index_c >= HEAT_INDEX_UNSAFE_C {
+        PetRisk::Unsafe
+    } else if pavement_c >= PAVEMENT_CAUTION_C || heat_index_c >= HEAT_INDEX_CAUTION_C {
+        PetRisk::Caution
+    } else {
+        return None;
+    };
+    Some(PetHeat {
+        time: hour.time.clone(),
+        pavement_c,
+        heat_index_c,
+        risk,
+    })
+}
+
+/// Returns the hottest risky hour of each forecast afternoon.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+///
+/// # Returns
+/// `(date, heat)` for every forecast day with an afternoon risk.
+pub fn afternoon_risks(weather: &WeatherData) -> Vec<(&str, PetHeat)> {
+    weather
+        .forecast
+        .forecastday
+        .iter()
+        .filter_map(|forecast_day| {
+            forecast_day
+                .hour
+                .iter()
+                .filter(|hour| {
+                    hour.time[11..13]
+                        .parse::<u32>()
+                        .is_ok_and(|h| AFTERNOON_HOURS.contains(&h))
+                })
+                .filter_map(pet_heat)
+                .max_by(|a, b| {
+                    a.risk
+                        .cmp(&b.risk)
+                        .then(a.pavement_c.total_cmp(&b.pavement_c))
+                })
+                .map(|heat| (forecast_day.date.as_str(), heat))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_hot_afternoons() {
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        assert!(afternoon_risks(&weather).is_empty());
+
+        // Day 2: a hot, sunny afternoon; day 3: hot pavement in the morning only.
+        for hour in &mut weather.forecast.forecastday[1].hour[12..18] {
+            hour.temp_c = 30.0;
+        }
+        weather.forecast.forecastday[1].hour[15].short_rad = 900.0;
+        weather.forecast.forecastday[2].hour[10].temp_c = 35.0;
+        let risks: Vec<(&str, PetHeat)> = afternoon_risks(&weather);
+        assert_eq!(risks.len(), 1);
+        assert_eq!(risks[0].0, "2025-08-02");
+        assert_eq!(risks[0].1.risk, PetRisk::Unsafe);
+        assert_eq!(risks[0].1.time, "2025-08-02 15:00");
+        assert!((54.0..55.0).contains(&risks[0].1.pavement_c));
+    }
+}
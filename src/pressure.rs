@@ -0,0 +1,151 @@
+use crate::WeatherData;
+
+/// Pressure change (hPa) that triggers a migraine warning.
+pub const SWING_THRESHOLD_HPA: f64 = 6.0;
+/// Window (hours) in which the pressure change must happen.
+pub const SWING_WINDOW_HOURS: i64 = 12;
+
+/// A rapid pressure change between two points in time.
+#[derive(Debug)]
+pub struct PressureSwing {
+    /// Pressure change in hPa (negative for a drop).
+    pub change_hpa: f64,
+    /// Local time at the start of the swing.
+    pub from: String,
+    /// Local time at the end of the swing.
+    pub to: String,
+}
+
+/// Finds the largest pressure swing exceeding the threshold within the window.
+///
+/// The current observation is used as the starting point, followed by the hourly
+/// forecast for the coming hours.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `threshold_hpa` - Minimum absolute pressure change to report.
+/// * `window_hours` - Maximum time span of the change.
+///
+/// # Returns
+/// The largest `PressureSwing`, or `None` if pressure stays within the threshold.
+pub fn find_swing(
+    weather: &WeatherData,
+    threshold_hpa: f64,
+    window_hours: i64,
+) -> Option<PressureSwing> {
+    let now: i64 = weather.current.last_updated_epoch as i64;
+    let mut series: Vec<(i64, f64, &str)> = vec![(
+        now,
+        weather.current.pressure_mb,
+        weather.current.last_updated.as_str(),
+    )];
+    for hour in weather.hours().filter(|hour| hour.time_epoch > now) {
+        series.push((hour.time_epoch, hour.pressure_mb, hour.time.as_str()));
+    }
+
+    let mut largest: Option<PressureSwing> = None;
+    for (i, (start_epoch, start_mb, start_time)) in series.iter().enumerate() {
+        for (end_epoch, end_mb, end_time) in &series[i + 1..] {
+            if end_epoch - start_epoch > window_hours * 3600 {
+                break;
+            }
+            let change: f64 = end_mb - start_mb;
+            let is_larger = largest
+                .as_ref()
+                .is_none_or(|swing| change.abs() > swing.change_hpa.abs());
+            if change.abs() >= threshold_hpa && is_larger {
+                largest = Some(PressureSwing {
+                    change_hpa: change,
+                    from: start_time.to_string(),
+                    to: end_time.to_string(),
+                });
+            }
+        }
+    }
+    largest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hour;
+
+    /// Returns the fixture with a steady pressure.
+    fn steady() -> WeatherData {
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        weather.current.pressure_mb = 1010.0;
+        for forecast_day in &mut weather.forecast.forecastday {
+            for hour in &mut forecast_day.hour {
+                hour.pressure_mb = 1010.0;
+            }
+        }
+        weather
+    }
+
+    /// Returns the `n`th forecast hour after the current observation.
+    fn hour_after(weather: &mut WeatherData, n: usize) -> &mut Hour {
+        let now: i64 = weather.current.last_updated_epoch as i64;
+        weather
+            .forecast
+            .forecastday
+            .iter_mut()
+            .flat_map(|forecast_day| &mut forecast_day.hour)
+            .filter(|hour| hour.time_epoch > now)
+            .nth(n)
+            .unwrap()
+    }
+
+    #[test]
+    fn finds_rising_and_falling_swings() {
+        let mut weather: WeatherData = steady();
+        hour_after(&mut weather, 5).pressure_mb = 1017.5;
+        let swing: PressureSwing = find_swing(&weather, 6.0, 12).unwrap();
+        assert_eq!(swing.change_hpa, 7.5);
+        assert_eq!(swing.from, weather.current.last_updated);
+
+        let mut weather: WeatherData = steady();
+        hour_after(&mut weather, 2).pressure_mb = 1003.0;
+        hour_after(&mut weather, 3).pressure_mb = 1001.0;
+        let swing: PressureSwing = find_swing(&weather, 6.0, 12).unwrap();
+        assert_eq!(swing.change_hpa, -9.0);
+        let to: String = hour_after(&mut weather, 3).time.clone();
+        assert_eq!(swing.to, to);
+    }
+
+    #[test]
+    fn ignores_small_or_slow_changes() {
+        let mut weather: WeatherData = steady();
+        assert!(find_swing(&weather, 6.0, 12).is_none());
+        hour_after(&mut weather, 5).pressure_mb = 1015.0;
+        assert!(find_swing(&weather, 6.0, 12).is_none());
+
+        // 0.4 hPa an hour only adds up to a swing over a longer window.
+        let now: i64 = weather.current.last_updated_epoch as i64;
+        let coming: usize = weather.hours().filter(|hour| hour.time_epoch > now).count();
+        for n in 0..coming {
+            hour_after(&mut weather, n).pressure_mb = 1010.0 + (n + 1) as f64 * 0.4;
+        }
+        assert!(find_swing(&weather, 6.0, 12).is_none());
+        assert!(find_swing(&weather, 6.0, 24).is_some());
+    }
+
+    #[test]
+    fn needs_two_readings() {
+        let mut weather: WeatherData = steady();
+        weather.current.pressure_mb = 1030.0;
+        weather.forecast.forecastday.clear();
+        assert!(find_swing(&weather, 6.0, 12).is_none());
+
+        // The current observation and a single forecast hour.
+        let mut weather: WeatherData = steady();
+        let next: &mut Hour = hour_after(&mut weather, 0);
+        next.pressure_mb = 1020.0;
+        let epoch: i64 = next.time_epoch;
+        for forecast_day in &mut weather.forecast.forecastday {
+            forecast_day.hour.retain(|hour| hour.time_epoch == epoch);
+        }
+        assert_eq!(weather.hours().count(), 1);
+        assert_eq!(find_swing(&weather, 6.0, 12).unwrap().change_hpa, 10.0);
+    }
+}
@@ -0,0 +1,59 @@
+#[cfg(feature = "blocking")]
+use crate::open_meteo::OpenMeteoClient;
+#[cfg(feature = "blocking")]
+use crate::{WeatherClient, WeatherData, WeatherError};
+
+/// Names of the providers accepted by `--provider` and the `provider` setting.
+pub const PROVIDERS: [&str; 2] = ["weatherapi", "open-meteo"];
+
+/// Source of forecasts behind the fetch layer.
+///
+/// Every provider returns the Weather API response shape, which sessions and the
+/// cache store and every renderer reads.
+#[cfg(feature = "blocking")]
+pub trait WeatherProvider {
+    /// Name of the provider, one of [`PROVIDERS`].
+    fn name(&self) -> &'static str;
+
+    /// Fetches the forecast as a Weather API shaped JSON body.
+    ///
+    /// # Arguments
+    /// * `query` - Location query (e.g., city name, coordinates).
+    /// * `days` - Number of forecast days to request.
+    fn fetch_forecast_json(&self, query: &str, days: u32) -> Result<String, WeatherError>;
+
+    /// Fetches and parses the forecast.
+    fn fetch_forecast(&self, query: &str, days: u32) -> Result<WeatherData, WeatherError> {
+        Ok(serde_json::from_str(
+            &self.fetch_forecast_json(query, days)?,
+        )?)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl WeatherProvider for WeatherClient {
+    fn name(&self) -> &'static str {
+        "weatherapi"
+    }
+
+    fn fetch_forecast_json(&self, query: &str, days: u32) -> Result<String, WeatherError> {
+        WeatherClient::fetch_forecast_json(self, query, days)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl WeatherProvider for OpenMeteoClient {
+    fn name(&self) -> &'static str {
+        "open-meteo"
+    }
+
+    fn fetch_forecast_json(&self, query: &str, days: u32) -> Result<String, WeatherError> {
+        Ok(serde_json::to_string(&OpenMeteoClient::fetch_forecast(
+            self, query, days,
+        )?)?)
+    }
+
+    fn fetch_forecast(&self, query: &str, days: u32) -> Result<WeatherData, WeatherError> {
+        OpenMeteoClient::fetch_forecast(self, query, days)
+    }
+}
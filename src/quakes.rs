@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+/// Default search radius around the location, in kilometres.
+pub const DEFAULT_RADIUS_KM: f64 = 300.0;
+/// Default minimum magnitude for reported earthquakes.
+pub const DEFAULT_MIN_MAGNITUDE: f64 = 2.5;
+
+/// USGS event feed response (GeoJSON `FeatureCollection`).
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuakeFeed {
+    /// List of earthquake events.
+    pub features: Vec<Quake>,
+}
+
+/// Single earthquake event.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Quake {
+    /// Event details.
+    pub properties: QuakeProperties,
+    /// Epicentre coordinates.
+    pub geometry: QuakeGeometry,
+}
+
+/// Event details reported by USGS.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuakeProperties {
+    pub mag: Option<f64>,
+    pub place: Option<String>,
+    /// UNIX timestamp of the event in milliseconds.
+    pub time: i64,
+    /// Set to 1 when a tsunami advisory was issued for the event.
+    pub tsunami: i32,
+    /// PAGER alert level (green, yellow, orange, red).
+    pub alert: Option<String>,
+    pub url: String,
+}
+
+/// Event location as `[longitude, latitude, depth_km]`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct QuakeGeometry {
+    pub coordinates: Vec<f64>,
+}
+
+/// Fetches earthquakes from the last 30 days near a location from the USGS feed.
+///
+/// # Arguments
+/// * `lat` - Latitude of the location in decimal degrees.
+/// * `lon` - Longitude of the location in decimal degrees.
+/// * `radius_km` - Search radius around the location, in kilometres.
+/// * `min_magnitude` - Events below this magnitude are ignored.
+///
+/// # Returns
+/// A `QuakeFeed` with the matching events, most recent first.
+#[cfg(feature = "blocking")]
+pub fn fetch_quakes(
+    lat: f64,
+    lon: f64,
+    radius_km: f64,
+    min_magnitude: f64,
+) -> Result<QuakeFeed, crate::WeatherError> {
+    let url: reqwest::Url = reqwest::Url::parse_with_params(
+        "https://earthquake.usgs.gov/fdsnws/event/1/query",
+        [
+            ("format", "geojson"),
+            ("latitude", lat.to_string().as_str()),
+            ("longitude", &lon.to_string()),
+            ("maxradiuskm", &radius_km.to_string()),
+            ("minmagnitude", &min_magnitude.to_string()),
+            ("orderby", "time"),
+            ("limit", "20"),
+        ],
+    )
+    .map_err(|e| crate::WeatherError::InvalidQuery(e.to_string()))?;
+    let response: reqwest::blocking::Response = crate::http::send(crate::http::client()?.get(url))?;
+    if response.status() != 200 {
+        return Err(crate::WeatherError::Status {
+            status: response.status().as_u16(),
+            message: None,
+        });
+    }
+    let json_body = response.text()?;
+    Ok(serde_json::from_str(&json_body)?)
+}
+
+/// Formats a UNIX timestamp in milliseconds as `YYYY-MM-DD HH:MM UTC`.
+pub fn format_utc(epoch_ms: i64) -> String {
+    format!(
+        "{} UTC",
+        crate::time::format_local(epoch_ms.div_euclid(1000), 0)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_utc_times() {
+        assert_eq!(format_utc(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_utc(1_754_053_259_999), "2025-08-01 13:00 UTC");
+        assert_eq!(format_utc(1_709_164_800_000), "2024-02-29 00:00 UTC");
+        assert_eq!(format_utc(-1), "1969-12-31 23:59 UTC");
+    }
+}
@@ -0,0 +1,1738 @@
+use std::io::Write;
+
+use crate::{
+    AirQuality, Alert, Astro, Current, Day, ForecastDay, FutureData, HistoryData, Hour, WeatherData,
+};
+use crate::{
+    air, art, aurora, auxiliary, color, commute, drift, drone, event, fasting, favorites, frost,
+    geo, hourly, i18n, ice, jobsite, layout, pets, pressure, quakes, schedule, snow, storms, sun,
+    tailwind, units, ventilation, windows,
+};
+
+/// Width of the separator lines framing the report.
+pub const REPORT_WIDTH: usize = 70;
+
+/// Returns the Unicode arrow for a wind direction, or `❓` if unknown.
+pub fn wind_arrow(wind_dir: &str) -> &'static str {
+    match wind_dir {
+        "N" | "NNW" => "⬆",
+        "NNE" | "NE" => "↗",
+        "ENE" | "E" => "➡",
+        "ESE" | "SE" => "↘",
+        "SSE" | "S" => "⬇",
+        "SSW" | "SW" => "↙",
+        "WSW" | "W" => "⬅",
+        "WNW" | "NW" => "↖",
+        _ => "❓",
+    }
+}
+
+/// Returns the moon emoji for a Weather API moon phase name, or `🌙` if unknown.
+pub fn moon_phase_icon(moon_phase: &str) -> &'static str {
+    match moon_phase {
+        "New Moon" => "🌑",
+        "Waxing Crescent" => "🌒",
+        "First Quarter" => "🌓",
+        "Waxing Gibbous" => "🌔",
+        "Full Moon" => "🌕",
+        "Waning Gibbous" => "🌖",
+        "Last Quarter" => "🌗",
+        "Waning Crescent" => "🌘",
+        _ => "🌙",
+    }
+}
+
+/// Returns the description of a US EPA Air Quality Index value.
+pub fn us_epa_index_text(index: i32) -> &'static str {
+    match index {
+        1 => "Good",
+        2 => "Moderate",
+        3 => "Unhealthy for sensitive group",
+        4 => "Unhealthy",
+        5 => "Very Unhealthy",
+        6 => "Hazardous",
+        _ => "Unknown",
+    }
+}
+
+/// Writes the list of earthquakes near a location.
+///
+/// # Arguments
+/// * `weather` - Weather data of the searched location, used for its name and coordinates.
+/// * `feed` - Earthquakes returned by the USGS feed.
+/// * `radius_km` - Search radius shown in the header.
+/// * `min_magnitude` - Minimum magnitude shown in the header.
+/// * `out` - Destination of the rendered list.
+pub fn render_quakes<W: Write>(
+    weather: &WeatherData,
+    feed: &quakes::QuakeFeed,
+    radius_km: f64,
+    min_magnitude: f64,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let (lat, lon) = (weather.location.lat, weather.location.lon);
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\n{} M{min_magnitude}+ {} {radius_km} km ({})\n",
+        weather.location,
+        i18n::label("Earthquakes"),
+        i18n::label("within"),
+        i18n::label("last 30 days"),
+    )?;
+    if feed.features.is_empty() {
+        writeln!(out, "No earthquakes reported.")?;
+    }
+    for quake in &feed.features {
+        let distance: f64 = match quake.geometry.coordinates.as_slice() {
+            [q_lon, q_lat, ..] => geo::distance_km(lat, lon, *q_lat, *q_lon),
+            _ => f64::NAN,
+        };
+        writeln!(
+            out,
+            "  - M{:.1} {} ({:.0} km away) {}",
+            quake.properties.mag.unwrap_or(0.0),
+            quake.properties.place.as_deref().unwrap_or("Unknown place"),
+            distance,
+            quakes::format_utc(quake.properties.time),
+        )?;
+        if let Some(alert) = &quake.properties.alert {
+            writeln!(out, "    PAGER alert: {}", alert.to_uppercase())?;
+        }
+        if quake.properties.tsunami == 1 {
+            writeln!(
+                out,
+                "    ⚠ Tsunami advisory issued, see https://www.tsunami.gov"
+            )?;
+        }
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes tonight's aurora outlook for a location.
+pub fn render_aurora<W: Write>(
+    weather: &WeatherData,
+    outlook: &aurora::AuroraOutlook,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\n{} (NOAA SWPC)\n",
+        weather.location,
+        i18n::label("Aurora outlook for tonight")
+    )?;
+    match (&outlook.dark_from, &outlook.dark_until) {
+        (Some(from), Some(until)) => writeln!(out, "  Dark hours: {from} to {until}")?,
+        _ => writeln!(out, "  The sun does not set tonight.")?,
+    }
+    writeln!(
+        out,
+        "  Kp index (max tonight): {:.1}\tOverhead from Kp: {:.1}",
+        outlook.kp, outlook.overhead_kp
+    )?;
+    writeln!(
+        out,
+        "  Geomagnetic latitude: {:.1}°\tCloud cover: {:.0}%\tMoon: {}%",
+        outlook.geomagnetic_lat, outlook.cloud, outlook.moon_illumination
+    )?;
+    let rating: &str = match outlook.probability {
+        0..=9 => "unlikely",
+        10..=39 => "low",
+        40..=69 => "moderate",
+        _ => "high",
+    };
+    writeln!(
+        out,
+        "\n  Chance of seeing the aurora: {}% ({rating})",
+        outlook.probability
+    )?;
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the active tropical cyclones with their distance and nearby forecast positions.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location, used for its name and coordinates.
+/// * `active` - Active storms with their forecast track.
+/// * `radius_km` - Forecast positions farther than this from the location are omitted.
+/// * `out` - Destination of the rendered list.
+pub fn render_storms<W: Write>(
+    weather: &WeatherData,
+    active: &[(storms::Storm, Vec<storms::TrackPoint>)],
+    radius_km: f64,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let (lat, lon) = (weather.location.lat, weather.location.lon);
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\n{} (NHC)\n",
+        weather.location,
+        i18n::label("Active tropical cyclones")
+    )?;
+    if active.is_empty() {
+        writeln!(out, "No active storms.")?;
+    }
+    for (storm, track) in active {
+        let (s_lat, s_lon) = (storm.latitude_numeric, storm.longitude_numeric);
+        writeln!(
+            out,
+            "  - {} {} ({}): {} km {} of the location",
+            storms::classification_text(&storm.classification),
+            storm.name,
+            storm.id.to_uppercase(),
+            geo::distance_km(lat, lon, s_lat, s_lon).round(),
+            geo::compass_point(geo::bearing_deg(lat, lon, s_lat, s_lon)),
+        )?;
+        writeln!(
+            out,
+            "    Wind: {} kt\tPressure: {} mb\tMoving: {} at {} kt",
+            storm.intensity.as_deref().unwrap_or("?"),
+            storm.pressure.as_deref().unwrap_or("?"),
+            storm
+                .movement_dir
+                .map_or("?", |degrees| geo::compass_point(degrees)),
+            storm
+                .movement_speed
+                .map_or("?".to_owned(), |speed| speed.to_string()),
+        )?;
+        let nearby: Vec<(&storms::TrackPoint, f64)> = track
+            .iter()
+            .map(|point| (point, geo::distance_km(lat, lon, point.lat, point.lon)))
+            .filter(|(_, distance)| *distance <= radius_km)
+            .collect();
+        if !nearby.is_empty() {
+            writeln!(out, "    Forecast track within {radius_km} km:")?;
+        }
+        for (point, distance) in nearby {
+            writeln!(
+                out,
+                "      {}  {:.1}, {:.1}  ({} km)",
+                point.valid,
+                point.lat,
+                point.lon,
+                distance.round(),
+            )?;
+        }
+        if let Some(advisory) = &storm.public_advisory {
+            writeln!(out, "    Advisory: {}", advisory.url)?;
+        }
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes an hour-by-hour table, with a date line wherever the day changes.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `hours` - Forecast hours to list, in order.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered table.
+pub fn render_hourly<W: Write>(
+    weather: &WeatherData,
+    hours: &[&Hour],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let rows: Vec<[String; 5]> = hours
+        .iter()
+        .map(|hour| {
+            [
+                hour.time[11..].to_owned(),
+                units.temp(hour.temp_c, hour.temp_f),
+                hour.condition.text.clone(),
+                units.rain(hour.chance_of_rain, hour.precip_mm, hour.precip_in),
+                format!(
+                    "{} {}",
+                    units.speed(hour.wind_kph, hour.wind_mph),
+                    hour.wind_dir
+                ),
+            ]
+        })
+        .collect();
+    let header: [&str; 5] = ["Time", "Temp", "Condition", "Rain", "Wind"].map(i18n::label);
+    let lines: Vec<String> = table_lines(header, &rows);
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\n{}\n",
+        weather.location,
+        i18n::label("Hourly forecast")
+    )?;
+    writeln!(out, "{}", lines[0])?;
+    let mut date: Option<&str> = None;
+    for (hour, line) in hours.iter().zip(&lines[1..]) {
+        if date != Some(&hour.time[..10]) {
+            date = Some(&hour.time[..10]);
+            writeln!(out, "  {}", &hour.time[..10])?;
+        }
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes a compact table comparing the current conditions of several locations.
+///
+/// # Arguments
+/// * `weathers` - Weather data of each location, in the order given.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered table.
+pub fn render_compare<W: Write>(
+    weathers: &[WeatherData],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let rows: Vec<[String; 6]> = weathers
+        .iter()
+        .map(|weather| {
+            let current = &weather.current;
+            let today: Option<&ForecastDay> = weather.forecast.forecastday.first();
+            [
+                weather.location.short_name(),
+                weather
+                    .location
+                    .localtime
+                    .get(11..)
+                    .unwrap_or("")
+                    .to_owned(),
+                units.temp(current.temp_c, current.temp_f),
+                current.condition.text.clone(),
+                today.map_or_else(String::new, |today| {
+                    units.rain(
+                        today.day.daily_chance_of_rain,
+                        today.day.totalprecip_mm,
+                        today.day.totalprecip_in,
+                    )
+                }),
+                format!(
+                    "{} {}",
+                    units.speed(current.wind_kph, current.wind_mph),
+                    current.wind_dir
+                ),
+            ]
+        })
+        .collect();
+    let header: [&str; 6] =
+        ["Location", "Time", "Temp", "Condition", "Rain", "Wind"].map(i18n::label);
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    for line in table_lines(header, &rows) {
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the weather observed on a past date beside today's forecast.
+///
+/// # Arguments
+/// * `history` - Weather observed on the past date.
+/// * `today` - Weather data of the same location, with today's forecast first.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered table.
+pub fn render_history<W: Write>(
+    history: &HistoryData,
+    today: &WeatherData,
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let days: Vec<&ForecastDay> = history
+        .day()
+        .into_iter()
+        .chain(today.forecast.forecastday.first())
+        .collect();
+    let row = |label: &str, value: &dyn Fn(&Day) -> String| -> [String; 3] {
+        let mut cells = days.iter().map(|forecast_day| value(&forecast_day.day));
+        [
+            label.to_owned(),
+            cells.next().unwrap_or_default(),
+            cells.next().unwrap_or_default(),
+        ]
+    };
+    let rows: Vec<[String; 3]> = vec![
+        row(i18n::label("High"), &|day| {
+            units.temp(day.maxtemp_c, day.maxtemp_f)
+        }),
+        row(i18n::label("Low"), &|day| {
+            units.temp(day.mintemp_c, day.mintemp_f)
+        }),
+        row(i18n::label("Average"), &|day| {
+            units.temp(day.avgtemp_c, day.avgtemp_f)
+        }),
+        row(i18n::label("Precipitation"), &|day| {
+            units.precip(day.totalprecip_mm, day.totalprecip_in)
+        }),
+        row(i18n::label("Max wind"), &|day| {
+            units.speed(day.maxwind_kph, day.maxwind_mph)
+        }),
+        row(i18n::label("Humidity"), &|day| {
+            format!("{}%", day.avghumidity)
+        }),
+        row(i18n::label("Condition"), &|day| day.condition.text.clone()),
+    ];
+    let past: &str = history.day().map_or("", |day| day.date.as_str());
+    let present: String = days.get(1).map_or(i18n::label("Today").to_owned(), |day| {
+        format!("{} ({})", i18n::label("Today"), day.date)
+    });
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(out, "{}\n", history.location)?;
+    for line in table_lines(["", past, &present], &rows) {
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the long-range forecast of a date.
+///
+/// # Arguments
+/// * `future` - Long-range forecast of the date.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered summary.
+pub fn render_future<W: Write>(
+    future: &FutureData,
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(out, "{}", future.location)?;
+    match future.day() {
+        None => writeln!(out, "\nNo forecast for this date.")?,
+        Some(forecast_day) => {
+            let day = &forecast_day.day;
+            writeln!(
+                out,
+                "Long-range forecast for {}, indicative only\n",
+                forecast_day.date
+            )?;
+            let rows: Vec<[String; 2]> = [
+                ("High", units.temp(day.maxtemp_c, day.maxtemp_f)),
+                ("Low", units.temp(day.mintemp_c, day.mintemp_f)),
+                ("Average", units.temp(day.avgtemp_c, day.avgtemp_f)),
+                (
+                    "Precipitation",
+                    units.precip(day.totalprecip_mm, day.totalprecip_in),
+                ),
+                ("Max wind", units.speed(day.maxwind_kph, day.maxwind_mph)),
+                ("Humidity", format!("{}%", day.avghumidity)),
+                ("UV", day.uv.to_string()),
+                ("Condition", day.condition.text.clone()),
+                (
+                    "Sun",
+                    format!(
+                        "{} – {}",
+                        forecast_day.astro.sunrise, forecast_day.astro.sunset
+                    ),
+                ),
+            ]
+            .into_iter()
+            .map(|(label, value)| [label.to_owned(), value])
+            .collect();
+            // Skip the empty header line.
+            for line in table_lines(["", ""], &rows).iter().skip(1) {
+                writeln!(out, "{line}")?;
+            }
+        }
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the setup checks and the schema drift of a live response.
+///
+/// # Arguments
+/// * `checks` - Label and outcome of each setup check.
+/// * `drift` - Drift of the forecast response, or why it could not be fetched.
+/// * `out` - Destination of the rendered checks.
+pub fn render_doctor<W: Write>(
+    checks: &[(&str, String)],
+    drift: Result<&drift::Drift, String>,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let rows: Vec<[String; 2]> = checks
+        .iter()
+        .map(|(label, outcome)| [format!("{label}:"), outcome.clone()])
+        .collect();
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    for line in table_lines(["", ""], &rows).iter().skip(1) {
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out)?;
+    match drift {
+        Err(reason) => writeln!(out, "Schema: not checked, {reason}")?,
+        Ok(drift) if drift.is_empty() => {
+            writeln!(out, "Schema: the response matches the model")?;
+        }
+        Ok(drift) => {
+            writeln!(
+                out,
+                "Schema: {} unknown and {} missing fields",
+                drift.unknown.len(),
+                drift.missing.len()
+            )?;
+            for path in &drift.unknown {
+                writeln!(out, "  + {path}")?;
+            }
+            for path in &drift.missing {
+                writeln!(out, "  - {path}")?;
+            }
+        }
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Lays out a header and rows as left-aligned columns.
+///
+/// # Returns
+/// The header line followed by one line per row, indented by two spaces.
+fn table_lines<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> Vec<String> {
+    let header: [String; N] = header.map(str::to_owned);
+    let widths: Vec<usize> = (0..N)
+        .map(|column| {
+            std::iter::once(&header)
+                .chain(rows)
+                .map(|row| layout::display_width(&row[column]))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    std::iter::once(&header)
+        .chain(rows)
+        .map(|row| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| layout::pad_right(cell, *width))
+                .collect();
+            format!("  {}", cells.join("  ")).trim_end().to_owned()
+        })
+        .collect()
+}
+
+/// Writes the hours with the best tailwind along a travel heading.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `heading_deg` - Direction of travel, clockwise from north.
+/// * `ranked` - Hours ranked by tailwind, strongest first.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered table.
+pub fn render_tailwind<W: Write>(
+    weather: &WeatherData,
+    heading_deg: f64,
+    ranked: &[tailwind::TailwindHour],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    // Components are labelled by their side, none once they round to zero.
+    let component = |kph: f64, positive: &str, negative: &str| -> String {
+        let rounded: f64 = (kph.abs() * 10.0).round() / 10.0;
+        match rounded {
+            0.0 => units.speed_kph(0.0),
+            _ if kph > 0.0 => format!("{} {positive}", units.speed_kph(rounded)),
+            _ => format!("{} {negative}", units.speed_kph(rounded)),
+        }
+    };
+    let rows: Vec<[String; 4]> = ranked
+        .iter()
+        .map(|entry| {
+            let hour: &Hour = entry.hour;
+            [
+                entry.time.to_owned(),
+                component(entry.tailwind_kph, "tail", "head"),
+                component(entry.crosswind_kph, "from left", "from right"),
+                format!(
+                    "{} {}",
+                    units.speed(hour.wind_kph, hour.wind_mph),
+                    hour.wind_dir
+                ),
+            ]
+        })
+        .collect();
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\n{} {heading_deg}° ({})\n",
+        weather.location,
+        i18n::label("Best tailwind heading"),
+        geo::compass_point(heading_deg)
+    )?;
+    if rows.is_empty() {
+        writeln!(out, "  No forecast hours to compare.")?;
+    } else {
+        for line in table_lines(["Time", "Along", "Across", "Wind"].map(i18n::label), &rows) {
+            writeln!(out, "{line}")?;
+        }
+    }
+    if ranked.first().is_some_and(|best| best.tailwind_kph < 0.0) {
+        writeln!(out, "\n  Headwind all along; the lightest is shown first.")?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the saved locations, marking the default one.
+pub fn render_locations<W: Write>(
+    favorites: &favorites::Favorites,
+    out: &mut W,
+) -> std::io::Result<()> {
+    if favorites.locations.is_empty() {
+        return writeln!(
+            out,
+            "No saved locations, add one with: mosm-rs locations add home \"London\""
+        );
+    }
+    let width: usize = favorites
+        .locations
+        .keys()
+        .map(|name| layout::display_width(name) + 1)
+        .max()
+        .unwrap_or(0);
+    for (name, query) in &favorites.locations {
+        let marker: &str = if favorites.default.as_ref() == Some(name) {
+            "  (default)"
+        } else {
+            ""
+        };
+        writeln!(
+            out,
+            "  {}  {query}{marker}",
+            layout::pad_right(&format!("@{name}"), width)
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the drone flight verdict of each coming hour with the limits it nears or breaks.
+///
+/// # Arguments
+/// * `weather` - Weather data of the flying site.
+/// * `limits` - Aircraft limits the hours were checked against.
+/// * `checks` - Verdict of each hour.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered table.
+pub fn render_drone<W: Write>(
+    weather: &WeatherData,
+    limits: &drone::Limits,
+    checks: &[drone::HourCheck],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let describe = |concern: &drone::Concern| -> String {
+        let (value, limit) = (concern.value, concern.limit);
+        match concern.factor {
+            drone::Factor::Wind => format!(
+                "wind {} (max {})",
+                units.speed_kph(value),
+                units.speed_kph(limit)
+            ),
+            drone::Factor::Gust => format!(
+                "gusts {} (max {})",
+                units.speed_kph(value),
+                units.speed_kph(limit)
+            ),
+            drone::Factor::Precip => format!("rain {}", units.precip_mm(value)),
+            drone::Factor::Visibility => format!(
+                "visibility {} (min {})",
+                units.distance_km(value),
+                units.distance_km(limit)
+            ),
+            drone::Factor::Cold => format!("{} (min {})", units.temp_c(value), units.temp_c(limit)),
+            drone::Factor::Heat => format!("{} (max {})", units.temp_c(value), units.temp_c(limit)),
+            drone::Factor::Kp => format!("Kp {value:.1} (max {limit})"),
+        }
+    };
+    let rows: Vec<[String; 3]> = checks
+        .iter()
+        .map(|check| {
+            let mut notes: Vec<String> = check.concerns.iter().map(describe).collect();
+            notes.extend(check.unknown.iter().map(|factor| {
+                let name: &str = match factor {
+                    drone::Factor::Wind => "wind",
+                    drone::Factor::Gust => "gusts",
+                    drone::Factor::Precip => "rain",
+                    drone::Factor::Visibility => "visibility",
+                    drone::Factor::Cold | drone::Factor::Heat => "temperature",
+                    drone::Factor::Kp => "Kp",
+                };
+                format!("{name} unknown")
+            }));
+            if event::THUNDER_CODES.contains(&check.hour.condition.code) {
+                notes.push("thunderstorms".to_owned());
+            }
+            [
+                check.time[11..].to_owned(),
+                check.verdict.to_string(),
+                notes.join(", "),
+            ]
+        })
+        .collect();
+    let lines: Vec<String> = table_lines(["Time", "Verdict", "Concerns"].map(i18n::label), &rows);
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\n{}",
+        weather.location,
+        i18n::label("Drone flight check")
+    )?;
+    writeln!(
+        out,
+        "Limits: wind ≤ {}, gusts ≤ {}, rain ≤ {}, visibility ≥ {}, {} to {}, Kp ≤ {}\n",
+        units.speed_kph(limits.max_wind_kph),
+        units.speed_kph(limits.max_gust_kph),
+        units.precip_mm(limits.max_precip_mm),
+        units.distance_km(limits.min_vis_km),
+        units.temp_c(limits.min_temp_c),
+        units.temp_c(limits.max_temp_c),
+        limits.max_kp,
+    )?;
+    writeln!(out, "{}", lines[0])?;
+    let mut date: Option<&str> = None;
+    for (check, line) in checks.iter().zip(&lines[1..]) {
+        if date != Some(&check.time[..10]) {
+            date = Some(&check.time[..10]);
+            writeln!(out, "  {}", &check.time[..10])?;
+        }
+        writeln!(out, "{line}")?;
+    }
+    if checks.iter().all(|check| check.kp.is_none()) {
+        writeln!(
+            out,
+            "\n  Kp forecast unavailable, geomagnetic activity not checked."
+        )?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the jobsite safety verdict of each remaining day-part.
+///
+/// # Arguments
+/// * `weather` - Weather data of the site.
+/// * `trade` - Name of the trade whose limits apply.
+/// * `thresholds` - Limits of the trade.
+/// * `parts` - Summary of each day-part.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered summary.
+pub fn render_jobsite<W: Write>(
+    weather: &WeatherData,
+    trade: &str,
+    thresholds: &jobsite::Thresholds,
+    parts: &[jobsite::DayPart],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let describe = |concern: &jobsite::Concern| -> String {
+        let (value, limit) = (concern.value, concern.limit);
+        match concern.hazard {
+            jobsite::Hazard::Heat => format!(
+                "heat index {} (max {})",
+                units.temp_c(value),
+                units.temp_c(limit)
+            ),
+            jobsite::Hazard::Wind => format!(
+                "wind {} (max {})",
+                units.speed_kph(value),
+                units.speed_kph(limit)
+            ),
+            jobsite::Hazard::Gust => format!(
+                "gusts {} (max {})",
+                units.speed_kph(value),
+                units.speed_kph(limit)
+            ),
+            jobsite::Hazard::Precip => format!(
+                "rain {}/h (max {})",
+                units.precip_mm(value),
+                units.precip_mm(limit)
+            ),
+            jobsite::Hazard::Lightning => "lightning".to_owned(),
+        }
+    };
+    let rows: Vec<[String; 3]> = parts
+        .iter()
+        .map(|part| {
+            let mut notes: Vec<String> = part.concerns.iter().map(describe).collect();
+            notes.extend(part.unknown.iter().map(|hazard| {
+                let name: &str = match hazard {
+                    jobsite::Hazard::Heat => "heat index",
+                    jobsite::Hazard::Wind => "wind",
+                    jobsite::Hazard::Gust => "gusts",
+                    jobsite::Hazard::Precip => "rain",
+                    jobsite::Hazard::Lightning => "lightning",
+                };
+                format!("{name} unknown")
+            }));
+            [
+                part.name.to_owned(),
+                part.verdict.to_string(),
+                notes.join(", "),
+            ]
+        })
+        .collect();
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\n{}: {trade}",
+        weather.location,
+        i18n::label("Jobsite safety")
+    )?;
+    writeln!(
+        out,
+        "Limits: heat index ≤ {}, wind ≤ {}, gusts ≤ {}, rain ≤ {}/h\n",
+        units.temp_c(thresholds.max_heat_index_c),
+        units.speed_kph(thresholds.max_wind_kph),
+        units.speed_kph(thresholds.max_gust_kph),
+        units.precip_mm(thresholds.max_precip_mm),
+    )?;
+    let lines: Vec<String> =
+        table_lines(["Day-part", "Verdict", "Concerns"].map(i18n::label), &rows);
+    writeln!(out, "{}", lines[0])?;
+    let mut date: Option<&str> = None;
+    for (part, line) in parts.iter().zip(&lines[1..]) {
+        if date != Some(&part.date) {
+            date = Some(&part.date);
+            writeln!(out, "  {}", part.date)?;
+        }
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the frost stage of each night at the field locations.
+///
+/// # Arguments
+/// * `fields` - Weather data of each location with its nights.
+/// * `stages` - Temperatures at which the stages start.
+/// * `units` - Unit system for measurements.
+pub fn render_frost<W: Write>(
+    fields: &[(&WeatherData, Vec<frost::Night>)],
+    stages: &frost::Stages,
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let rows: Vec<[String; 4]> = fields
+        .iter()
+        .flat_map(|(_, nights)| nights)
+        .map(|night| {
+            [
+                night.date.clone(),
+                night
+                    .stage
+                    .map_or("-".to_owned(), |stage| stage.to_string()),
+                format!(
+                    "{} at {}",
+                    units.temp_c(night.min_temp_c),
+                    &night.min_at[11..]
+                ),
+                format!(
+                    "{}{}",
+                    night
+                        .dewpoint_c
+                        .map_or(units::NOT_AVAILABLE.to_owned(), |dewpoint| units
+                            .temp_c(dewpoint)),
+                    if night.clear { ", clear sky" } else { "" }
+                ),
+            ]
+        })
+        .collect();
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "Frost stages: advisory ≤ {}, warning ≤ {}, critical ≤ {}\n",
+        units.temp_c(stages.advisory_c),
+        units.temp_c(stages.warning_c),
+        units.temp_c(stages.critical_c),
+    )?;
+    let lines: Vec<String> = table_lines(["Night", "Stage", "Lowest", "Dew point"], &rows);
+    writeln!(out, "{}", lines[0])?;
+    let mut lines = lines[1..].iter();
+    for (weather, nights) in fields {
+        writeln!(out, "  {}", weather.location)?;
+        for line in lines.by_ref().take(nights.len()) {
+            writeln!(out, "{line}")?;
+        }
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the time ranges left today to air the room.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `indoor` - Indoor conditions to keep.
+/// * `found` - Ranges of suitable hours, earliest first.
+/// * `units` - Unit system for temperatures.
+pub fn render_ventilation<W: Write>(
+    weather: &WeatherData,
+    indoor: &ventilation::Indoor,
+    found: &[windows::Window],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\nAiring today: indoors {}, {}% (dew point {})\n",
+        weather.location,
+        units.temp_c(indoor.temp_c),
+        indoor.humidity,
+        units.temp_c(indoor.dewpoint_c()),
+    )?;
+    if found.is_empty() {
+        writeln!(
+            out,
+            "Keep the windows closed: outdoor air is warmer or more humid."
+        )?;
+    }
+    for window in found {
+        let coolest: &Hour = window
+            .hours
+            .iter()
+            .copied()
+            .min_by(|a, b| a.temp_c.total_cmp(&b.temp_c))
+            .expect("windows are never empty");
+        writeln!(
+            out,
+            "  - {}–{}  down to {}, {}%",
+            window.start(),
+            window.end(),
+            units.temp(coolest.temp_c, coolest.temp_f),
+            coolest.humidity,
+        )?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the coming hours with the values of an auxiliary source, marking the
+/// lowest ones.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `(label, unit)` - Label and unit of the values, from the source.
+/// * `rows` - Forecast hours with their values.
+/// * `units` - Unit system for temperatures.
+pub fn render_energy<W: Write>(
+    weather: &WeatherData,
+    (label, unit): (&str, &str),
+    rows: &[auxiliary::AuxHour],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let mut lowest: Vec<f64> = rows.iter().filter_map(|row| row.value).collect();
+    lowest.sort_by(f64::total_cmp);
+    let cheap: f64 = lowest.get(2).or(lowest.last()).copied().unwrap_or(f64::MIN);
+    let cells: Vec<[String; 4]> = rows
+        .iter()
+        .map(|row| {
+            [
+                row.time[5..].to_owned(),
+                row.value.map_or("-".to_owned(), |value| {
+                    format!("{value:.2}{}", if value <= cheap { " ★" } else { "" })
+                }),
+                units.temp(row.hour.temp_c, row.hour.temp_f),
+                format!("{:.0}", row.short_rad),
+            ]
+        })
+        .collect();
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(out, "{}\n★ the 3 lowest\n", weather.location)?;
+    let price_header: String = format!("{label} ({unit})");
+    for line in table_lines(["Time", &price_header, "Temp", "Sun (W/m²)"], &cells) {
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the sun's position and the shadow it casts.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `at` - Local time of the position (`YYYY-MM-DD HH:MM`).
+/// * `position` - Sun position at that time.
+/// * `out` - Destination of the rendered position.
+pub fn render_sunpos<W: Write>(
+    weather: &WeatherData,
+    at: &str,
+    position: &sun::SunPosition,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(out, "{}\nSun position at {at}\n", weather.location)?;
+    writeln!(
+        out,
+        "  Azimuth: {:.1}° ({})\tElevation: {:.1}°",
+        position.azimuth_deg,
+        geo::compass_point(position.azimuth_deg),
+        position.elevation_deg,
+    )?;
+    match position.shadow_ratio() {
+        Some(ratio) => writeln!(
+            out,
+            "  Shadow: {ratio:.2} m per metre of height, pointing {} ({:.1}°)",
+            geo::compass_point(position.shadow_bearing_deg()),
+            position.shadow_bearing_deg(),
+        )?,
+        None => writeln!(out, "  The sun is below the horizon.")?,
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the daily fasting window, from dawn to sunset.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `days` - Fasting window of each day.
+/// * `dawn_angle` - Depression of the sun at dawn used for the times, in degrees.
+/// * `generic` - Label the columns dawn/dusk instead of suhoor/iftar.
+/// * `out` - Destination of the rendered table.
+pub fn render_fasting<W: Write>(
+    weather: &WeatherData,
+    days: &[fasting::FastingDay],
+    dawn_angle: f64,
+    generic: bool,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let (start, end): (&str, &str) = if generic {
+        ("Dawn", "Dusk")
+    } else {
+        ("Suhoor ends", "Iftar")
+    };
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\nFasting times (dawn with the sun {dawn_angle}° below the horizon)\n",
+        weather.location
+    )?;
+    writeln!(out, "  Date        {start:<11}  {end:<5}  Fast")?;
+    for day in days {
+        writeln!(
+            out,
+            "  {}  {:<11}  {:<5}  {}",
+            day.date,
+            day.dawn.as_deref().unwrap_or("--:--"),
+            day.sunset.as_deref().unwrap_or("--:--"),
+            day.fast_secs.map_or("-".to_owned(), format_daylight),
+        )?;
+    }
+    if days.iter().any(|day| day.dawn.is_none()) {
+        writeln!(
+            out,
+            "\n  --:-- The sun does not sink far enough below the horizon, see local guidance."
+        )?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the daily sun schedule: dawn, sunrise, solar noon, sunset and dusk.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `days` - Schedule of each day.
+/// * `depression` - Depression of the sun at dawn and dusk, in degrees.
+/// * `out` - Destination of the rendered table.
+pub fn render_schedule<W: Write>(
+    weather: &WeatherData,
+    days: &[schedule::DaySchedule],
+    depression: f64,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let time = |event: &Option<schedule::SunEvent>| -> String {
+        event
+            .as_ref()
+            .map_or("--:--".to_owned(), |event| event.time.clone())
+    };
+    let rows: Vec<[String; 6]> = days
+        .iter()
+        .map(|day| {
+            [
+                day.date.clone(),
+                time(&day.dawn),
+                time(&day.sunrise),
+                day.solar_noon.time.clone(),
+                time(&day.sunset),
+                time(&day.dusk),
+            ]
+        })
+        .collect();
+    let header = ["Date", "Dawn", "Sunrise", "Noon", "Sunset", "Dusk"];
+
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\nSun schedule (dawn and dusk with the sun {depression}° below the horizon)\n",
+        weather.location
+    )?;
+    for line in table_lines(header, &rows) {
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the hour-by-hour conditions and verdict for an event window.
+///
+/// # Arguments
+/// * `weather` - Weather data of the venue.
+/// * `at` - Local start time of the event.
+/// * `duration_minutes` - Length of the event.
+/// * `hours` - Forecast hours overlapping the event.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered summary.
+pub fn render_event<W: Write>(
+    weather: &WeatherData,
+    at: &str,
+    duration_minutes: u32,
+    hours: &[&Hour],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let summary: event::EventSummary = event::summarize(hours);
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\nEvent: {at} for {}h{:02}m\n",
+        weather.location,
+        duration_minutes / 60,
+        duration_minutes % 60,
+    )?;
+    let temps: Vec<String> = hours
+        .iter()
+        .map(|hour| units.temp(hour.temp_c, hour.temp_f))
+        .collect();
+    let temp_width: usize = temps
+        .iter()
+        .map(|temp| temp.chars().count())
+        .max()
+        .unwrap_or(0);
+    let condition_width: usize = hours
+        .iter()
+        .map(|hour| layout::display_width(&hour.condition.text))
+        .max()
+        .unwrap_or(0);
+    for (hour, temp) in hours.iter().zip(&temps) {
+        writeln!(
+            out,
+            "  {}  {temp:>temp_width$}  {}  (Rain: {}, Gusts: {})",
+            &hour.time[11..],
+            layout::pad_right(&hour.condition.text, condition_width),
+            units.rain(hour.chance_of_rain, hour.precip_mm, hour.precip_in),
+            units.speed_or_na(hour.gust_kph, hour.gust_mph),
+        )?;
+    }
+    writeln!(
+        out,
+        "\nTemp: {} to {}\tRain: up to {}% · {} total\tGusts: up to {}",
+        units.temp_c(summary.min_temp_c),
+        units.temp_c(summary.max_temp_c),
+        summary.max_chance_of_rain,
+        units.precip_mm((summary.total_precip_mm * 10.0).round() / 10.0),
+        summary
+            .max_gust_kph
+            .map_or(units::NOT_AVAILABLE.to_owned(), |gust| units
+                .speed_kph(gust)),
+    )?;
+    if summary.thunder {
+        writeln!(out, "⚡ Thunderstorms expected")?;
+    }
+    writeln!(out, "Verdict: {}", summary.verdict)?;
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the commute briefing.
+///
+/// # Arguments
+/// * `weather` - Weather data of the commute location.
+/// * `legs` - Label and local time (`HH:MM`) of each leg.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered briefing.
+pub fn render_commute<W: Write>(
+    weather: &WeatherData,
+    legs: &[(&str, String)],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(out, "{}\nCommute briefing\n", weather.location,)?;
+    for (label, time) in legs {
+        let Some(hour) = commute::next_hour_at(weather, time) else {
+            writeln!(out, "{label} {time}: no forecast available")?;
+            continue;
+        };
+        writeln!(
+            out,
+            "{label} {} {time}: {} | {} (Feels like: {})\tRain: {}\tWind: {} {}",
+            &hour.time[..10],
+            hour.condition.text,
+            units.temp(hour.temp_c, hour.temp_f),
+            units.temp(hour.feelslike_c, hour.feelslike_f),
+            units.rain(hour.chance_of_rain, hour.precip_mm, hour.precip_in),
+            wind_arrow(&hour.wind_dir),
+            units.speed(hour.wind_kph, hour.wind_mph),
+        )?;
+        for warning in commute::warnings(hour) {
+            writeln!(out, "  {warning}")?;
+        }
+        if let Some(risk) = ice::black_ice_risk(weather, hour) {
+            writeln!(out, "  ⚠ Black ice risk: {risk}")?;
+        }
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes the walk windows found for the coming day.
+///
+/// # Arguments
+/// * `weather` - Weather data of the location.
+/// * `criteria` - Criteria the windows were selected with.
+/// * `min_minutes` - Minimum window length.
+/// * `found` - Matching windows.
+/// * `units` - Unit system for measurements.
+/// * `out` - Destination of the rendered list.
+pub fn render_walk<W: Write>(
+    weather: &WeatherData,
+    criteria: &windows::Criteria,
+    min_minutes: u32,
+    found: &[windows::Window],
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    writeln!(
+        out,
+        "{}\nWalk windows: {}+ min, rain ≤ {}%, wind ≤ {}{}\n",
+        weather.location,
+        min_minutes,
+        criteria.max_chance_of_rain,
+        units.speed_kph(criteria.max_wind_kph),
+        if criteria.daylight_only {
+            ", daylight"
+        } else {
+            ""
+        },
+    )?;
+    if found.is_empty() {
+        writeln!(out, "No suitable window in the next 24 hours.")?;
+    }
+    for window in found {
+        let warmest: &Hour = window
+            .hours
+            .iter()
+            .copied()
+            .max_by(|a, b| a.temp_c.total_cmp(&b.temp_c))
+            .expect("windows are never empty");
+        writeln!(
+            out,
+            "  - {} {}–{} ({}h)  up to {}",
+            &window.hours[0].time[..10],
+            window.start(),
+            window.end(),
+            window.minutes() / 60,
+            units.temp(warmest.temp_c, warmest.temp_f),
+        )?;
+    }
+    writeln!(out, "<>{:-<REPORT_WIDTH$}<>", "")?;
+    Ok(())
+}
+
+/// Writes a single-line summary of the current conditions for non-interactive use.
+///
+/// The fields are always in the same order: location, temperature, condition,
+/// AQI and number of warnings.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data to summarize.
+/// * `units` - Unit system for the temperature.
+/// * `out` - Destination of the summary line.
+pub fn render_summary_line<W: Write>(
+    weather: &WeatherData,
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let warnings: usize = usize::from(
+        pressure::find_swing(
+            weather,
+            pressure::SWING_THRESHOLD_HPA,
+            pressure::SWING_WINDOW_HOURS,
+        )
+        .is_some(),
+    ) + weather.alerts.alert.len();
+    writeln!(
+        out,
+        "{} | {} | {} | AQI: {} | Warnings: {}",
+        weather.location.short_name(),
+        units.temp(weather.current.temp_c, weather.current.temp_f),
+        weather.current.condition.text,
+        weather
+            .current
+            .air_quality
+            .as_ref()
+            .map_or("N/A".to_owned(), |air_quality| air_quality
+                .us_epa_index
+                .to_string()),
+        warnings,
+    )
+}
+
+/// Writes the location header with its local time, and the coordinates and
+/// elevation when set on the [`Location`](crate::Location).
+pub fn render_header<W: Write>(weather: &WeatherData, out: &mut W) -> std::io::Result<()> {
+    writeln!(out, "{}", weather.location)?;
+    if weather.location.show_coordinates {
+        writeln!(
+            out,
+            "{}: {:.4}, {:.4}",
+            i18n::label("Coordinates"),
+            weather.location.lat,
+            weather.location.lon
+        )?;
+    }
+    if let Some(elevation_m) = weather.location.elevation_m {
+        writeln!(
+            out,
+            "{}: {elevation_m:.0} m ({:.0} ft)",
+            i18n::label("Elevation"),
+            elevation_m * 3.280_84
+        )?;
+    }
+    writeln!(
+        out,
+        "{}: {}\n",
+        i18n::label("Local Time"),
+        weather.location.localtime
+    )
+}
+
+/// Writes the current conditions, air quality and warnings.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data to render.
+/// * `units` - Unit system for temperatures, wind and precipitation.
+/// * `colors` - Whether to color temperatures, UV and AQI by severity.
+/// * `out` - Destination of the rendered section.
+pub fn render_current<W: Write>(
+    weather: &WeatherData,
+    units: units::Units,
+    colors: bool,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let current: &Current = &weather.current;
+    // The conditions, next to the art of the sky.
+    let mut block: Vec<u8> = Vec::new();
+    writeln!(
+        block,
+        "{} | {}\t{}: {}\n",
+        current.condition.text,
+        color::paint(
+            &units.temp(current.temp_c, current.temp_f),
+            color::temp(current.temp_c),
+            colors
+        ),
+        i18n::label("UV"),
+        current
+            .uv
+            .map_or(units::NOT_AVAILABLE.to_owned(), |uv| color::paint(
+                &uv.to_string(),
+                color::uv(uv),
+                colors
+            ))
+    )?;
+
+    writeln!(
+        block,
+        "{}: {}\t{}: {}%\t{}: {}",
+        i18n::label("Feels like"),
+        color::paint(
+            &units.temp(current.feelslike_c, current.feelslike_f),
+            color::temp(current.feelslike_c),
+            colors
+        ),
+        i18n::label("Humidity"),
+        current.humidity,
+        i18n::label("Rain"),
+        units.rain(
+            weather
+                .hour_at(&current.last_updated)
+                .map_or(0, |hour| hour.chance_of_rain),
+            current.precip_mm,
+            current.precip_in
+        )
+    )?;
+
+    writeln!(
+        block,
+        "{}: {} {} \t{}: {}\t{}: {}",
+        i18n::label("Wind"),
+        wind_arrow(&current.wind_dir),
+        units.speed(current.wind_kph, current.wind_mph),
+        i18n::label("Dew Point"),
+        units.temp_or_na(current.dewpoint_c, current.dewpoint_f),
+        i18n::label("Visibility"),
+        units.distance_or_na(current.vis_km, current.vis_miles)
+    )?;
+
+    render_air_quality(current.air_quality.as_ref(), colors, &mut block)?;
+
+    let sky: art::Sky = art::Sky::from_code(current.condition.code, current.is_day == 1);
+    let block: String = String::from_utf8_lossy(&block).into_owned();
+    let lines: Vec<&str> = block.lines().collect();
+    let art: [&str; 5] = sky.art();
+    for i in 0..lines.len().max(art.len()) {
+        let art_line: &str = art.get(i).copied().unwrap_or("");
+        let line: String = format!(
+            "{} {}",
+            layout::pad_right(art_line, art::ART_WIDTH),
+            lines.get(i).copied().unwrap_or("")
+        );
+        writeln!(out, "{}", line.trim_end())?;
+    }
+
+    // Chance of rain over the coming hours, at a glance.
+    let coming: Vec<&Hour> = hourly::upcoming(weather, 24);
+    if let Some(peak) = coming
+        .iter()
+        .copied()
+        .filter(|hour| hour.chance_of_rain > 0)
+        .max_by_key(|hour| hour.chance_of_rain)
+    {
+        writeln!(
+            out,
+            "Rain {} {}  peak {}% at {}",
+            &coming[0].time[11..],
+            layout::sparkline(
+                coming.iter().map(|hour| f64::from(hour.chance_of_rain)),
+                100.0
+            ),
+            peak.chance_of_rain,
+            &peak.time[11..]
+        )?;
+    }
+
+    if let Some(swing) = pressure::find_swing(
+        weather,
+        pressure::SWING_THRESHOLD_HPA,
+        pressure::SWING_WINDOW_HOURS,
+    ) {
+        writeln!(
+            out,
+            "⚠ {}: {:+.1} hPa {} {} {} {} ({})",
+            i18n::label("Pressure swing"),
+            swing.change_hpa,
+            i18n::label("between"),
+            swing.from,
+            i18n::label("and"),
+            swing.to,
+            i18n::label("migraine risk")
+        )?;
+    }
+    render_alerts(&weather.alerts.alert, out)
+}
+
+/// Writes the active weather warnings: severity, headline and validity.
+pub fn render_alerts<W: Write>(alerts: &[Alert], out: &mut W) -> std::io::Result<()> {
+    // `2025-08-01T14:00:00+01:00` to `2025-08-01 14:00`.
+    let time = |iso: &str| -> String { iso.get(..16).unwrap_or(iso).replace('T', " ") };
+    for alert in alerts {
+        let headline: &str = if alert.headline.is_empty() {
+            &alert.event
+        } else {
+            &alert.headline
+        };
+        writeln!(out, "⚠ {}: {headline}", alert.severity)?;
+        if !alert.effective.is_empty() || !alert.expires.is_empty() {
+            writeln!(
+                out,
+                "  {} to {}",
+                time(&alert.effective),
+                time(&alert.expires)
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the US EPA index with particulate levels.
+///
+/// # Arguments
+/// * `air_quality` - Air quality data, `None` if the response has none.
+/// * `colors` - Whether to color the index by severity.
+/// * `out` - Destination of the rendered line.
+pub fn render_air_quality<W: Write>(
+    air_quality: Option<&AirQuality>,
+    colors: bool,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let Some(air_quality) = air_quality else {
+        return writeln!(out, "AQI: {}", i18n::label("not available"));
+    };
+    writeln!(
+        out,
+        "AQI: {}\tPM2.5: {:.1} μg/m³\tPM10: {:.1} μg/m³",
+        color::paint(
+            us_epa_index_text(air_quality.us_epa_index),
+            color::us_epa_index(air_quality.us_epa_index),
+            colors
+        ),
+        air_quality.pm2_5,
+        air_quality.pm10,
+    )
+}
+
+/// Writes both air quality indices with health advice, and each pollutant with
+/// its DEFRA band.
+///
+/// # Arguments
+/// * `air_quality` - Air quality data, `None` if the response has none.
+/// * `colors` - Whether to color the indices and bands by severity.
+/// * `out` - Destination of the rendered section.
+pub fn render_aqi<W: Write>(
+    air_quality: Option<&AirQuality>,
+    colors: bool,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let Some(air_quality) = air_quality else {
+        return writeln!(out, "AQI: {}", i18n::label("not available"));
+    };
+    writeln!(out, "▶ Air quality:")?;
+    let us_epa: String = format!(
+        "{} {}",
+        air_quality.us_epa_index,
+        us_epa_index_text(air_quality.us_epa_index)
+    );
+    let defra: String = format!(
+        "{} {}",
+        air_quality.gb_defra_index,
+        air::defra_text(air_quality.gb_defra_index)
+    );
+    let width: usize = us_epa.chars().count().max(defra.chars().count());
+    writeln!(
+        out,
+        "  US EPA:    {}  {}",
+        color::paint(
+            &layout::pad_right(&us_epa, width),
+            color::us_epa_index(air_quality.us_epa_index),
+            colors
+        ),
+        air::us_epa_advice(air_quality.us_epa_index)
+    )?;
+    writeln!(
+        out,
+        "  UK DEFRA:  {}  {}",
+        color::paint(
+            &layout::pad_right(&defra, width),
+            color::defra_index(air_quality.gb_defra_index),
+            colors
+        ),
+        air::defra_advice(air_quality.gb_defra_index)
+    )?;
+
+    let rows: Vec<[String; 3]> = air::pollutants(air_quality)
+        .iter()
+        .map(|(name, value)| {
+            [
+                (*name).to_owned(),
+                format!("{value:.1}"),
+                air::defra_band(name, *value).map_or("-".to_owned(), |band| {
+                    color::paint(
+                        &format!("{band} {}", air::defra_text(band)),
+                        color::defra_index(band),
+                        colors,
+                    )
+                }),
+            ]
+        })
+        .collect();
+    for line in table_lines(["Pollutant", "μg/m³", "DEFRA band"], &rows) {
+        writeln!(out, "{line}")?;
+    }
+    Ok(())
+}
+
+/// Writes the daily forecast lines.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data to render.
+/// * `units` - Unit system for temperatures and precipitation.
+/// * `colors` - Whether to color temperatures and UV by severity.
+/// * `out` - Destination of the rendered section.
+pub fn render_forecast<W: Write>(
+    weather: &WeatherData,
+    units: units::Units,
+    colors: bool,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "▶ {}:", i18n::label("Forecast"))?;
+    let ice_risks: Vec<(&str, ice::IceRisk)> = ice::morning_risks(weather);
+    let pet_risks: Vec<(&str, pets::PetHeat)> = pets::afternoon_risks(weather);
+    for forecast_day in &weather.forecast.forecastday {
+        writeln!(
+            out,
+            "  - {}: {}, {} {} ({}: {}, {}: {})",
+            forecast_day.date,
+            color::paint(
+                &units.temp(forecast_day.day.maxtemp_c, forecast_day.day.maxtemp_f),
+                color::temp(forecast_day.day.maxtemp_c),
+                colors
+            ),
+            art::Sky::from_code(forecast_day.day.condition.code, true).glyph(),
+            forecast_day.day.condition.text,
+            i18n::label("Precip"),
+            units.rain(
+                forecast_day.day.daily_chance_of_rain,
+                forecast_day.day.totalprecip_mm,
+                forecast_day.day.totalprecip_in
+            ),
+            i18n::label("UV"),
+            color::paint(
+                &forecast_day.day.uv.to_string(),
+                color::uv(forecast_day.day.uv),
+                colors
+            )
+        )?;
+        if let Some((_, risk)) = ice_risks
+            .iter()
+            .find(|(date, _)| *date == forecast_day.date)
+        {
+            writeln!(out, "    ⚠ Black ice risk in the morning: {risk}")?;
+        }
+        if let Some((_, heat)) = pet_risks
+            .iter()
+            .find(|(date, _)| *date == forecast_day.date)
+        {
+            writeln!(
+                out,
+                "    ⚠ Afternoon heat for dogs ({}): pavement {}, heat index {} at {}",
+                heat.risk,
+                units.temp_c(heat.pavement_c),
+                units.temp_c(heat.heat_index_c),
+                &heat.time[11..],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the coming snow events with their running accumulation per day.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data to render.
+/// * `units` - Unit system for snow depths.
+/// * `out` - Destination of the rendered section.
+pub fn render_snow<W: Write>(
+    weather: &WeatherData,
+    units: units::Units,
+    out: &mut W,
+) -> std::io::Result<()> {
+    for event in snow::find_snow_events(weather) {
+        writeln!(
+            out,
+            "❄ Snow from {} to {}: {} total",
+            event.start(),
+            event.end(),
+            units.snow(event.total_cm()),
+        )?;
+        for (date, snowfall, accumulated) in event.daily_totals() {
+            writeln!(
+                out,
+                "    {date}: +{} (accumulated: {})",
+                units.snow(snowfall),
+                units.snow(accumulated),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Formats a duration in seconds as `15h 24m`.
+fn format_daylight(secs: i64) -> String {
+    format!("{}h {:02}m", secs / 3600, secs % 3600 / 60)
+}
+
+/// Formats a change in seconds as `+2m 14s` or `-45s`.
+fn format_change(secs: i64) -> String {
+    let sign: char = if secs < 0 { '-' } else { '+' };
+    let secs: i64 = secs.abs();
+    match secs / 60 {
+        0 => format!("{sign}{secs}s"),
+        minutes => format!("{sign}{minutes}m {:02}s", secs % 60),
+    }
+}
+
+/// Writes today's daylight trend, then sunrise, sunset and moon data for every forecast day.
+pub fn render_astro<W: Write>(weather: &WeatherData, out: &mut W) -> std::io::Result<()> {
+    let trend: sun::DaylightTrend = sun::daylight_trend(&weather.location);
+    writeln!(
+        out,
+        "  ☀ Daylight today: {} ({} vs yesterday, {} by next week)",
+        format_daylight(trend.today_secs),
+        format_change(trend.vs_yesterday_secs),
+        format_change(trend.vs_next_week_secs),
+    )?;
+    for forecast_day in &weather.forecast.forecastday {
+        let astro: &Astro = &forecast_day.astro;
+        writeln!(out, "  - {}", forecast_day.date)?;
+        writeln!(
+            out,
+            "    🌅 Sunrise {}\t🌇 Sunset {}",
+            astro.sunrise, astro.sunset
+        )?;
+        writeln!(
+            out,
+            "    🌙 Moonrise {}\tMoonset {}",
+            astro.moonrise, astro.moonset
+        )?;
+        writeln!(
+            out,
+            "    {} {} ({}% illuminated)",
+            moon_phase_icon(&astro.moon_phase),
+            astro.moon_phase,
+            astro.moon_illumination
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes the weather report for a location.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data to render.
+/// * `units` - Unit system for measurements.
+/// * `width` - Width of the separator lines framing the report.
+/// * `colors` - Whether to color temperatures, UV and AQI by severity.
+/// * `out` - Destination of the rendered report.
+pub fn render_report<W: Write>(
+    weather: &WeatherData,
+    units: units::Units,
+    width: usize,
+    colors: bool,
+    out: &mut W,
+) -> std::io::Result<()> {
+    writeln!(out, "<>{:-<width$}<>", "")?;
+    render_header(weather, out)?;
+    render_current(weather, units, colors, out)?;
+    writeln!(out)?;
+    render_forecast(weather, units, colors, out)?;
+    render_snow(weather, units, out)?;
+    writeln!(out, "<>{:-<width$}<>", "")?;
+    Ok(())
+}
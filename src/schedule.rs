@@ -0,0 +1,199 @@
+use std::io::Write;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::{Location, sun, time};
+
+/// Twilight used for dawn and dusk, by how far the sun is below the horizon.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Twilight {
+    /// 6°: bright enough to be outdoors without lights.
+    #[default]
+    Civil,
+    /// 12°: the horizon is still visible at sea.
+    Nautical,
+    /// 18°: the sky is fully dark in between.
+    Astronomical,
+}
+
+impl FromStr for Twilight {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Twilight, String> {
+        match name {
+            "civil" => Ok(Twilight::Civil),
+            "nautical" => Ok(Twilight::Nautical),
+            "astronomical" => Ok(Twilight::Astronomical),
+            _ => Err(format!("Unknown twilight {name}")),
+        }
+    }
+}
+
+impl Twilight {
+    /// Depression of the sun below the horizon, in degrees.
+    pub fn depression(self) -> f64 {
+        match self {
+            Twilight::Civil => 6.0,
+            Twilight::Nautical => 12.0,
+            Twilight::Astronomical => 18.0,
+        }
+    }
+}
+
+/// A moment of the daily schedule.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct SunEvent {
+    /// UNIX timestamp, in seconds.
+    pub epoch: i64,
+    /// Local time (`HH:MM`).
+    pub time: String,
+}
+
+/// Daily schedule of the sun at a location, in local time.
+///
+/// Events are `None` where the sun does not cross the altitude that day, as in
+/// polar day and night or the white nights for dawn and dusk.
+#[derive(Serialize, Debug)]
+pub struct DaySchedule {
+    /// Local date (`YYYY-MM-DD`).
+    pub date: String,
+    pub dawn: Option<SunEvent>,
+    pub sunrise: Option<SunEvent>,
+    pub solar_noon: SunEvent,
+    pub sunset: Option<SunEvent>,
+    pub dusk: Option<SunEvent>,
+}
+
+/// Computes the daily schedules for consecutive days from today.
+///
+/// # Arguments
+/// * `location` - Location with its local time, for today's date and UTC offset.
+/// * `days` - Number of days.
+/// * `depression` - Depression of the sun at dawn and dusk, in degrees.
+pub fn day_schedules(location: &Location, days: u32, depression: f64) -> Vec<DaySchedule> {
+    let offset: i64 = time::utc_offset(location);
+    let today: i64 = time::local_epoch(location.localtime.get(..10).unwrap_or(""), offset);
+    let (lat, lon) = (location.lat, location.lon);
+    let event = |epoch: i64| -> SunEvent {
+        SunEvent {
+            epoch,
+            time: time::format_local(epoch, offset)[11..].to_owned(),
+        }
+    };
+    (0..i64::from(days))
+        .map(|day| {
+            // Noon avoids landing on the wrong date across DST changes.
+            let date: String =
+                time::format_local(today + day * 86_400 + 43_200, offset)[..10].to_owned();
+            let at = |altitude: f64, morning: bool| -> Option<SunEvent> {
+                sun::time_at_altitude(lat, lon, &date, altitude, morning).map(event)
+            };
+            DaySchedule {
+                dawn: at(-depression, true),
+                sunrise: at(sun::HORIZON_ALTITUDE, true),
+                solar_noon: event(sun::solar_noon(lon, &date)),
+                sunset: at(sun::HORIZON_ALTITUDE, false),
+                dusk: at(-depression, false),
+                date,
+            }
+        })
+        .collect()
+}
+
+/// Writes the schedules as an iCalendar (RFC 5545) file, one instant event per moment.
+///
+/// Times are in UTC so that calendars place them correctly without a time zone
+/// definition.
+///
+/// # Arguments
+/// * `location` - Location named in the events.
+/// * `schedules` - Schedules from [`day_schedules`].
+/// * `stamp` - UNIX time the file is created at.
+/// * `out` - Destination of the calendar.
+pub fn write_ics<W: Write>(
+    location: &Location,
+    schedules: &[DaySchedule],
+    stamp: i64,
+    out: &mut W,
+) -> std::io::Result<()> {
+    let ics_time = |epoch: i64| -> String {
+        // `YYYY-MM-DD HH:MM` to `YYYYMMDDTHHMM00Z`.
+        let utc: String = time::format_local(epoch, 0).replace(['-', ':'], "");
+        format!("{}T{}00Z", &utc[..8], &utc[9..])
+    };
+    write!(
+        out,
+        "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nPRODID:-//mosm-rs//schedule//EN\r\n"
+    )?;
+    for schedule in schedules {
+        let events = [
+            ("Dawn", schedule.dawn.as_ref()),
+            ("Sunrise", schedule.sunrise.as_ref()),
+            ("Solar noon", Some(&schedule.solar_noon)),
+            ("Sunset", schedule.sunset.as_ref()),
+            ("Dusk", schedule.dusk.as_ref()),
+        ];
+        for (name, event) in events {
+            let Some(event) = event else { continue };
+            let start: String = ics_time(event.epoch);
+            write!(
+                out,
+                "BEGIN:VEVENT\r\nUID:{start}-{}-{:.2}_{:.2}@mosm-rs\r\nDTSTAMP:{}\r\nDTSTART:{start}\r\n\
+                 DTEND:{start}\r\nSUMMARY:{name}\r\nLOCATION:{}\r\nTRANSP:TRANSPARENT\r\n\
+                 END:VEVENT\r\n",
+                name.to_lowercase().replace(' ', "-"),
+                location.lat,
+                location.lon,
+                ics_time(stamp),
+                escape_text(&format!("{}, {}", location.name, location.country)),
+            )?;
+        }
+    }
+    write!(out, "END:VCALENDAR\r\n")
+}
+
+/// Escapes an iCalendar text value.
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn computes_schedule() {
+        let weather: crate::WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let days: Vec<DaySchedule> =
+            day_schedules(&weather.location, 2, Twilight::Civil.depression());
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[1].date, "2025-08-02");
+        let today: &DaySchedule = &days[0];
+        assert_eq!(today.sunset.as_ref().unwrap().time, "20:48");
+        assert_eq!(today.solar_noon.time, "13:06");
+        let times: Vec<&str> = [&today.dawn, &today.sunrise, &today.sunset, &today.dusk]
+            .map(|event| event.as_ref().unwrap().time.as_str())
+            .to_vec();
+        assert!(times.is_sorted());
+        assert!(("04:40".."05:00").contains(&times[0]));
+
+        // London sees astronomical night in early August, but the sun never sinks 30°.
+        let days: Vec<DaySchedule> =
+            day_schedules(&weather.location, 1, Twilight::Astronomical.depression());
+        assert!(days[0].dawn.is_some());
+        let days: Vec<DaySchedule> = day_schedules(&weather.location, 1, 30.0);
+        assert!(days[0].dawn.is_none());
+
+        let mut out: Vec<u8> = Vec::new();
+        write_ics(&weather.location, &days, 0, &mut out).unwrap();
+        let ics: String = String::from_utf8(out).unwrap();
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 3);
+        assert!(ics.contains("DTSTART:20250801T1206"));
+        assert!(ics.contains("LOCATION:London\\, United Kingdom\r\n"));
+    }
+}
@@ -0,0 +1,102 @@
+use serde_json::Value;
+
+/// Version of the JSON output, `MAJOR.MINOR`, independent of the upstream APIs.
+///
+/// Within a major version fields are only added (a minor bump), never removed,
+/// renamed or retyped, so parsers written against `1.0` keep working on `1.x`.
+pub const SCHEMA_VERSION: &str = "2.0";
+
+/// JSON Schema of the report printed with `--format json`.
+pub const REPORT_SCHEMA: &str = include_str!("../schema/report.schema.json");
+
+/// JSON Schema of the hours printed with `hourly --json`.
+pub const HOURLY_SCHEMA: &str = include_str!("../schema/hourly.schema.json");
+
+/// JSON Schema of the reports printed with `compare --json`.
+pub const COMPARE_SCHEMA: &str = include_str!("../schema/compare.schema.json");
+
+/// JSON Schema of the alerts printed with `--alerts-only --format json`.
+pub const ALERTS_SCHEMA: &str = include_str!("../schema/alerts.schema.json");
+
+/// Adds `schema_version` to a JSON value printed by the CLI.
+///
+/// Objects get the field; arrays, such as the hours of `hourly --json`, are wrapped
+/// as `{"schema_version": .., "items": [..]}`. Other values are returned unchanged.
+pub fn versioned(value: Value) -> Value {
+    let version: Value = Value::String(SCHEMA_VERSION.to_owned());
+    match value {
+        Value::Object(mut fields) => {
+            fields.insert("schema_version".to_owned(), version);
+            Value::Object(fields)
+        }
+        Value::Array(items) => serde_json::json!({
+            "schema_version": version,
+            "items": items,
+        }),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WeatherData;
+    use std::collections::BTreeSet;
+
+    /// Adds the path of every property of a schema, merging array items.
+    fn schema_paths(schema: &Value, prefix: &str, paths: &mut BTreeSet<String>) {
+        if let Some(items) = schema.get("items") {
+            schema_paths(items, &format!("{prefix}[]"), paths);
+        }
+        for (key, property) in schema["properties"].as_object().into_iter().flatten() {
+            let path: String = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{prefix}.{key}")
+            };
+            schema_paths(property, &path, paths);
+            paths.insert(path);
+        }
+    }
+
+    /// Checks that an output has exactly the fields of its schema.
+    fn assert_matches(schema: &str, output: Value) {
+        let schema: Value = serde_json::from_str(schema).unwrap();
+        let mut expected: BTreeSet<String> = BTreeSet::new();
+        schema_paths(&schema, "", &mut expected);
+        // Removing a field needs a major version; adding one, a schema update.
+        let actual: BTreeSet<String> = crate::drift::field_paths(&versioned(output));
+        assert_eq!(
+            actual.symmetric_difference(&expected).collect::<Vec<_>>(),
+            Vec::<&String>::new()
+        );
+        let major: &str = SCHEMA_VERSION.split('.').next().unwrap();
+        assert_eq!(
+            schema["properties"]["schema_version"]["pattern"],
+            format!("^{major}\\.")
+        );
+    }
+
+    #[test]
+    fn outputs_match_schemas() {
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        weather.alerts.alert.push(Default::default());
+        let report: Value = serde_json::to_value(&weather).unwrap();
+
+        assert_matches(REPORT_SCHEMA, report.clone());
+        assert_matches(COMPARE_SCHEMA, Value::Array(vec![report]));
+        assert_matches(
+            HOURLY_SCHEMA,
+            serde_json::to_value(weather.hours().collect::<Vec<_>>()).unwrap(),
+        );
+        assert_matches(
+            ALERTS_SCHEMA,
+            serde_json::to_value(&weather.alerts.alert).unwrap(),
+        );
+        assert_eq!(
+            versioned(serde_json::json!([1])),
+            serde_json::json!({"schema_version": SCHEMA_VERSION, "items": [1]})
+        );
+    }
+}
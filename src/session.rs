@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A recorded fetch that can be replayed offline.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Session {
+    /// UNIX timestamp of the recording, in seconds.
+    pub recorded_at: u64,
+    /// Location query as given by the user.
+    pub query: String,
+    /// Number of forecast days requested.
+    pub days: u32,
+    /// Raw response body from the Weather API.
+    pub response: serde_json::Value,
+}
+
+impl Session {
+    /// Creates a session for a response fetched just now.
+    pub fn new(query: &str, days: u32, response: serde_json::Value) -> Session {
+        let recorded_at: u64 = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        Session {
+            recorded_at,
+            query: query.to_owned(),
+            days,
+            response,
+        }
+    }
+
+    /// Writes the session as pretty-printed JSON.
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let json: String = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+    }
+
+    /// Reads a session previously written by [`Session::save`].
+    pub fn load(path: &str) -> std::io::Result<Session> {
+        let json: String = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
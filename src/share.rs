@@ -0,0 +1,67 @@
+use crate::{WeatherData, units};
+
+/// Maximum length of the share text, in characters.
+pub const SHARE_MAX_CHARS: usize = 300;
+
+/// Returns a link to an OpenStreetMap map centred on coordinates.
+pub fn map_link(lat: f64, lon: f64) -> String {
+    format!("https://www.openstreetmap.org/?mlat={lat:.4}&mlon={lon:.4}#map=12/{lat:.4}/{lon:.4}")
+}
+
+/// Builds a compact share text covering now, today and the next two days.
+///
+/// Condition texts of the forecast days are dropped if the text would exceed
+/// [`SHARE_MAX_CHARS`]; as a last resort the text is truncated.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data to summarize.
+/// * `units` - Unit system for measurements.
+/// * `link` - Optional link to the location's page, appended last.
+pub fn share_text(weather: &WeatherData, units: units::Units, link: Option<&str>) -> String {
+    let text = |with_conditions: bool| -> String {
+        let mut lines: Vec<String> = vec![format!(
+            "{}: now {}, {}",
+            weather.location.short_name(),
+            units.temp(
+                weather.current.temp_c.round(),
+                weather.current.temp_f.round()
+            ),
+            weather.current.condition.text
+        )];
+        for forecast_day in weather.forecast.forecastday.iter().take(3) {
+            let day = &forecast_day.day;
+            let mut line: String = format!(
+                "{} high {}, low {}",
+                forecast_day.date.get(5..).unwrap_or(&forecast_day.date),
+                units.temp(day.maxtemp_c.round(), day.maxtemp_f.round()),
+                units.temp(day.mintemp_c.round(), day.mintemp_f.round()),
+            );
+            if with_conditions {
+                line.push_str(&format!(", {}", day.condition.text));
+            }
+            line.push_str(&format!(
+                ", rain {}",
+                units.rain(
+                    day.daily_chance_of_rain,
+                    day.totalprecip_mm,
+                    day.totalprecip_in
+                )
+            ));
+            lines.push(line);
+        }
+        lines.extend(link.map(str::to_owned));
+        lines.join("\n")
+    };
+
+    let full: String = text(true);
+    if full.chars().count() <= SHARE_MAX_CHARS {
+        return full;
+    }
+    let compact: String = text(false);
+    if compact.chars().count() <= SHARE_MAX_CHARS {
+        return compact;
+    }
+    let mut truncated: String = compact.chars().take(SHARE_MAX_CHARS - 1).collect();
+    truncated.push('…');
+    truncated
+}
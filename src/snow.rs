@@ -0,0 +1,120 @@
+use crate::{Hour, WeatherData};
+
+/// Dry spell (hours) that ends a snow event; shorter lulls are part of it.
+pub const SNOW_GAP_HOURS: usize = 3;
+
+/// A period of consecutive snowfall in the hourly forecast.
+#[derive(Debug)]
+pub struct SnowEvent<'a> {
+    /// Hours from the first to the last snowy hour, lulls included.
+    pub hours: Vec<&'a Hour>,
+}
+
+impl SnowEvent<'_> {
+    /// Local time of the first snowy hour (`YYYY-MM-DD HH:MM`).
+    pub fn start(&self) -> &str {
+        &self.hours[0].time
+    }
+
+    /// Local time of the last snowy hour (`YYYY-MM-DD HH:MM`).
+    pub fn end(&self) -> &str {
+        &self.hours[self.hours.len() - 1].time
+    }
+
+    /// Total snowfall over the event, in centimetres.
+    pub fn total_cm(&self) -> f64 {
+        self.hours.iter().map(|hour| hour.snow_cm).sum()
+    }
+
+    /// Snowfall per day of the event with the running accumulation.
+    ///
+    /// # Returns
+    /// `(date, snowfall_cm, accumulated_cm)` for every day the event spans.
+    pub fn daily_totals(&self) -> Vec<(&str, f64, f64)> {
+        let mut totals: Vec<(&str, f64, f64)> = Vec::new();
+        let mut accumulated: f64 = 0.0;
+        for hour in &self.hours {
+            let date: &str = &hour.time[..10];
+            accumulated += hour.snow_cm;
+            match totals.last_mut() {
+                Some((last_date, snowfall, running)) if *last_date == date => {
+                    *snowfall += hour.snow_cm;
+                    *running = accumulated;
+                }
+                _ => totals.push((date, hour.snow_cm, accumulated)),
+            }
+        }
+        totals
+    }
+}
+
+/// Finds the snow events in the forecast from the hour in progress onwards.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+///
+/// # Returns
+/// The snow events, earliest first.
+pub fn find_snow_events(weather: &WeatherData) -> Vec<SnowEvent<'_>> {
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    let hours: Vec<&Hour> = weather
+        .hours()
+        .filter(|hour| hour.time_epoch > from)
+        .collect();
+    let mut events: Vec<SnowEvent> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut last_snowy: usize = 0;
+    for (i, hour) in hours.iter().enumerate() {
+        if hour.snow_cm <= 0.0 {
+            continue;
+        }
+        if let Some(first) = start
+            && i - last_snowy > SNOW_GAP_HOURS
+        {
+            events.push(SnowEvent {
+                hours: hours[first..=last_snowy].to_vec(),
+            });
+            start = None;
+        }
+        start.get_or_insert(i);
+        last_snowy = i;
+    }
+    if let Some(first) = start {
+        events.push(SnowEvent {
+            hours: hours[first..=last_snowy].to_vec(),
+        });
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accumulates_snow_across_days() {
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        // Snow from 20:00 on day 1 to 03:00 on day 2 with a two-hour lull,
+        // then a separate shower in the afternoon of day 2.
+        for (day, hour, cm) in [
+            (0, 20, 1.0),
+            (0, 21, 2.0),
+            (1, 0, 1.5),
+            (1, 3, 0.5),
+            (1, 15, 0.4),
+        ] {
+            weather.forecast.forecastday[day].hour[hour].snow_cm = cm;
+        }
+        let events: Vec<SnowEvent> = find_snow_events(&weather);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].start(), "2025-08-01 20:00");
+        assert_eq!(events[0].end(), "2025-08-02 03:00");
+        assert_eq!(events[0].total_cm(), 5.0);
+        assert_eq!(
+            events[0].daily_totals(),
+            [("2025-08-01", 3.0, 3.0), ("2025-08-02", 2.0, 5.0)]
+        );
+        assert_eq!(events[1].start(), "2025-08-02 15:00");
+    }
+}
@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+/// Default radius (km) within which forecast track points are listed.
+pub const DEFAULT_TRACK_RADIUS_KM: f64 = 1000.0;
+
+/// Active storms feed of the National Hurricane Center.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StormFeed {
+    /// Storms with current advisories in the Atlantic and Pacific basins.
+    pub active_storms: Vec<Storm>,
+}
+
+/// Single active tropical or subtropical cyclone.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Storm {
+    /// ATCF identifier, e.g. `al052025`.
+    pub id: String,
+    pub name: String,
+    /// Classification code (`TD`, `TS`, `HU`, ...), see [`classification_text`].
+    pub classification: String,
+    /// Maximum sustained wind, in knots.
+    pub intensity: Option<String>,
+    /// Minimum central pressure, in millibars.
+    pub pressure: Option<String>,
+    pub latitude_numeric: f64,
+    pub longitude_numeric: f64,
+    /// Direction of motion, in degrees.
+    pub movement_dir: Option<f64>,
+    /// Speed of motion, in knots.
+    pub movement_speed: Option<f64>,
+    /// Latest forecast advisory (TCM), listing the forecast track.
+    pub forecast_advisory: Option<Product>,
+    /// Latest public advisory, for the link shown to the user.
+    pub public_advisory: Option<Product>,
+}
+
+/// NHC text product.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Product {
+    pub url: String,
+}
+
+/// Forecast position of a storm.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct TrackPoint {
+    /// Valid time as written in the advisory (`DD/HHMMZ`).
+    pub valid: String,
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// Returns the description of an NHC classification code.
+pub fn classification_text(code: &str) -> &'static str {
+    match code {
+        "TD" => "Tropical Depression",
+        "STD" => "Subtropical Depression",
+        "TS" => "Tropical Storm",
+        "STS" => "Subtropical Storm",
+        "HU" => "Hurricane",
+        "TY" => "Typhoon",
+        "PTC" => "Post-Tropical Cyclone",
+        "PC" => "Potential Tropical Cyclone",
+        _ => "Cyclone",
+    }
+}
+
+/// Fetches the active storms from the NHC feed.
+#[cfg(feature = "blocking")]
+pub fn fetch_storms() -> Result<StormFeed, crate::WeatherError> {
+    let body: String = fetch_text("https://www.nhc.noaa.gov/CurrentStorms.json")?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// Fetches a storm's forecast advisory and extracts its forecast track.
+///
+/// # Returns
+/// The forecast positions, or an empty list if the storm has no forecast advisory.
+#[cfg(feature = "blocking")]
+pub fn fetch_track(storm: &Storm) -> Result<Vec<TrackPoint>, crate::WeatherError> {
+    match &storm.forecast_advisory {
+        Some(advisory) => Ok(parse_track(&fetch_text(&advisory.url)?)),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Fetches an NHC product as text.
+#[cfg(feature = "blocking")]
+fn fetch_text(url: &str) -> Result<String, crate::WeatherError> {
+    let response: reqwest::blocking::Response = crate::http::send(crate::http::client()?.get(url))?;
+    if response.status() != 200 {
+        return Err(crate::WeatherError::Status {
+            status: response.status().as_u16(),
+            message: None,
+        });
+    }
+    Ok(response.text()?)
+}
+
+/// Extracts the forecast and outlook positions from a forecast advisory (TCM).
+///
+/// Positions are written as `FORECAST VALID 20/0000Z 26.5N  72.9W` and
+/// `OUTLOOK VALID 23/1200Z 36.0N  65.5W`.
+pub fn parse_track(advisory: &str) -> Vec<TrackPoint> {
+    advisory
+        .lines()
+        .filter_map(|line| {
+            let rest: &str = line
+                .trim_start()
+                .strip_prefix("FORECAST VALID")
+                .or_else(|| line.trim_start().strip_prefix("OUTLOOK VALID"))?;
+            let mut fields = rest.split_whitespace();
+            let valid: &str = fields.next()?;
+            let lat: f64 = parse_coordinate(fields.next()?, 'N', 'S')?;
+            // The outlook may be followed by `...POST-TROP/EXTRATROP`.
+            let lon: f64 = parse_coordinate(fields.next()?.split("...").next()?, 'E', 'W')?;
+            Some(TrackPoint {
+                valid: valid.to_owned(),
+                lat,
+                lon,
+            })
+        })
+        .collect()
+}
+
+/// Parses `26.5N` / `72.9W` style coordinates into signed decimal degrees.
+fn parse_coordinate(text: &str, positive: char, negative: char) -> Option<f64> {
+    let value: f64 = text.get(..text.len().checked_sub(1)?)?.parse().ok()?;
+    match text.chars().last()? {
+        c if c == positive => Some(value),
+        c if c == negative => Some(-value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_feed_and_forecast_track() {
+        let feed: StormFeed = serde_json::from_str(
+            r#"{"activeStorms":[{"id":"al052025","binNumber":"AT5","name":"Erin",
+                "classification":"HU","intensity":"100","pressure":"960",
+                "latitudeNumeric":25.1,"longitudeNumeric":-72.3,
+                "movementDir":315,"movementSpeed":12,
+                "forecastAdvisory":{"advNum":"017","url":"https://www.nhc.noaa.gov/tcm"}}]}"#,
+        )
+        .unwrap();
+        let storm: &Storm = &feed.active_storms[0];
+        assert_eq!(classification_text(&storm.classification), "Hurricane");
+        assert!(storm.public_advisory.is_none());
+
+        let track: Vec<TrackPoint> = parse_track(
+            "HURRICANE CENTER LOCATED NEAR 25.1N  72.3W AT 19/1500Z\n\
+             FORECAST VALID 20/0000Z 26.5N  72.9W\n\
+             MAX WIND 100 KT...GUSTS 120 KT.\n\
+             FORECAST VALID 21/1200Z 30.2N  73.5W\n\
+             OUTLOOK VALID 23/1200Z 36.0N  65.5W...POST-TROP/EXTRATROP\n",
+        );
+        assert_eq!(track.len(), 3);
+        assert_eq!(
+            track[0],
+            TrackPoint {
+                valid: "20/0000Z".to_owned(),
+                lat: 26.5,
+                lon: -72.9
+            }
+        );
+        assert_eq!(track[2].lon, -65.5);
+    }
+}
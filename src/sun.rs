@@ -0,0 +1,215 @@
+use serde::Serialize;
+
+use crate::{Location, time};
+
+/// Altitude of the sun's centre at sunrise and sunset, accounting for refraction
+/// and its radius.
+pub const HORIZON_ALTITUDE: f64 = -0.833;
+
+/// Position of the sun in the sky.
+#[derive(Serialize, Debug)]
+pub struct SunPosition {
+    /// Compass bearing of the sun, clockwise from north, in degrees.
+    pub azimuth_deg: f64,
+    /// Angle above the horizon, in degrees (negative below it).
+    pub elevation_deg: f64,
+}
+
+impl SunPosition {
+    /// Length of the shadow cast by a vertical object of unit height.
+    ///
+    /// # Returns
+    /// The ratio, or `None` while the sun is below the horizon.
+    pub fn shadow_ratio(&self) -> Option<f64> {
+        (self.elevation_deg > 0.0).then(|| 1.0 / self.elevation_deg.to_radians().tan())
+    }
+
+    /// Bearing the shadow points to, opposite the sun.
+    pub fn shadow_bearing_deg(&self) -> f64 {
+        (self.azimuth_deg + 180.0).rem_euclid(360.0)
+    }
+}
+
+/// Computes the sun's position at a place and time.
+///
+/// Uses the low-precision solar coordinates of the Astronomical Almanac (about
+/// 0.01° in 1950-2050), without atmospheric refraction.
+///
+/// # Arguments
+/// * `lat` - Latitude of the observer, in degrees.
+/// * `lon` - Longitude of the observer, in degrees (east positive).
+/// * `epoch` - UNIX timestamp, in seconds.
+pub fn position(lat: f64, lon: f64, epoch: i64) -> SunPosition {
+    let (n, right_ascension, declination) = solar_coordinates(epoch);
+    let sidereal_deg: f64 = (280.460_618_37 + 360.985_647_366_29 * n + lon).rem_euclid(360.0);
+    let hour_angle: f64 = (sidereal_deg - right_ascension).to_radians();
+
+    let lat: f64 = lat.to_radians();
+    let elevation: f64 =
+        (lat.sin() * declination.sin() + lat.cos() * declination.cos() * hour_angle.cos()).asin();
+    let azimuth: f64 =
+        (-hour_angle.sin()).atan2(declination.tan() * lat.cos() - lat.sin() * hour_angle.cos());
+    SunPosition {
+        azimuth_deg: azimuth.to_degrees().rem_euclid(360.0),
+        elevation_deg: elevation.to_degrees(),
+    }
+}
+
+/// Daylight length of the current day and its change, in seconds.
+#[derive(Serialize, Debug)]
+pub struct DaylightTrend {
+    pub today_secs: i64,
+    /// Change since yesterday.
+    pub vs_yesterday_secs: i64,
+    /// Change by the same day next week.
+    pub vs_next_week_secs: i64,
+}
+
+/// Computes the length of the day at a latitude, from sunrise to sunset.
+///
+/// Sunrise and sunset are when the sun's upper limb touches the horizon, with
+/// standard refraction (-0.833°).
+///
+/// # Arguments
+/// * `lat` - Latitude of the observer, in degrees.
+/// * `noon_epoch` - UNIX timestamp near local noon of the day.
+///
+/// # Returns
+/// The daylight length in seconds, 0 in polar night and 86400 in polar day.
+pub fn daylight_secs(lat: f64, noon_epoch: i64) -> f64 {
+    let (_, _, declination) = solar_coordinates(noon_epoch);
+    let lat: f64 = lat.to_radians();
+    let cos_hour_angle: f64 = (HORIZON_ALTITUDE.to_radians().sin() - lat.sin() * declination.sin())
+        / (lat.cos() * declination.cos());
+    cos_hour_angle.clamp(-1.0, 1.0).acos().to_degrees() / 15.0 * 2.0 * 3600.0
+}
+
+/// Computes today's daylight length at a location and how it changes.
+pub fn daylight_trend(location: &Location) -> DaylightTrend {
+    let noon: i64 = time::local_epoch(
+        &format!("{} 12:00", location.localtime.get(..10).unwrap_or("")),
+        time::utc_offset(location),
+    );
+    let daylight = |days: i64| -> f64 { daylight_secs(location.lat, noon + days * 86_400) };
+    let today: f64 = daylight(0);
+    DaylightTrend {
+        today_secs: today.round() as i64,
+        vs_yesterday_secs: (today - daylight(-1)).round() as i64,
+        vs_next_week_secs: (daylight(7) - today).round() as i64,
+    }
+}
+
+/// Finds when the sun's centre crosses an altitude on a local date.
+///
+/// # Arguments
+/// * `lat` - Latitude of the observer, in degrees.
+/// * `lon` - Longitude of the observer, in degrees (east positive).
+/// * `date` - Local date (`YYYY-MM-DD`).
+/// * `altitude_deg` - Altitude, e.g. -0.833 for sunset or -18 for astronomical dawn.
+/// * `morning` - Whether to find the crossing before solar noon rather than after.
+///
+/// # Returns
+/// The UNIX time of the crossing, or `None` if the sun stays above or below the altitude.
+pub fn time_at_altitude(
+    lat: f64,
+    lon: f64,
+    date: &str,
+    altitude_deg: f64,
+    morning: bool,
+) -> Option<i64> {
+    let (transit, declination) = transit(lon, date);
+    let lat: f64 = lat.to_radians();
+    let cos_hour_angle: f64 = (altitude_deg.to_radians().sin() - lat.sin() * declination.sin())
+        / (lat.cos() * declination.cos());
+    if !(-1.0..=1.0).contains(&cos_hour_angle) {
+        return None;
+    }
+    let half_arc_secs: f64 = cos_hour_angle.acos().to_degrees() * 240.0;
+    Some(if morning {
+        transit - half_arc_secs
+    } else {
+        transit + half_arc_secs
+    } as i64)
+}
+
+/// Returns the UNIX time of solar noon, when the sun crosses the meridian, on a local date.
+///
+/// # Arguments
+/// * `lon` - Longitude of the observer, in degrees (east positive).
+/// * `date` - Local date (`YYYY-MM-DD`).
+pub fn solar_noon(lon: f64, date: &str) -> i64 {
+    transit(lon, date).0 as i64
+}
+
+/// Returns the UNIX time of the sun's transit on a date and its declination (radians).
+fn transit(lon: f64, date: &str) -> (f64, f64) {
+    let midnight_utc: i64 = time::local_epoch(date, 0);
+    let approximate_noon: i64 = midnight_utc + 43_200 - (lon * 240.0) as i64;
+    let (n, right_ascension, declination) = solar_coordinates(approximate_noon);
+    // Equation of time, from the mean longitude and the right ascension.
+    let mean_longitude: f64 = 280.460 + 0.985_647_4 * n;
+    let equation_of_time_deg: f64 =
+        (mean_longitude - right_ascension + 180.0).rem_euclid(360.0) - 180.0;
+    (
+        midnight_utc as f64 + 43_200.0 - (lon + equation_of_time_deg) * 240.0,
+        declination,
+    )
+}
+
+/// Returns the days since J2000.0, and the sun's right ascension (degrees) and
+/// declination (radians) at a UNIX time.
+fn solar_coordinates(epoch: i64) -> (f64, f64, f64) {
+    // Days since J2000.0 (2000-01-01 12:00 UTC).
+    let n: f64 = (epoch as f64 - 946_728_000.0) / 86_400.0;
+    let mean_longitude: f64 = (280.460 + 0.985_647_4 * n).rem_euclid(360.0);
+    let mean_anomaly: f64 = (357.528 + 0.985_600_3 * n).rem_euclid(360.0).to_radians();
+    let ecliptic_longitude: f64 =
+        (mean_longitude + 1.915 * mean_anomaly.sin() + 0.020 * (2.0 * mean_anomaly).sin())
+            .to_radians();
+    let obliquity: f64 = (23.439 - 0.000_000_4 * n).to_radians();
+    let right_ascension: f64 = (obliquity.cos() * ecliptic_longitude.sin())
+        .atan2(ecliptic_longitude.cos())
+        .to_degrees();
+    let declination: f64 = (obliquity.sin() * ecliptic_longitude.sin()).asin();
+    (n, right_ascension, declination)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_position() {
+        // London at the June solstice, 12:00 UTC: just before solar noon.
+        let noon: SunPosition = position(51.5, -0.13, 1_750_507_200);
+        assert!((61.5..62.5).contains(&noon.elevation_deg));
+        assert!((170.0..180.0).contains(&noon.azimuth_deg));
+        assert!((0.5..0.6).contains(&noon.shadow_ratio().unwrap()));
+
+        // Sydney, same instant: 22:00 local time.
+        let night: SunPosition = position(-33.87, 151.21, 1_750_507_200);
+        assert!(night.elevation_deg < 0.0);
+        assert!(night.shadow_ratio().is_none());
+
+        // 16h38m at the June solstice in London, polar day and night in Tromsø.
+        let solstice: f64 = daylight_secs(51.5, 1_750_507_200);
+        assert!((16.0 * 3600.0 + 35.0 * 60.0..16.0 * 3600.0 + 41.0 * 60.0).contains(&solstice));
+        assert_eq!(daylight_secs(69.65, 1_750_507_200), 86_400.0);
+        assert_eq!(daylight_secs(69.65, 1_766_318_400), 0.0);
+
+        // London sunset on 2025-08-01 at 20:48 BST.
+        let sunset: i64 = time_at_altitude(51.5, -0.13, "2025-08-01", -0.833, false).unwrap();
+        assert!((sunset - 1_754_077_680).abs() < 120);
+        assert!(time_at_altitude(69.65, 18.96, "2025-06-21", -0.833, true).is_none());
+        // Solar noon at 13:06 BST.
+        assert!((solar_noon(-0.13, "2025-08-01") - 1_754_049_960).abs() < 60);
+
+        let weather: crate::WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        let trend: DaylightTrend = daylight_trend(&weather.location);
+        // Fixture sunrise 05:24, sunset 20:48.
+        assert!((trend.today_secs - (15 * 3600 + 24 * 60)).abs() < 5 * 60);
+        assert!((-200..-140).contains(&trend.vs_yesterday_secs));
+        assert!(trend.vs_next_week_secs < 7 * trend.vs_yesterday_secs / 2);
+    }
+}
@@ -0,0 +1,96 @@
+use serde::Serialize;
+
+use crate::{Hour, WeatherData};
+
+/// Wind along and across a travel heading during a forecast hour.
+#[derive(Serialize, Debug)]
+pub struct TailwindHour<'a> {
+    #[serde(skip)]
+    pub hour: &'a Hour,
+    /// Local time (`YYYY-MM-DD HH:MM`).
+    pub time: &'a str,
+    /// Wind component pushing along the heading, in kph (negative for a headwind).
+    pub tailwind_kph: f64,
+    /// Wind component across the heading, in kph (positive from the left).
+    pub crosswind_kph: f64,
+}
+
+/// Splits a wind into its components along and across a travel heading.
+///
+/// # Arguments
+/// * `heading_deg` - Direction of travel, clockwise from north.
+/// * `wind_degree` - Direction the wind blows from, clockwise from north.
+/// * `wind_kph` - Wind speed.
+///
+/// # Returns
+/// The tailwind (negative for a headwind) and crosswind (positive from the left) in kph.
+pub fn components(heading_deg: f64, wind_degree: f64, wind_kph: f64) -> (f64, f64) {
+    // The wind blows towards the opposite of where it comes from.
+    let angle: f64 = (wind_degree + 180.0 - heading_deg).to_radians();
+    (wind_kph * angle.cos(), wind_kph * angle.sin())
+}
+
+/// Ranks the coming hours by tailwind along a heading.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `heading_deg` - Direction of travel, clockwise from north.
+/// * `hours` - Number of hours ahead to consider, from the hour in progress.
+/// * `daylight_only` - Skip night hours.
+/// * `count` - Maximum number of hours returned.
+///
+/// # Returns
+/// Up to `count` hours, strongest tailwind first.
+pub fn best_hours(
+    weather: &WeatherData,
+    heading_deg: f64,
+    hours: usize,
+    daylight_only: bool,
+    count: usize,
+) -> Vec<TailwindHour<'_>> {
+    let mut ranked: Vec<TailwindHour> = crate::hourly::upcoming(weather, hours)
+        .into_iter()
+        .filter(|hour| !daylight_only || hour.is_day == 1)
+        .map(|hour| {
+            let (tailwind_kph, crosswind_kph) =
+                components(heading_deg, f64::from(hour.wind_degree), hour.wind_kph);
+            TailwindHour {
+                hour,
+                time: &hour.time,
+                tailwind_kph,
+                crosswind_kph,
+            }
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.tailwind_kph.total_cmp(&a.tailwind_kph));
+    ranked.truncate(count);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../tests/fixtures/forecast.json");
+
+    #[test]
+    fn ranks_tailwind() {
+        // A westerly wind pushes an eastbound rider and opposes a westbound one.
+        let (tailwind, crosswind) = components(90.0, 270.0, 20.0);
+        assert!((tailwind - 20.0).abs() < 1e-9 && crosswind.abs() < 1e-9);
+        let (tailwind, _) = components(270.0, 270.0, 20.0);
+        assert!((tailwind + 20.0).abs() < 1e-9);
+        // A northerly wind comes from the left when heading east.
+        let (tailwind, crosswind) = components(90.0, 0.0, 20.0);
+        assert!(tailwind.abs() < 1e-9 && (crosswind - 20.0).abs() < 1e-9);
+
+        let weather: WeatherData = serde_json::from_str(FIXTURE).unwrap();
+        let best: Vec<TailwindHour> = best_hours(&weather, 45.0, 24, true, 3);
+        assert_eq!(best.len(), 3);
+        assert!(best.is_sorted_by(|a, b| a.tailwind_kph >= b.tailwind_kph));
+        assert!(best.iter().all(|entry| entry.hour.is_day == 1));
+        // Turning around swaps tailwind for headwind.
+        let back: Vec<TailwindHour> = best_hours(&weather, 225.0, 24, true, 24);
+        assert!((back[back.len() - 1].tailwind_kph + best[0].tailwind_kph).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::Location;
+
+/// Converts a local `YYYY-MM-DD[ HH:MM]` time to a UNIX timestamp.
+///
+/// The date and time may be separated by a space or `T`, and the hour may have a
+/// single digit as in the Weather API's `localtime` (`2025-08-01 9:05`).
+///
+/// # Arguments
+/// * `time` - Local date or date and time.
+/// * `utc_offset` - Offset of the local time from UTC, in seconds.
+pub fn local_epoch(time: &str, utc_offset: i64) -> i64 {
+    let number = |text: Option<&str>| -> i64 { text.and_then(|s| s.parse().ok()).unwrap_or(0) };
+    let (year, month, day): (i64, i64, i64) = (
+        number(time.get(0..4)),
+        number(time.get(5..7)),
+        number(time.get(8..10)),
+    );
+    let mut clock = time.get(11..).unwrap_or("").split(':');
+    let (hour, minute): (i64, i64) = (number(clock.next()), number(clock.next()));
+    // Days-from-civil conversion (proleptic Gregorian calendar).
+    let y: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = y.div_euclid(400);
+    let yoe: i64 = y - era * 400;
+    let doy: i64 = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe: i64 = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days: i64 = era * 146_097 + doe - 719_468;
+    days * 86_400 + hour * 3600 + minute * 60 - utc_offset
+}
+
+/// Validates a local date in the `YYYY-MM-DD` format, with a month and day in range.
+pub fn is_valid_date(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+        && format_local(local_epoch(input, 0), 0).starts_with(input)
+}
+
+/// Returns the number of days from one `YYYY-MM-DD` date to another, negative
+/// if `to` is earlier.
+pub fn days_between(from: &str, to: &str) -> i64 {
+    (local_epoch(to, 0) - local_epoch(from, 0)) / 86_400
+}
+
+/// Formats a UNIX timestamp as a local `YYYY-MM-DD HH:MM` time.
+///
+/// # Arguments
+/// * `epoch` - UNIX timestamp, in seconds.
+/// * `utc_offset` - Offset of the local time from UTC, in seconds.
+pub fn format_local(epoch: i64, utc_offset: i64) -> String {
+    let secs: i64 = epoch + utc_offset;
+    let days: i64 = secs.div_euclid(86_400);
+    let secs_of_day: i64 = secs.rem_euclid(86_400);
+    // Civil-from-days conversion (proleptic Gregorian calendar).
+    let z: i64 = days + 719_468;
+    let era: i64 = z.div_euclid(146_097);
+    let doe: i64 = z.rem_euclid(146_097);
+    let yoe: i64 = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy: i64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp: i64 = (5 * doy + 2) / 153;
+    let day: i64 = doy - (153 * mp + 2) / 5 + 1;
+    let month: i64 = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year: i64 = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        secs_of_day / 3600,
+        secs_of_day % 3600 / 60
+    )
+}
+
+/// Returns the offset of a location's local time from UTC, in seconds.
+///
+/// Derived from `localtime` and `localtime_epoch`, rounded to the quarter hour
+/// as the local time has no seconds.
+pub fn utc_offset(location: &Location) -> i64 {
+    let offset: i64 = local_epoch(&location.localtime, 0) - i64::from(location.localtime_epoch);
+    (offset as f64 / 900.0).round() as i64 * 900
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_local_times() {
+        assert_eq!(local_epoch("1970-01-01", 0), 0);
+        assert_eq!(local_epoch("2025-08-01T14:00", 3600), 1_754_053_200);
+        assert_eq!(local_epoch("2025-08-01 9:05", 0), 1_754_039_100);
+        assert_eq!(local_epoch("2024-02-29 00:00", 0), 1_709_164_800);
+        assert_eq!(format_local(1_754_053_200, 3600), "2025-08-01 14:00");
+        assert_eq!(format_local(1_709_164_800, 0), "2024-02-29 00:00");
+        assert_eq!(format_local(-60, 0), "1969-12-31 23:59");
+        assert!(is_valid_date("2024-02-29"));
+        assert!(!is_valid_date("2025-02-29"));
+        assert!(!is_valid_date("2025-13-01"));
+        assert!(!is_valid_date("2025-8-01"));
+        assert_eq!(days_between("2025-08-01", "2026-05-28"), 300);
+        assert_eq!(days_between("2025-08-01", "2025-07-31"), -1);
+    }
+}
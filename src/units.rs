@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+/// Kilometres in a mile.
+const KM_PER_MILE: f64 = 1.609_344;
+
+/// Text printed for a reading missing from the response.
+pub const NOT_AVAILABLE: &str = "N/A";
+
+/// Unit system used to print measurements.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Units {
+    /// °C, kph, mm.
+    Metric,
+    /// °F, mph, in.
+    Imperial,
+    /// Metric and imperial side by side.
+    #[default]
+    Both,
+}
+
+impl FromStr for Units {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Units, String> {
+        match name {
+            "metric" => Ok(Units::Metric),
+            "imperial" => Ok(Units::Imperial),
+            "both" => Ok(Units::Both),
+            _ => Err(format!("Unknown unit system {name}")),
+        }
+    }
+}
+
+impl Units {
+    /// Formats a temperature given in both scales.
+    pub fn temp(self, celsius: f64, fahrenheit: f64) -> String {
+        match self {
+            Units::Metric => format!("{celsius}°C"),
+            Units::Imperial => format!("{fahrenheit}°F"),
+            Units::Both => format!("{celsius}°C / {fahrenheit}°F"),
+        }
+    }
+
+    /// Formats a speed given in kph and mph.
+    pub fn speed(self, kph: f64, mph: f64) -> String {
+        match self {
+            Units::Metric => format!("{kph}kph"),
+            Units::Imperial => format!("{mph}mph"),
+            Units::Both => format!("{kph}kph / {mph}mph"),
+        }
+    }
+
+    /// Formats a temperature that may be missing, as [`NOT_AVAILABLE`].
+    pub fn temp_or_na(self, celsius: Option<f64>, fahrenheit: Option<f64>) -> String {
+        celsius
+            .zip(fahrenheit)
+            .map_or(NOT_AVAILABLE.to_owned(), |(c, f)| self.temp(c, f))
+    }
+
+    /// Formats a speed that may be missing, as [`NOT_AVAILABLE`].
+    pub fn speed_or_na(self, kph: Option<f64>, mph: Option<f64>) -> String {
+        kph.zip(mph)
+            .map_or(NOT_AVAILABLE.to_owned(), |(kph, mph)| self.speed(kph, mph))
+    }
+
+    /// Formats a temperature known in Celsius only, e.g. a forecast extreme or an
+    /// estimate, to one decimal.
+    pub fn temp_c(self, celsius: f64) -> String {
+        self.temp(round1(celsius), round1(celsius * 9.0 / 5.0 + 32.0))
+    }
+
+    /// Formats a speed known in kph only, e.g. a user threshold.
+    pub fn speed_kph(self, kph: f64) -> String {
+        self.speed(kph, round1(kph / KM_PER_MILE))
+    }
+
+    /// Formats a distance given in km and miles, e.g. visibility.
+    pub fn distance(self, km: f64, miles: f64) -> String {
+        match self {
+            Units::Metric => format!("{km} km"),
+            Units::Imperial => format!("{miles} mi"),
+            Units::Both => format!("{km} km / {miles} mi"),
+        }
+    }
+
+    /// Formats a distance that may be missing, as [`NOT_AVAILABLE`].
+    pub fn distance_or_na(self, km: Option<f64>, miles: Option<f64>) -> String {
+        km.zip(miles)
+            .map_or(NOT_AVAILABLE.to_owned(), |(km, miles)| {
+                self.distance(km, miles)
+            })
+    }
+
+    /// Formats a distance known in km only, e.g. a user threshold.
+    pub fn distance_km(self, km: f64) -> String {
+        self.distance(km, round1(km / KM_PER_MILE))
+    }
+
+    /// Formats a precipitation amount given in mm and inches.
+    ///
+    /// `Both` keeps millimetres only, as amounts are small enough to read in one unit.
+    pub fn precip(self, mm: f64, inches: f64) -> String {
+        match self {
+            Units::Metric | Units::Both => format!("{mm} mm"),
+            Units::Imperial => format!("{inches} in"),
+        }
+    }
+
+    /// Formats a snow depth given in centimetres.
+    ///
+    /// `Both` keeps centimetres only, like [`Units::precip`].
+    pub fn snow(self, cm: f64) -> String {
+        match self {
+            Units::Metric | Units::Both => format!("{cm:.1} cm"),
+            Units::Imperial => format!("{:.1} in", cm / 2.54),
+        }
+    }
+
+    /// Formats a precipitation amount known in mm only, e.g. a total.
+    pub fn precip_mm(self, mm: f64) -> String {
+        self.precip(mm, (mm / 25.4 * 100.0).round() / 100.0)
+    }
+
+    /// Formats rain as probability and expected amount, e.g. `60% · 3 mm`.
+    ///
+    /// The probability says how likely rain is, the amount how much falls if it does.
+    pub fn rain(self, chance: i32, mm: f64, inches: f64) -> String {
+        format!("{chance}% · {}", self.precip(mm, inches))
+    }
+}
+
+/// Rounds to one decimal, the precision of the Weather API's converted values.
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
@@ -0,0 +1,108 @@
+use serde::Serialize;
+
+use crate::windows::{self, Window};
+use crate::{Hour, WeatherData};
+
+/// Highest US EPA index at which to let outdoor air in (2: moderate).
+pub const MAX_US_EPA_INDEX: i32 = 2;
+
+/// Indoor conditions to keep, or reach by airing.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Indoor {
+    pub temp_c: f64,
+    /// Relative humidity (%).
+    pub humidity: f64,
+}
+
+impl Default for Indoor {
+    fn default() -> Self {
+        Indoor {
+            temp_c: 22.0,
+            humidity: 50.0,
+        }
+    }
+}
+
+impl Indoor {
+    /// Dew point of the indoor air.
+    pub fn dewpoint_c(&self) -> f64 {
+        dewpoint_c(self.temp_c, self.humidity)
+    }
+}
+
+/// Computes the dew point with the Magnus formula (within 0.4 °C from -45 to 60 °C).
+///
+/// # Arguments
+/// * `temp_c` - Air temperature.
+/// * `humidity` - Relative humidity (%).
+pub fn dewpoint_c(temp_c: f64, humidity: f64) -> f64 {
+    let (b, c): (f64, f64) = (17.62, 243.12);
+    let gamma: f64 = (humidity.max(1.0) / 100.0).ln() + b * temp_c / (c + temp_c);
+    c * gamma / (b - gamma)
+}
+
+/// Returns whether opening the windows at an hour cools and dries the room.
+///
+/// The outdoor air must be cooler than indoors and hold less moisture (a lower dew
+/// point), without rain and with acceptable air quality where it is known.
+pub fn airs_well(hour: &Hour, indoor: &Indoor) -> bool {
+    hour.temp_c < indoor.temp_c
+        && dewpoint_c(hour.temp_c, f64::from(hour.humidity)) < indoor.dewpoint_c()
+        && hour.precip_mm == 0.0
+        && hour
+            .air_quality
+            .as_ref()
+            .is_none_or(|air_quality| air_quality.us_epa_index <= MAX_US_EPA_INDEX)
+}
+
+/// Finds the time ranges left today to air the room.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `indoor` - Indoor conditions to keep.
+///
+/// # Returns
+/// The ranges of consecutive suitable hours, earliest first.
+pub fn airing_windows<'a>(weather: &'a WeatherData, indoor: &Indoor) -> Vec<Window<'a>> {
+    // Include the hour in progress, which started before `last_updated`.
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    let today: &str = weather.location.localtime.get(..10).unwrap_or("");
+    windows::runs(
+        weather
+            .hours()
+            .filter(|hour| hour.time_epoch > from && hour.time.starts_with(today)),
+        |hour| airs_well(hour, indoor),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_airing_windows() {
+        assert!((dewpoint_c(22.0, 50.0) - 11.1).abs() < 0.1);
+        assert!((dewpoint_c(10.0, 100.0) - 10.0).abs() < 0.01);
+
+        let mut weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        let ranges = |weather: &WeatherData, indoor: &Indoor| -> Vec<(String, String)> {
+            airing_windows(weather, indoor)
+                .iter()
+                .map(|window| (window.start().to_owned(), window.end()))
+                .collect()
+        };
+        // The London afternoon is too humid for a 22 °C, 50% room; the evening dries.
+        assert_eq!(
+            ranges(&weather, &Indoor::default()),
+            [("20:00".to_owned(), "00:00".to_owned())]
+        );
+        weather.forecast.forecastday[0].hour[21].precip_mm = 0.4;
+        assert_eq!(ranges(&weather, &Indoor::default()).len(), 2);
+        let cold: Indoor = Indoor {
+            temp_c: 12.0,
+            humidity: 50.0,
+        };
+        assert!(ranges(&weather, &cold).is_empty());
+    }
+}
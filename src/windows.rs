@@ -0,0 +1,143 @@
+use crate::{Hour, WeatherData};
+
+/// Conditions an hour must meet to be part of a window.
+#[derive(Debug)]
+pub struct Criteria {
+    /// Highest acceptable chance of rain (%).
+    pub max_chance_of_rain: i32,
+    /// Highest acceptable precipitation (mm).
+    pub max_precip_mm: f64,
+    /// Highest acceptable sustained wind (kph).
+    pub max_wind_kph: f64,
+    /// Only accept daylight hours.
+    pub daylight_only: bool,
+}
+
+impl Default for Criteria {
+    fn default() -> Self {
+        Criteria {
+            max_chance_of_rain: 20,
+            max_precip_mm: 0.0,
+            max_wind_kph: 20.0,
+            daylight_only: true,
+        }
+    }
+}
+
+impl Criteria {
+    /// Returns `true` if the hour satisfies every criterion.
+    pub fn matches(&self, hour: &Hour) -> bool {
+        hour.chance_of_rain <= self.max_chance_of_rain
+            && hour.precip_mm <= self.max_precip_mm
+            && hour.wind_kph <= self.max_wind_kph
+            && (!self.daylight_only || hour.is_day == 1)
+    }
+}
+
+/// A run of consecutive matching hours.
+#[derive(Debug)]
+pub struct Window<'a> {
+    /// Matching hours, in order.
+    pub hours: Vec<&'a Hour>,
+}
+
+impl Window<'_> {
+    /// Local start time (`HH:MM`).
+    pub fn start(&self) -> &str {
+        &self.hours[0].time[11..]
+    }
+
+    /// Local end time (`HH:MM`), one hour after the start of the last hour.
+    pub fn end(&self) -> String {
+        let last: &str = &self.hours[self.hours.len() - 1].time[11..13];
+        let hour: u32 = last.parse::<u32>().unwrap_or(0) + 1;
+        format!("{:02}:00", hour % 24)
+    }
+
+    /// Length of the window in minutes.
+    pub fn minutes(&self) -> u32 {
+        self.hours.len() as u32 * 60
+    }
+}
+
+/// Finds the next windows matching the criteria within the coming 24 hours.
+///
+/// # Arguments
+/// * `weather` - Parsed weather data with hourly forecast.
+/// * `criteria` - Conditions every hour of a window must meet.
+/// * `min_minutes` - Minimum length of a window.
+/// * `count` - Maximum number of windows to return.
+///
+/// # Returns
+/// Up to `count` windows, earliest first.
+pub fn find_windows<'a>(
+    weather: &'a WeatherData,
+    criteria: &Criteria,
+    min_minutes: u32,
+    count: usize,
+) -> Vec<Window<'a>> {
+    // Include the hour in progress, which started before `last_updated`.
+    let from: i64 = weather.current.last_updated_epoch as i64 - 3600;
+    let until: i64 = from + 25 * 3600;
+    runs(
+        weather
+            .hours()
+            .filter(|hour| hour.time_epoch > from && hour.time_epoch < until),
+        |hour| criteria.matches(hour),
+    )
+    .into_iter()
+    .filter(|window| window.minutes() >= min_minutes)
+    .take(count)
+    .collect()
+}
+
+/// Splits hours into the runs of consecutive hours that match a condition.
+///
+/// # Arguments
+/// * `hours` - Forecast hours, in order.
+/// * `matches` - Condition every hour of a run must meet.
+pub fn runs<'a>(
+    hours: impl IntoIterator<Item = &'a Hour>,
+    matches: impl Fn(&Hour) -> bool,
+) -> Vec<Window<'a>> {
+    let mut windows: Vec<Window> = Vec::new();
+    let mut current: Vec<&Hour> = Vec::new();
+    for hour in hours {
+        if matches(hour) {
+            current.push(hour);
+            continue;
+        }
+        if !current.is_empty() {
+            windows.push(Window {
+                hours: std::mem::take(&mut current),
+            });
+        }
+    }
+    if !current.is_empty() {
+        windows.push(Window { hours: current });
+    }
+    windows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_daylight_windows_in_fixture() {
+        let weather: WeatherData =
+            serde_json::from_str(include_str!("../tests/fixtures/forecast.json")).unwrap();
+        let found: Vec<Window> = find_windows(&weather, &Criteria::default(), 60, 3);
+        let ranges: Vec<(String, String)> = found
+            .iter()
+            .map(|window| (window.hours[0].time.clone(), window.end()))
+            .collect();
+        assert_eq!(
+            ranges,
+            [
+                ("2025-08-01 14:00".to_string(), "21:00".to_string()),
+                ("2025-08-02 06:00".to_string(), "14:00".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Parsing of responses missing the fields that some plans and options leave out.
+
+use mosm_rs::render::{self, REPORT_WIDTH};
+use mosm_rs::{WeatherData, units};
+
+/// Fields absent with `aqi=no` or on lower API tiers.
+const OPTIONAL_FIELDS: [&str; 16] = [
+    "air_quality",
+    "short_rad",
+    "diff_rad",
+    "dni",
+    "gti",
+    "windchill_c",
+    "windchill_f",
+    "heatindex_c",
+    "heatindex_f",
+    "dewpoint_c",
+    "dewpoint_f",
+    "gust_kph",
+    "gust_mph",
+    "uv",
+    "vis_km",
+    "vis_miles",
+];
+
+/// Returns the frozen forecast response without some fields, at every level.
+fn fixture_without(fields: &[&str]) -> serde_json::Value {
+    fn strip(value: &mut serde_json::Value, fields: &[&str]) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|key, _| !fields.contains(&key.as_str()));
+                map.values_mut().for_each(|value| strip(value, fields));
+            }
+            serde_json::Value::Array(items) => {
+                items.iter_mut().for_each(|item| strip(item, fields))
+            }
+            _ => {}
+        }
+    }
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/forecast.json")).expect("Invalid fixture");
+    strip(&mut value, fields);
+    value
+}
+
+fn render(weather: &WeatherData) -> String {
+    let mut out: Vec<u8> = Vec::new();
+    render::render_report(weather, units::Units::Both, REPORT_WIDTH, false, &mut out).unwrap();
+    render::render_summary_line(weather, units::Units::Both, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn parses_response_without_air_quality() {
+    let weather: WeatherData = serde_json::from_value(fixture_without(&["air_quality"])).unwrap();
+    assert!(weather.current.air_quality.is_none());
+    assert!(weather.hours().all(|hour| hour.air_quality.is_none()));
+    let report: String = render(&weather);
+    assert!(report.contains("AQI: not available"));
+    assert!(report.contains("| AQI: N/A |"));
+}
+
+#[test]
+fn parses_response_without_optional_fields() {
+    let weather: WeatherData = serde_json::from_value(fixture_without(&OPTIONAL_FIELDS)).unwrap();
+    assert_eq!(weather.current.gti, 0.0);
+    assert_eq!(weather.current.dewpoint_c, None);
+    assert!(weather.hours().all(|hour| hour.gust_kph.is_none()));
+    let report: String = render(&weather);
+    assert!(report.contains("Sunny"));
+    assert!(report.contains("Dew Point: N/A"));
+    assert!(report.contains("Visibility: N/A"));
+
+    // A partial air quality block keeps the pollutants it has.
+    let mut value: serde_json::Value = fixture_without(&["co", "so2", "gb-defra-index"]);
+    value["alerts"] = serde_json::json!({});
+    let weather: WeatherData = serde_json::from_value(value).unwrap();
+    let air_quality = weather.current.air_quality.as_ref().unwrap();
+    assert_eq!(air_quality.co, 0.0);
+    assert!(air_quality.pm2_5 > 0.0);
+    assert!(weather.alerts.alert.is_empty());
+}
+
+#[test]
+fn rejects_response_without_essential_fields() {
+    let error = serde_json::from_value::<WeatherData>(fixture_without(&["temp_c"])).unwrap_err();
+    assert!(error.to_string().contains("temp_c"));
+}
@@ -0,0 +1,227 @@
+//! Snapshot tests of the rendered reports against a frozen forecast response.
+
+use mosm_rs::render::{self, REPORT_WIDTH};
+use mosm_rs::{FutureData, HistoryData, WeatherData, applet, share, units};
+
+/// Frozen forecast response used by the renderer snapshots.
+fn fixture() -> WeatherData {
+    serde_json::from_str(include_str!("fixtures/forecast.json")).expect("Invalid fixture")
+}
+
+/// Compares output against `tests/snapshots/<name>.snap`.
+///
+/// Run with `UPDATE_SNAPSHOTS=1` to (re)write the snapshot instead.
+fn assert_snapshot(name: &str, rendered: &str) {
+    let path = format!("{}/tests/snapshots/{name}.snap", env!("CARGO_MANIFEST_DIR"));
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&path, rendered).expect("Failed to write snapshot");
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Missing snapshot {path}, run with UPDATE_SNAPSHOTS=1"));
+    assert_eq!(rendered, expected, "snapshot {name} changed");
+}
+
+fn render_at(width: usize) -> String {
+    let mut out: Vec<u8> = Vec::new();
+    render::render_report(&fixture(), units::Units::Both, width, false, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn report_default_width() {
+    assert_snapshot("report_70", &render_at(REPORT_WIDTH));
+}
+
+#[test]
+fn report_narrow_width() {
+    assert_snapshot("report_40", &render_at(40));
+}
+
+#[test]
+fn astro_section() {
+    let mut out: Vec<u8> = Vec::new();
+    render::render_astro(&fixture(), &mut out).unwrap();
+    assert_snapshot("astro", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn header_with_context() {
+    let mut weather: WeatherData = fixture();
+    weather.location.show_coordinates = true;
+    weather.location.elevation_m = Some(35.0);
+    let mut out: Vec<u8> = Vec::new();
+    render::render_header(&weather, &mut out).unwrap();
+    assert_snapshot("header_context", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn forecast_section() {
+    let mut out: Vec<u8> = Vec::new();
+    render::render_forecast(&fixture(), units::Units::Imperial, false, &mut out).unwrap();
+    assert_snapshot("forecast", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn redacted_report_hides_context() {
+    let mut weather: WeatherData = fixture();
+    weather.location.show_coordinates = true;
+    weather.location.elevation_m = Some(35.0);
+    weather.location.redact("Home");
+    let mut out: Vec<u8> = Vec::new();
+    render::render_report(
+        &weather,
+        units::Units::Metric,
+        REPORT_WIDTH,
+        false,
+        &mut out,
+    )
+    .unwrap();
+    let report: String = String::from_utf8(out).unwrap();
+    assert!(report.contains("\nHome\n"), "{report}");
+    for hidden in ["Coordinates", "51.5171", "Elevation", "35 m"] {
+        assert!(!report.contains(hidden), "{hidden} in {report}");
+    }
+}
+
+#[test]
+fn aqi_section() {
+    let mut out: Vec<u8> = Vec::new();
+    render::render_aqi(fixture().current.air_quality.as_ref(), false, &mut out).unwrap();
+    assert_snapshot("aqi", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn applet_formats() {
+    for (name, format) in [
+        ("argos", applet::AppletFormat::Argos),
+        ("genmon", applet::AppletFormat::Genmon),
+    ] {
+        let mut out: Vec<u8> = Vec::new();
+        applet::render(&fixture(), format, units::Units::Metric, &mut out).unwrap();
+        assert_snapshot(&format!("applet_{name}"), &String::from_utf8(out).unwrap());
+    }
+}
+
+#[test]
+fn share_text_fits_limit() {
+    let text: String = share::share_text(
+        &fixture(),
+        units::Units::Metric,
+        Some("https://example.com"),
+    );
+    assert!(text.chars().count() <= share::SHARE_MAX_CHARS);
+    assert_eq!(
+        share::map_link(51.5171, -0.1062),
+        "https://www.openstreetmap.org/?mlat=51.5171&mlon=-0.1062#map=12/51.5171/-0.1062"
+    );
+    assert_snapshot("share", &text);
+}
+
+#[test]
+fn summary_line() {
+    let mut out: Vec<u8> = Vec::new();
+    render::render_summary_line(&fixture(), units::Units::Metric, &mut out).unwrap();
+    render::render_summary_line(&fixture(), units::Units::Imperial, &mut out).unwrap();
+    assert_snapshot("summary_line", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn compare_table() {
+    let mut tokyo: WeatherData = fixture();
+    tokyo.location.name = "Tokyo".to_owned();
+    tokyo.location.country = "Japan".to_owned();
+    tokyo.current.condition.text = "Partly cloudy".to_owned();
+    let mut home: WeatherData = fixture();
+    home.location.redact("Home");
+    let mut out: Vec<u8> = Vec::new();
+    render::render_compare(&[fixture(), tokyo, home], units::Units::Metric, &mut out).unwrap();
+    assert_snapshot("compare", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn current_rain_sparkline() {
+    let mut weather: WeatherData = fixture();
+    for (hour, chance) in weather.forecast.forecastday[0].hour[17..]
+        .iter_mut()
+        .zip([10, 40, 70, 90, 60, 30])
+    {
+        hour.chance_of_rain = chance;
+    }
+    let mut out: Vec<u8> = Vec::new();
+    render::render_current(&weather, units::Units::Metric, false, &mut out).unwrap();
+    assert_snapshot("current_rain", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn history_table() {
+    // The history response is a forecast response without current conditions.
+    let mut response: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/forecast.json")).unwrap();
+    let response = response.as_object_mut().unwrap();
+    response.remove("current");
+    response.remove("alerts");
+    let days = response["forecast"]["forecastday"].as_array_mut().unwrap();
+    days.truncate(1);
+    days[0]["date"] = "2024-01-15".into();
+    for (field, value) in [
+        ("maxtemp_c", 8.1),
+        ("maxtemp_f", 46.6),
+        ("mintemp_c", 2.3),
+        ("mintemp_f", 36.1),
+        ("avgtemp_c", 5.2),
+        ("avgtemp_f", 41.4),
+        ("totalprecip_mm", 3.4),
+    ] {
+        days[0]["day"][field] = value.into();
+    }
+    days[0]["day"]["condition"]["text"] = "Light rain".into();
+    let history: HistoryData = serde_json::from_value(response.clone().into()).unwrap();
+    let mut out: Vec<u8> = Vec::new();
+    render::render_history(&history, &fixture(), units::Units::Metric, &mut out).unwrap();
+    assert_snapshot("history", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn future_summary() {
+    // Future days have no rain or snow chances, nor hours kept.
+    let future: FutureData = serde_json::from_str(
+        r#"{"location": {"name": "London", "region": "City of London, Greater London",
+            "country": "United Kingdom", "lat": 51.5171, "lon": -0.1062,
+            "tz_id": "Europe/London", "localtime_epoch": 1754053800,
+            "localtime": "2025-08-01 14:10"},
+        "forecast": {"forecastday": [{"date": "2026-03-14", "date_epoch": 1773446400,
+            "day": {"maxtemp_c": 11.4, "maxtemp_f": 52.5, "mintemp_c": 4.1, "mintemp_f": 39.4,
+                "avgtemp_c": 7.6, "avgtemp_f": 45.7, "maxwind_mph": 14.8, "maxwind_kph": 23.8,
+                "totalprecip_mm": 1.2, "totalprecip_in": 0.05, "avgvis_km": 10.0,
+                "avgvis_miles": 6.0, "avghumidity": 78,
+                "condition": {"text": "Patchy rain possible", "icon": "", "code": 1063},
+                "uv": 2.0},
+            "astro": {"sunrise": "06:12 AM", "sunset": "06:05 PM", "moonrise": "02:31 AM",
+                "moonset": "10:47 AM", "moon_phase": "Waning Crescent"},
+            "hour": [{"time_epoch": 1773446400, "time": "2026-03-14 00:00"}]}]}}"#,
+    )
+    .unwrap();
+    let mut out: Vec<u8> = Vec::new();
+    render::render_future(&future, units::Units::Metric, &mut out).unwrap();
+    assert_snapshot("future", &String::from_utf8(out).unwrap());
+}
+
+#[test]
+fn alerts_section() {
+    let mut weather: WeatherData = fixture();
+    weather.alerts = serde_json::from_str(
+        r#"{"alert": [{
+            "headline": "Met Office: Yellow Thunderstorm Warning",
+            "severity": "Moderate",
+            "event": "Yellow Thunderstorm Warning",
+            "effective": "2025-08-01T15:00:00+01:00",
+            "expires": "2025-08-01T23:59:00+01:00"
+        }]}"#,
+    )
+    .unwrap();
+    let mut out: Vec<u8> = Vec::new();
+    render::render_alerts(&weather.alerts.alert, &mut out).unwrap();
+    render::render_summary_line(&weather, units::Units::Metric, &mut out).unwrap();
+    assert_snapshot("alerts", &String::from_utf8(out).unwrap());
+}